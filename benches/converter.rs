@@ -0,0 +1,91 @@
+//! Compare the HTML to markdown backends on representative pages
+//!
+//! Run with `cargo bench --bench converter`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use tarzi::converter::MarkdownBackendKind;
+
+/// A long article: headings, paragraphs with inline markup and links, code and quotes
+fn article() -> String {
+    let mut html = String::from("<html><head><title>Article</title></head><body><main>");
+    for section in 0..40 {
+        html.push_str(&format!("<h2>Section {section}</h2>"));
+        for _ in 0..5 {
+            html.push_str(
+                "<p>Rust is a <strong>systems</strong> programming language that runs \
+                 <em>blazingly</em> fast, prevents segfaults and guarantees thread safety. \
+                 See <a href=\"https://www.rust-lang.org/learn\">the book</a> and \
+                 <code>cargo doc</code> for more.</p>",
+            );
+        }
+        html.push_str("<blockquote><p>A quoted remark about ownership.</p></blockquote>");
+        html.push_str("<pre><code class=\"language-rust\">fn main() {\n    println!(\"hi\");\n}\n</code></pre>");
+    }
+    html.push_str("</main></body></html>");
+    html
+}
+
+/// Navigation-heavy page with deeply nested lists
+fn lists() -> String {
+    let mut html = String::from("<nav><ul>");
+    for group in 0..60 {
+        html.push_str(&format!("<li><a href=\"/g/{group}\">Group {group}</a><ol>"));
+        for item in 0..8 {
+            html.push_str(&format!(
+                "<li>Item {item}<ul><li><a href=\"/g/{group}/{item}\">Detail</a></li></ul></li>"
+            ));
+        }
+        html.push_str("</ol></li>");
+    }
+    html.push_str("</ul></nav>");
+    html
+}
+
+/// Data page made of tables
+fn tables() -> String {
+    let mut html = String::new();
+    for table in 0..20 {
+        html.push_str(&format!("<h3>Table {table}</h3><table><thead><tr>"));
+        for column in 0..6 {
+            html.push_str(&format!("<th>Column {column}</th>"));
+        }
+        html.push_str("</tr></thead><tbody>");
+        for row in 0..30 {
+            html.push_str("<tr>");
+            for column in 0..6 {
+                html.push_str(&format!("<td>{row}.{column}</td>"));
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</tbody></table>");
+    }
+    html
+}
+
+fn bench_backends(c: &mut Criterion) {
+    let fixtures = [
+        ("article", article()),
+        ("lists", lists()),
+        ("tables", tables()),
+    ];
+    let backends = [
+        ("html2md", MarkdownBackendKind::Html2Md),
+        ("dom_walker", MarkdownBackendKind::DomWalker),
+    ];
+
+    let mut group = c.benchmark_group("html_to_markdown");
+    for (fixture, html) in &fixtures {
+        group.throughput(Throughput::Bytes(html.len() as u64));
+        for (name, kind) in backends {
+            let backend = kind.backend();
+            group.bench_with_input(BenchmarkId::new(name, fixture), html, |b, html| {
+                b.iter(|| backend.to_markdown(black_box(html)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_backends);
+criterion_main!(benches);
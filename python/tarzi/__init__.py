@@ -2,6 +2,7 @@
 from .tarzi import (
     Config,
     Converter,
+    RateLimitedError,
     SearchEngine,
     SearchResult,
     WebFetcher,
@@ -39,4 +40,5 @@ __all__ = [
     "WebFetcher",
     "SearchEngine",
     "SearchResult",
+    "RateLimitedError",
 ]
@@ -1,11 +1,15 @@
 use crate::constants::{
-    DEFAULT_QUERY_PATTERN, DEFAULT_SEARCH_LIMIT, DEFAULT_TIMEOUT_SECS,
-    FETCHER_MODE_BROWSER_HEADLESS, FORMAT_MARKDOWN, LOG_LEVEL_INFO, SEARCH_ENGINE_BING,
+    AUTOSWITCH_NONE, DEFAULT_FETCH_CONCURRENCY, DEFAULT_MAX_SEARCH_LIMIT, DEFAULT_QUERY_PATTERN,
+    DEFAULT_RETRY_BACKOFF_MS, DEFAULT_SEARCH_LIMIT, DEFAULT_SERPAPI_ENGINE, DEFAULT_TIMEOUT_SECS,
+    ENV_BING_API_KEY, ENV_GOOGLE_CSE_KEY, ENV_SERPAPI_KEY, FETCHER_MODE_BROWSER_HEADLESS,
+    FORMAT_MARKDOWN, LOG_FORMAT_TEXT, LOG_LEVEL_INFO, SAFE_SEARCH_MODERATE, SEARCH_ENGINE_BING,
 };
 use crate::{Result, error::TarziError};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -15,12 +19,17 @@ pub struct Config {
     pub fetcher: FetcherConfig,
     #[serde(default)]
     pub search: SearchConfig,
+    #[serde(default)]
+    pub converter: ConverterConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeneralConfig {
     #[serde(default = "default_log_level")]
     pub log_level: String,
+    /// Log output: "text" (default) or "json", one JSON object per line
+    #[serde(default = "default_log_format")]
+    pub log_format: String,
     #[serde(default = "default_timeout")]
     pub timeout: u64,
 }
@@ -36,9 +45,116 @@ pub struct FetcherConfig {
     #[serde(default = "default_fetch_timeout")]
     pub timeout: u64,
     pub proxy: Option<String>,
+    /// Proxies used in turn, one per request, instead of `proxy`. A proxy that
+    /// can't be reached is skipped for a minute.
+    #[serde(default)]
+    pub proxy_pool: Vec<String>,
     #[serde(default = "default_web_driver")]
     pub web_driver: String,
     pub web_driver_url: Option<String>,
+    /// Remote debugging address of an already running Chrome to attach to instead
+    /// of launching one, e.g. `127.0.0.1:9222` or the browser's `ws://` endpoint
+    pub browser_debugger_address: Option<String>,
+    /// WebDriver script timeout in seconds; the driver's default is used when unset
+    pub script_timeout: Option<u64>,
+    /// WebDriver page load timeout in seconds; the driver's default is used when unset
+    pub page_load_timeout: Option<u64>,
+    /// CSS selector browser fetches wait for (up to `timeout`) before extracting
+    /// the page, instead of a fixed delay
+    pub wait_for_selector: Option<String>,
+    /// Navigate one long-lived page per browser for sequential fetches, keeping
+    /// session state; when false each fetch opens (and closes) its own tab
+    #[serde(default = "default_reuse_page")]
+    pub reuse_page: bool,
+    /// Fetch all results of one search-and-fetch call with a single browser and
+    /// page, shut down (with its driver) when the call ends
+    #[serde(default)]
+    pub reuse_browser: bool,
+    /// Skip TLS certificate verification for plain requests.
+    ///
+    /// **Security:** this disables protection against man-in-the-middle attacks
+    /// for every HTTPS request made by the fetcher. Only enable it for trusted
+    /// internal hosts with self-signed certificates.
+    #[serde(default)]
+    pub danger_accept_invalid_certs: bool,
+    /// Path to an additional PEM-encoded CA certificate (or bundle) to trust
+    pub ca_cert_path: Option<String>,
+    /// HTTP authentication applied to plain-request fetches of `auth_hosts`
+    pub auth: Option<AuthConfig>,
+    /// Hosts `auth` credentials are sent to, e.g. `api.example.com`; requests to
+    /// any other host, including search result pages, are sent without them
+    #[serde(default)]
+    pub auth_hosts: Vec<String>,
+    /// Hostnames resolved to fixed addresses for plain requests, like `/etc/hosts`.
+    /// The URL (and so the TLS server name) is unchanged; only the connection goes
+    /// to the given address, on the port from the URL.
+    #[serde(default)]
+    pub host_overrides: HashMap<String, IpAddr>,
+    /// Extra HTTP headers sent with every plain request, e.g. `Accept-Language`
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Keep cookies set by responses and send them on later plain requests
+    /// from the same fetcher
+    #[serde(default)]
+    pub cookie_store: bool,
+    /// Retries for plain requests failing with a connection error or a
+    /// 429/500/502/503/504 response; 0 disables retrying
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Delay before the first retry in milliseconds, doubled for each further retry
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+    /// Plain requests run at once when fetching the content of search results;
+    /// browser fetches are always made one at a time
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    /// Download chromedriver/geckodriver to a cache directory when no driver is
+    /// found in PATH; off by default so nothing is downloaded unasked
+    #[serde(default)]
+    pub auto_download_driver: bool,
+}
+
+/// HTTP authentication credentials for the fetcher
+///
+/// In TOML this is written as `auth = { type = "basic", username = "...", password = "..." }`
+/// or `auth = { type = "bearer", token = "..." }`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AuthConfig {
+    /// HTTP Basic authentication
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    /// Static bearer token sent in the `Authorization` header
+    Bearer { token: String },
+}
+
+impl AuthConfig {
+    /// Name of the authentication scheme, safe to log
+    pub fn scheme(&self) -> &'static str {
+        match self {
+            AuthConfig::Basic { .. } => "basic",
+            AuthConfig::Bearer { .. } => "bearer",
+        }
+    }
+}
+
+// Credentials are redacted so configs can be logged safely
+impl std::fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthConfig::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            AuthConfig::Bearer { .. } => f
+                .debug_struct("Bearer")
+                .field("token", &"<redacted>")
+                .finish(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,22 +165,86 @@ pub struct SearchConfig {
     pub query_pattern: String,
     #[serde(default = "default_result_limit")]
     pub limit: usize,
+    /// Safety cap on the number of results a single search may request
+    #[serde(default = "default_max_result_limit")]
+    pub max_limit: usize,
+    /// API key for the `serpapi` engine
+    pub serpapi_key: Option<String>,
+    /// Further SerpApi keys, rotated per request together with `serpapi_key`
+    #[serde(default)]
+    pub serpapi_keys: Vec<String>,
+    /// Engine SerpApi queries on our behalf, e.g. "google" or "bing"
+    #[serde(default = "default_serpapi_engine")]
+    pub serpapi_engine: String,
+    /// Azure subscription key for the `bing_api` engine
+    pub bing_api_key: Option<String>,
+    /// Google API key for the `google_cse` engine
+    pub google_cse_key: Option<String>,
+    /// Programmable Search Engine ID (`cx`) queried by the `google_cse` engine
+    pub google_cse_cx: Option<String>,
+    /// Base URL of the SearxNG instance queried by the `searxng` engine, e.g.
+    /// `https://searx.example.com`
+    pub searxng_base_url: Option<String>,
+    /// Engines the SearxNG instance should query, e.g. `["google", "wikipedia"]`;
+    /// empty uses the instance's defaults
+    #[serde(default)]
+    pub searxng_engines: Vec<String>,
+    /// Base URLs replacing an engine's default domain, keyed by engine name,
+    /// e.g. `google = "https://www.google.de"` or a self-hosted mirror
+    #[serde(default)]
+    pub provider_urls: HashMap<String, String>,
+    /// Only keep results from these domains (and their subdomains)
+    #[serde(default)]
+    pub include_domains: Vec<String>,
+    /// Drop results from these domains (and their subdomains)
+    #[serde(default)]
+    pub exclude_domains: Vec<String>,
+    /// When to fall back to `fallback_engines`: "none", "smart", "on_error" or "on_empty"
+    #[serde(default = "default_autoswitch")]
+    pub autoswitch: String,
+    /// Engines tried in order after the primary engine, per `autoswitch`
+    #[serde(default)]
+    pub fallback_engines: Vec<String>,
+    /// Country results are localized for, as an ISO 3166-1 code such as "us" or "de"
+    pub country: Option<String>,
+    /// Language of results and interface, as an ISO 639-1 code such as "en" or "de"
+    pub language: Option<String>,
+    /// Filtering of explicit results: "off", "moderate" or "strict"
+    #[serde(default = "default_safe_search")]
+    pub safe_search: String,
+    /// Snippets longer than this many characters are cut and end with "…"; 0
+    /// keeps them whole
+    #[serde(default)]
+    pub max_snippet_len: usize,
+}
+
+/// Options for converting fetched pages
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConverterConfig {
+    /// Extract the main article content before conversion, dropping navigation,
+    /// headers, footers, sidebars and cookie banners
+    #[serde(default)]
+    pub readability: bool,
 }
 
 /// CLI configuration parameters that can override config file values
 #[derive(Debug, Clone)]
 pub struct CliConfigParams {
     pub fetcher_format: Option<String>,
+    pub fetcher_mode: Option<String>,
     pub search_limit: Option<usize>,
     pub search_engine: Option<String>,
+    pub log_format: Option<String>,
 }
 
 impl CliConfigParams {
     pub fn new() -> Self {
         Self {
             fetcher_format: None,
+            fetcher_mode: None,
             search_limit: None,
             search_engine: None,
+            log_format: None,
         }
     }
 }
@@ -81,13 +261,16 @@ impl Config {
             general: GeneralConfig::default(),
             fetcher: FetcherConfig::default(),
             search: SearchConfig::default(),
+            converter: ConverterConfig::default(),
         }
     }
 
     /// Load configuration with proper precedence order:
     /// 1. CLI parameters (highest priority)
-    /// 2. tarzi.toml (project config)
-    /// 3. Default values (lowest priority)
+    /// 2. API keys from the environment (`TARZI_SERPAPI_KEY`, `TARZI_BING_API_KEY`,
+    ///    `TARZI_GOOGLE_CSE_KEY`)
+    /// 3. tarzi.toml (project config)
+    /// 4. Default values (lowest priority)
     pub fn load() -> Result<Self> {
         // Start with default config
         let mut config = Config::new();
@@ -98,6 +281,63 @@ impl Config {
             config.merge(&project_config);
         }
 
+        config.apply_env_api_keys();
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check the settings that refer to files, so a broken setup fails when the
+    /// config is loaded rather than with an opaque error on the first request:
+    /// `fetcher.ca_cert_path` must be a readable PEM certificate
+    pub fn validate(&self) -> Result<()> {
+        if let Some(ca_cert_path) = &self.fetcher.ca_cert_path {
+            load_ca_certificate(ca_cert_path)?;
+        }
+        Ok(())
+    }
+
+    /// Override API keys with the ones set in the environment, so keys need not
+    /// be kept in committed config files
+    pub fn apply_env_api_keys(&mut self) {
+        self.search.serpapi_key =
+            get_api_key_from_env_or_config(ENV_SERPAPI_KEY, &self.search.serpapi_key);
+        self.search.bing_api_key =
+            get_api_key_from_env_or_config(ENV_BING_API_KEY, &self.search.bing_api_key);
+        self.search.google_cse_key =
+            get_api_key_from_env_or_config(ENV_GOOGLE_CSE_KEY, &self.search.google_cse_key);
+    }
+
+    /// Load only the config file at `path`, on top of the defaults, skipping the
+    /// usual precedence chain (API keys from the environment still apply). The
+    /// format follows the extension: `.toml`, `.yaml`/`.yml`, or `.json`.
+    pub fn load_from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        let content = fs::read_to_string(path).map_err(|e| {
+            TarziError::Config(format!(
+                "Failed to read config file {}: {e}",
+                path.display()
+            ))
+        })?;
+        let parse_error = |e: &dyn std::fmt::Display| {
+            TarziError::Config(format!(
+                "Failed to parse config file {}: {e}",
+                path.display()
+            ))
+        };
+        let mut config: Config = match extension.as_deref() {
+            Some("toml") => toml::from_str(&content).map_err(|e| parse_error(&e)),
+            Some("yaml" | "yml") => serde_yaml::from_str(&content).map_err(|e| parse_error(&e)),
+            Some("json") => serde_json::from_str(&content).map_err(|e| parse_error(&e)),
+            _ => Err(TarziError::Config(format!(
+                "Unsupported config file {} (expected a .toml, .yaml, .yml, or .json file)",
+                path.display()
+            ))),
+        }?;
+        config.apply_env_api_keys();
+        config.validate()?;
         Ok(config)
     }
 
@@ -107,6 +347,9 @@ impl Config {
         if other.general.log_level != default_log_level() {
             self.general.log_level = other.general.log_level.clone();
         }
+        if other.general.log_format != default_log_format() {
+            self.general.log_format = other.general.log_format.clone();
+        }
         if other.general.timeout != default_timeout() {
             self.general.timeout = other.general.timeout;
         }
@@ -127,12 +370,64 @@ impl Config {
         if other.fetcher.proxy.is_some() {
             self.fetcher.proxy = other.fetcher.proxy.clone();
         }
+        if !other.fetcher.proxy_pool.is_empty() {
+            self.fetcher.proxy_pool = other.fetcher.proxy_pool.clone();
+        }
         if other.fetcher.web_driver != default_web_driver() {
             self.fetcher.web_driver = other.fetcher.web_driver.clone();
         }
         if other.fetcher.web_driver_url.is_some() {
             self.fetcher.web_driver_url = other.fetcher.web_driver_url.clone();
         }
+        if other.fetcher.browser_debugger_address.is_some() {
+            self.fetcher.browser_debugger_address = other.fetcher.browser_debugger_address.clone();
+        }
+        if other.fetcher.script_timeout.is_some() {
+            self.fetcher.script_timeout = other.fetcher.script_timeout;
+        }
+        if other.fetcher.page_load_timeout.is_some() {
+            self.fetcher.page_load_timeout = other.fetcher.page_load_timeout;
+        }
+        if other.fetcher.wait_for_selector.is_some() {
+            self.fetcher.wait_for_selector = other.fetcher.wait_for_selector.clone();
+        }
+        if other.fetcher.reuse_page != default_reuse_page() {
+            self.fetcher.reuse_page = other.fetcher.reuse_page;
+        }
+        if other.fetcher.reuse_browser {
+            self.fetcher.reuse_browser = true;
+        }
+        if other.fetcher.danger_accept_invalid_certs {
+            self.fetcher.danger_accept_invalid_certs = true;
+        }
+        if other.fetcher.ca_cert_path.is_some() {
+            self.fetcher.ca_cert_path = other.fetcher.ca_cert_path.clone();
+        }
+        if other.fetcher.auth.is_some() {
+            self.fetcher.auth = other.fetcher.auth.clone();
+        }
+        if !other.fetcher.auth_hosts.is_empty() {
+            self.fetcher.auth_hosts = other.fetcher.auth_hosts.clone();
+        }
+        self.fetcher
+            .host_overrides
+            .extend(other.fetcher.host_overrides.clone());
+        self.fetcher.headers.extend(other.fetcher.headers.clone());
+        if other.fetcher.cookie_store {
+            self.fetcher.cookie_store = true;
+        }
+        if other.fetcher.max_retries != 0 {
+            self.fetcher.max_retries = other.fetcher.max_retries;
+        }
+        if other.fetcher.retry_backoff_ms != default_retry_backoff_ms() {
+            self.fetcher.retry_backoff_ms = other.fetcher.retry_backoff_ms;
+        }
+        if other.fetcher.concurrency != default_concurrency() {
+            self.fetcher.concurrency = other.fetcher.concurrency;
+        }
+        if other.fetcher.auto_download_driver {
+            self.fetcher.auto_download_driver = true;
+        }
 
         // Merge search config
         if other.search.engine != default_search_engine() {
@@ -144,6 +439,67 @@ impl Config {
         if other.search.query_pattern != default_query_pattern() {
             self.search.query_pattern = other.search.query_pattern.clone();
         }
+        if other.search.max_limit != default_max_result_limit() {
+            self.search.max_limit = other.search.max_limit;
+        }
+        if other.search.serpapi_key.is_some() {
+            self.search.serpapi_key = other.search.serpapi_key.clone();
+        }
+        if !other.search.serpapi_keys.is_empty() {
+            self.search.serpapi_keys = other.search.serpapi_keys.clone();
+        }
+        if other.search.bing_api_key.is_some() {
+            self.search.bing_api_key = other.search.bing_api_key.clone();
+        }
+        if other.search.google_cse_key.is_some() {
+            self.search.google_cse_key = other.search.google_cse_key.clone();
+        }
+        if other.search.google_cse_cx.is_some() {
+            self.search.google_cse_cx = other.search.google_cse_cx.clone();
+        }
+        if other.search.searxng_base_url.is_some() {
+            self.search.searxng_base_url = other.search.searxng_base_url.clone();
+        }
+        if !other.search.searxng_engines.is_empty() {
+            self.search.searxng_engines = other.search.searxng_engines.clone();
+        }
+        if other.search.serpapi_engine != default_serpapi_engine() {
+            self.search.serpapi_engine = other.search.serpapi_engine.clone();
+        }
+        if !other.search.include_domains.is_empty() {
+            self.search.include_domains = other.search.include_domains.clone();
+        }
+        if !other.search.exclude_domains.is_empty() {
+            self.search.exclude_domains = other.search.exclude_domains.clone();
+        }
+        if other.search.autoswitch != default_autoswitch() {
+            self.search.autoswitch = other.search.autoswitch.clone();
+        }
+        if !other.search.fallback_engines.is_empty() {
+            self.search.fallback_engines = other.search.fallback_engines.clone();
+        }
+        if other.search.country.is_some() {
+            self.search.country = other.search.country.clone();
+        }
+        if other.search.language.is_some() {
+            self.search.language = other.search.language.clone();
+        }
+        if other.search.safe_search != default_safe_search() {
+            self.search.safe_search = other.search.safe_search.clone();
+        }
+        if other.search.max_snippet_len != 0 {
+            self.search.max_snippet_len = other.search.max_snippet_len;
+        }
+        for (engine, url) in &other.search.provider_urls {
+            self.search
+                .provider_urls
+                .insert(engine.clone(), url.clone());
+        }
+
+        // Merge converter config
+        if other.converter.readability {
+            self.converter.readability = true;
+        }
     }
 
     /// Apply CLI parameters to config (highest priority)
@@ -151,12 +507,18 @@ impl Config {
         if let Some(format) = &cli_params.fetcher_format {
             self.fetcher.format = format.clone();
         }
+        if let Some(mode) = &cli_params.fetcher_mode {
+            self.fetcher.mode = mode.clone();
+        }
         if let Some(limit) = cli_params.search_limit {
             self.search.limit = limit;
         }
         if let Some(engine) = &cli_params.search_engine {
             self.search.engine = engine.clone();
         }
+        if let Some(log_format) = &cli_params.log_format {
+            self.general.log_format = log_format.clone();
+        }
     }
 
     pub fn get_dev_config_path() -> PathBuf {
@@ -198,6 +560,7 @@ impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
             log_level: default_log_level(),
+            log_format: default_log_format(),
             timeout: default_timeout(),
         }
     }
@@ -211,8 +574,26 @@ impl Default for FetcherConfig {
             user_agent: default_user_agent(),
             timeout: default_fetch_timeout(),
             proxy: None,
+            proxy_pool: Vec::new(),
             web_driver: default_web_driver(),
             web_driver_url: None,
+            browser_debugger_address: None,
+            script_timeout: None,
+            page_load_timeout: None,
+            wait_for_selector: None,
+            reuse_page: default_reuse_page(),
+            reuse_browser: false,
+            danger_accept_invalid_certs: false,
+            ca_cert_path: None,
+            auth: None,
+            auth_hosts: Vec::new(),
+            host_overrides: HashMap::new(),
+            headers: HashMap::new(),
+            cookie_store: false,
+            max_retries: 0,
+            retry_backoff_ms: default_retry_backoff_ms(),
+            concurrency: default_concurrency(),
+            auto_download_driver: false,
         }
     }
 }
@@ -223,6 +604,24 @@ impl Default for SearchConfig {
             engine: default_search_engine(),
             query_pattern: default_query_pattern(),
             limit: default_result_limit(),
+            max_limit: default_max_result_limit(),
+            serpapi_key: None,
+            serpapi_keys: Vec::new(),
+            serpapi_engine: default_serpapi_engine(),
+            bing_api_key: None,
+            google_cse_key: None,
+            google_cse_cx: None,
+            searxng_base_url: None,
+            searxng_engines: Vec::new(),
+            provider_urls: HashMap::new(),
+            include_domains: Vec::new(),
+            exclude_domains: Vec::new(),
+            autoswitch: default_autoswitch(),
+            fallback_engines: Vec::new(),
+            country: None,
+            language: None,
+            safe_search: default_safe_search(),
+            max_snippet_len: 0,
         }
     }
 }
@@ -238,6 +637,10 @@ fn default_log_level() -> String {
     LOG_LEVEL_INFO.to_string()
 }
 
+fn default_log_format() -> String {
+    LOG_FORMAT_TEXT.to_string()
+}
+
 fn default_timeout() -> u64 {
     DEFAULT_TIMEOUT_SECS
 }
@@ -258,6 +661,18 @@ fn default_fetch_timeout() -> u64 {
     30
 }
 
+fn default_reuse_page() -> bool {
+    true
+}
+
+fn default_retry_backoff_ms() -> u64 {
+    DEFAULT_RETRY_BACKOFF_MS
+}
+
+fn default_concurrency() -> usize {
+    DEFAULT_FETCH_CONCURRENCY
+}
+
 fn default_search_engine() -> String {
     SEARCH_ENGINE_BING.to_string()
 }
@@ -270,10 +685,35 @@ fn default_result_limit() -> usize {
     DEFAULT_SEARCH_LIMIT
 }
 
+fn default_max_result_limit() -> usize {
+    DEFAULT_MAX_SEARCH_LIMIT
+}
+
+fn default_serpapi_engine() -> String {
+    DEFAULT_SERPAPI_ENGINE.to_string()
+}
+
+fn default_autoswitch() -> String {
+    AUTOSWITCH_NONE.to_string()
+}
+
+fn default_safe_search() -> String {
+    SAFE_SEARCH_MODERATE.to_string()
+}
+
 fn default_web_driver() -> String {
     "chromedriver".to_string()
 }
 
+/// Load a PEM-encoded CA certificate to add to the HTTP client's trust store
+pub(crate) fn load_ca_certificate(path: &str) -> Result<reqwest::Certificate> {
+    let load_error = |e: &dyn std::fmt::Display| {
+        TarziError::Config(format!("Failed to load CA certificate {path}: {e}"))
+    };
+    let pem = fs::read(path).map_err(|e| load_error(&e))?;
+    reqwest::Certificate::from_pem(&pem).map_err(|e| load_error(&e))
+}
+
 /// Get proxy configuration with environment variable override
 /// Environment variables checked in order: HTTP_PROXY, HTTPS_PROXY, http_proxy, https_proxy
 /// Falls back to config.proxy if no environment variables are set
@@ -282,10 +722,10 @@ pub fn get_proxy_from_env_or_config(config_proxy: &Option<String>) -> Option<Str
     let env_vars = ["HTTPS_PROXY", "HTTP_PROXY", "https_proxy", "http_proxy"];
 
     for env_var in &env_vars {
-        if let Ok(proxy) = std::env::var(env_var) {
-            if !proxy.is_empty() {
-                return Some(proxy);
-            }
+        if let Ok(proxy) = std::env::var(env_var)
+            && !proxy.is_empty()
+        {
+            return Some(proxy);
         }
     }
 
@@ -293,10 +733,23 @@ pub fn get_proxy_from_env_or_config(config_proxy: &Option<String>) -> Option<Str
     config_proxy.clone()
 }
 
+/// API key from the environment variable `env_name`, falling back to the
+/// configured key when the variable is unset or empty
+pub fn get_api_key_from_env_or_config(
+    env_name: &str,
+    config_value: &Option<String>,
+) -> Option<String> {
+    match std::env::var(env_name) {
+        Ok(key) if !key.is_empty() => Some(key),
+        _ => config_value.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::constants::*;
+    use std::collections::HashMap;
     use std::fs;
     use tempfile::tempdir;
 
@@ -305,6 +758,7 @@ mod tests {
         let config = Config::new();
 
         assert_eq!(config.general.log_level, LOG_LEVEL_INFO);
+        assert_eq!(config.general.log_format, LOG_FORMAT_TEXT);
         assert_eq!(config.general.timeout, DEFAULT_TIMEOUT_SECS);
         assert_eq!(config.fetcher.mode, FETCHER_MODE_BROWSER_HEADLESS);
         assert_eq!(config.fetcher.format, FORMAT_MARKDOWN);
@@ -313,6 +767,8 @@ mod tests {
             crate::constants::DEFAULT_USER_AGENT
         );
         assert_eq!(config.fetcher.timeout, 30);
+        assert!(!config.fetcher.danger_accept_invalid_certs);
+        assert_eq!(config.fetcher.ca_cert_path, None);
         assert_eq!(config.search.engine, SEARCH_ENGINE_BING);
         assert_eq!(config.search.query_pattern, DEFAULT_QUERY_PATTERN);
         assert_eq!(config.search.limit, DEFAULT_SEARCH_LIMIT);
@@ -353,6 +809,128 @@ mod tests {
         assert_eq!(loaded_config.general.log_level, LOG_LEVEL_DEBUG);
     }
 
+    #[test]
+    fn test_load_from_path_formats() {
+        let temp_dir = tempdir().unwrap();
+        let files = [
+            (
+                "tarzi.toml",
+                "[fetcher]\nmode = \"plain_request\"\n\n[search]\nengine = \"google\"\nlimit = 7\n",
+            ),
+            (
+                "tarzi.yaml",
+                "fetcher:\n  mode: plain_request\nsearch:\n  engine: google\n  limit: 7\n",
+            ),
+            (
+                "tarzi.yml",
+                "fetcher:\n  mode: plain_request\nsearch:\n  engine: google\n  limit: 7\n",
+            ),
+            (
+                "tarzi.json",
+                r#"{"fetcher": {"mode": "plain_request"}, "search": {"engine": "google", "limit": 7}}"#,
+            ),
+        ];
+
+        for (name, content) in files {
+            let path = temp_dir.path().join(name);
+            fs::write(&path, content).unwrap();
+            let config = Config::load_from_path(&path).unwrap();
+            assert_eq!(config.fetcher.mode, FETCHER_MODE_PLAIN_REQUEST, "{name}");
+            assert_eq!(config.search.engine, SEARCH_ENGINE_GOOGLE, "{name}");
+            assert_eq!(config.search.limit, 7, "{name}");
+            // Everything else keeps its default
+            assert_eq!(config.general.log_level, LOG_LEVEL_INFO, "{name}");
+            assert_eq!(config.fetcher.format, FORMAT_MARKDOWN, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_load_from_path_errors() {
+        let temp_dir = tempdir().unwrap();
+
+        let ini = temp_dir.path().join("tarzi.ini");
+        fs::write(&ini, "[search]\nlimit = 7\n").unwrap();
+        let error = Config::load_from_path(&ini).unwrap_err().to_string();
+        assert!(error.contains("Unsupported config file"), "{error}");
+
+        let broken = temp_dir.path().join("tarzi.json");
+        fs::write(&broken, "{").unwrap();
+        let error = Config::load_from_path(&broken).unwrap_err().to_string();
+        assert!(error.contains("Failed to parse config file"), "{error}");
+
+        let missing = temp_dir.path().join("missing.toml");
+        assert!(Config::load_from_path(&missing).is_err());
+    }
+
+    #[test]
+    fn test_load_from_path_invalid_ca_cert() {
+        let temp_dir = tempdir().unwrap();
+        let bad_pem = temp_dir.path().join("bad.pem");
+        fs::write(&bad_pem, "not a certificate").unwrap();
+
+        for ca_cert_path in [temp_dir.path().join("missing.pem"), bad_pem] {
+            let path = temp_dir.path().join("tarzi.toml");
+            fs::write(
+                &path,
+                format!(
+                    "[fetcher]\nca_cert_path = {:?}\n",
+                    ca_cert_path.display().to_string()
+                ),
+            )
+            .unwrap();
+            let error = Config::load_from_path(&path).unwrap_err();
+            assert!(matches!(error, TarziError::Config(_)), "{error:?}");
+            assert!(
+                error.to_string().contains("Failed to load CA certificate"),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_api_keys_from_env() {
+        use std::sync::Mutex;
+
+        // Serialize the tests that change these environment variables
+        static ENV_LOCK: Mutex<()> = Mutex::new(());
+        let _guard = ENV_LOCK.lock().unwrap();
+        let original = std::env::var(ENV_SERPAPI_KEY).ok();
+
+        let file_key = Some("file-key".to_string());
+        unsafe {
+            std::env::remove_var(ENV_SERPAPI_KEY);
+        }
+        assert_eq!(
+            get_api_key_from_env_or_config(ENV_SERPAPI_KEY, &file_key),
+            file_key
+        );
+
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("tarzi.toml");
+        fs::write(&path, "[search]\nserpapi_key = \"file-key\"\n").unwrap();
+
+        // The environment wins over the config file
+        unsafe {
+            std::env::set_var(ENV_SERPAPI_KEY, "env-key");
+        }
+        let config = Config::load_from_path(&path).unwrap();
+        assert_eq!(config.search.serpapi_key.as_deref(), Some("env-key"));
+
+        // An empty variable falls back to the config file
+        unsafe {
+            std::env::set_var(ENV_SERPAPI_KEY, "");
+        }
+        let config = Config::load_from_path(&path).unwrap();
+        assert_eq!(config.search.serpapi_key.as_deref(), Some("file-key"));
+
+        unsafe {
+            match original {
+                Some(key) => std::env::set_var(ENV_SERPAPI_KEY, key),
+                None => std::env::remove_var(ENV_SERPAPI_KEY),
+            }
+        }
+    }
+
     #[test]
     fn test_dev_config_path() {
         let dev_path = Config::get_dev_config_path();
@@ -379,6 +957,9 @@ web_driver_url = "http://example.com/driver"
 engine = "google.com"
 query_pattern = ".*"
 limit = 5
+
+[search.provider_urls]
+duckduckgo = "https://ddg.example.com"
 "#;
 
         let config: Config = toml::from_str(config_str).unwrap();
@@ -401,6 +982,45 @@ limit = 5
         assert_eq!(config.search.engine, "google.com");
         assert_eq!(config.search.query_pattern, ".*");
         assert_eq!(config.search.limit, 5);
+        assert_eq!(
+            config
+                .search
+                .provider_urls
+                .get("duckduckgo")
+                .map(String::as_str),
+            Some("https://ddg.example.com")
+        );
+    }
+
+    #[test]
+    fn test_config_with_auth() {
+        let config_str = r#"
+[fetcher]
+auth = { type = "basic", username = "alice", password = "hunter2" }
+auth_hosts = ["api.example.com"]
+"#;
+        let config: Config = toml::from_str(config_str).unwrap();
+        assert_eq!(
+            config.fetcher.auth,
+            Some(AuthConfig::Basic {
+                username: "alice".to_string(),
+                password: Some("hunter2".to_string()),
+            })
+        );
+        assert_eq!(config.fetcher.auth_hosts, ["api.example.com"]);
+
+        let config_str = r#"
+[fetcher]
+auth = { type = "bearer", token = "secret-token" }
+"#;
+        let config: Config = toml::from_str(config_str).unwrap();
+        let auth = config.fetcher.auth.unwrap();
+        assert_eq!(auth.scheme(), "bearer");
+
+        // Credentials must never show up in debug output
+        let debug = format!("{auth:?}");
+        assert!(!debug.contains("secret-token"));
+        assert!(debug.contains("<redacted>"));
     }
 
     #[test]
@@ -650,6 +1270,11 @@ limit = 10
         assert_eq!(config.fetcher.format, FORMAT_JSON);
         assert_eq!(config.search.limit, DEFAULT_SEARCH_LIMIT);
         assert_eq!(config.search.engine, SEARCH_ENGINE_GOOGLE);
+
+        // Mode override, e.g. from --no-browser
+        cli_params.fetcher_mode = Some(FETCHER_MODE_PLAIN_REQUEST.to_string());
+        config.apply_cli_params(&cli_params);
+        assert_eq!(config.fetcher.mode, FETCHER_MODE_PLAIN_REQUEST);
     }
 
     #[test]
@@ -664,6 +1289,7 @@ limit = 10
         let override_config = Config {
             general: GeneralConfig {
                 log_level: LOG_LEVEL_DEBUG.to_string(),
+                log_format: LOG_FORMAT_JSON.to_string(),
                 timeout: 60,
             },
             fetcher: FetcherConfig {
@@ -672,14 +1298,59 @@ limit = 10
                 user_agent: "Custom Agent".to_string(),
                 timeout: 45,
                 proxy: Some("http://proxy:8080".to_string()),
+                proxy_pool: vec!["http://proxy-a:8080".to_string()],
                 web_driver: CHROMEDRIVER.to_string(),
                 web_driver_url: Some("http://localhost:4444".to_string()),
+                browser_debugger_address: Some("127.0.0.1:9222".to_string()),
+                script_timeout: Some(90),
+                page_load_timeout: Some(120),
+                wait_for_selector: Some("#content".to_string()),
+                reuse_page: false,
+                reuse_browser: true,
+                danger_accept_invalid_certs: true,
+                ca_cert_path: Some("/etc/ssl/certs/internal-ca.pem".to_string()),
+                auth: Some(AuthConfig::Bearer {
+                    token: "secret-token".to_string(),
+                }),
+                auth_hosts: vec!["api.example.com".to_string()],
+                host_overrides: HashMap::from([(
+                    "example.com".to_string(),
+                    IpAddr::from([10, 0, 0, 5]),
+                )]),
+                headers: HashMap::from([("Accept-Language".to_string(), "de".to_string())]),
+                cookie_store: true,
+                max_retries: 3,
+                retry_backoff_ms: 250,
+                concurrency: 8,
+                auto_download_driver: true,
             },
             search: SearchConfig {
                 engine: SEARCH_ENGINE_GOOGLE.to_string(),
                 query_pattern: "custom pattern".to_string(),
                 limit: DEFAULT_SEARCH_LIMIT,
+                max_limit: 30,
+                serpapi_key: Some("serpapi-key".to_string()),
+                serpapi_keys: vec!["second-key".to_string()],
+                serpapi_engine: "bing".to_string(),
+                bing_api_key: Some("bing-key".to_string()),
+                google_cse_key: Some("cse-key".to_string()),
+                google_cse_cx: Some("cse-cx".to_string()),
+                searxng_base_url: Some("https://searx.example.com".to_string()),
+                searxng_engines: vec!["wikipedia".to_string()],
+                provider_urls: HashMap::from([(
+                    "google".to_string(),
+                    "https://www.google.de".to_string(),
+                )]),
+                include_domains: vec!["rust-lang.org".to_string()],
+                exclude_domains: vec!["pinterest.com".to_string()],
+                autoswitch: "on_empty".to_string(),
+                fallback_engines: vec!["searxng".to_string()],
+                country: Some("de".to_string()),
+                language: Some("de".to_string()),
+                safe_search: "strict".to_string(),
+                max_snippet_len: 200,
             },
+            converter: ConverterConfig { readability: true },
         };
 
         // Merge override config into base config
@@ -687,6 +1358,7 @@ limit = 10
 
         // Override config values should take precedence
         assert_eq!(base_config.general.log_level, LOG_LEVEL_DEBUG);
+        assert_eq!(base_config.general.log_format, LOG_FORMAT_JSON);
         assert_eq!(base_config.general.timeout, 60);
         assert_eq!(base_config.fetcher.mode, FETCHER_MODE_PLAIN_REQUEST);
         assert_eq!(base_config.fetcher.format, FORMAT_JSON);
@@ -696,13 +1368,83 @@ limit = 10
             base_config.fetcher.proxy,
             Some("http://proxy:8080".to_string())
         );
+        assert_eq!(base_config.fetcher.proxy_pool, vec!["http://proxy-a:8080"]);
         assert_eq!(base_config.fetcher.web_driver, CHROMEDRIVER);
         assert_eq!(
             base_config.fetcher.web_driver_url,
             Some("http://localhost:4444".to_string())
         );
+        assert_eq!(
+            base_config.fetcher.browser_debugger_address,
+            Some("127.0.0.1:9222".to_string())
+        );
+        assert_eq!(base_config.fetcher.script_timeout, Some(90));
+        assert!(!base_config.fetcher.reuse_page);
+        assert!(base_config.fetcher.reuse_browser);
+        assert_eq!(base_config.fetcher.page_load_timeout, Some(120));
+        assert_eq!(
+            base_config.fetcher.wait_for_selector,
+            Some("#content".to_string())
+        );
+        assert!(base_config.fetcher.danger_accept_invalid_certs);
+        assert_eq!(
+            base_config.fetcher.ca_cert_path,
+            Some("/etc/ssl/certs/internal-ca.pem".to_string())
+        );
+        assert_eq!(base_config.fetcher.auth_hosts, vec!["api.example.com"]);
+        assert_eq!(
+            base_config.fetcher.host_overrides.get("example.com"),
+            Some(&IpAddr::from([10, 0, 0, 5]))
+        );
+        assert_eq!(
+            base_config.fetcher.headers.get("Accept-Language"),
+            Some(&"de".to_string())
+        );
+        assert!(base_config.fetcher.cookie_store);
+        assert_eq!(base_config.fetcher.max_retries, 3);
+        assert_eq!(base_config.fetcher.retry_backoff_ms, 250);
+        assert_eq!(base_config.fetcher.concurrency, 8);
+        assert!(base_config.fetcher.auto_download_driver);
         assert_eq!(base_config.search.engine, SEARCH_ENGINE_GOOGLE);
         assert_eq!(base_config.search.query_pattern, "custom pattern");
         assert_eq!(base_config.search.limit, DEFAULT_SEARCH_LIMIT);
+        assert_eq!(
+            base_config.search.serpapi_key,
+            Some("serpapi-key".to_string())
+        );
+        assert_eq!(base_config.search.max_limit, 30);
+        assert_eq!(base_config.search.serpapi_keys, vec!["second-key"]);
+        assert_eq!(
+            base_config.search.bing_api_key,
+            Some("bing-key".to_string())
+        );
+        assert_eq!(
+            base_config.search.google_cse_key,
+            Some("cse-key".to_string())
+        );
+        assert_eq!(base_config.search.google_cse_cx, Some("cse-cx".to_string()));
+        assert_eq!(
+            base_config.search.searxng_base_url,
+            Some("https://searx.example.com".to_string())
+        );
+        assert_eq!(base_config.search.searxng_engines, vec!["wikipedia"]);
+        assert_eq!(base_config.search.serpapi_engine, "bing");
+        assert_eq!(base_config.search.include_domains, vec!["rust-lang.org"]);
+        assert_eq!(base_config.search.exclude_domains, vec!["pinterest.com"]);
+        assert_eq!(base_config.search.autoswitch, "on_empty");
+        assert_eq!(base_config.search.fallback_engines, vec!["searxng"]);
+        assert_eq!(base_config.search.country, Some("de".to_string()));
+        assert_eq!(base_config.search.language, Some("de".to_string()));
+        assert_eq!(base_config.search.safe_search, "strict");
+        assert_eq!(base_config.search.max_snippet_len, 200);
+        assert_eq!(
+            base_config
+                .search
+                .provider_urls
+                .get("google")
+                .map(String::as_str),
+            Some("https://www.google.de")
+        );
+        assert!(base_config.converter.readability);
     }
 }
@@ -18,6 +18,32 @@ pub const CHROMEDRIVER_DEFAULT_PORT: u16 = 9515;
 /// Default GeckoDriver port
 pub const GECKODRIVER_DEFAULT_PORT: u16 = 4444;
 
+/// Directory, under the user's runtime or cache directory, holding tarzi's
+/// per-user files
+pub const USER_DIR_NAME: &str = "tarzi";
+
+/// File in the per-user state directory recording the driver started by
+/// `tarzi driver start`
+pub const PERSISTENT_DRIVER_STATE_FILE: &str = "driver.json";
+
+/// Directory, under the user's cache directory, drivers are downloaded to when
+/// `fetcher.auto_download_driver` is enabled
+pub const DRIVER_CACHE_DIR: &str = "drivers";
+
+/// Chrome for Testing endpoints naming the chromedriver release for a Chrome
+/// major version (`LATEST_RELEASE_<major>`) or the latest one (`LATEST_RELEASE_STABLE`)
+pub const CHROME_FOR_TESTING_URL: &str = "https://googlechromelabs.github.io/chrome-for-testing";
+
+/// Base URL of Chrome for Testing downloads
+pub const CHROMEDRIVER_DOWNLOAD_URL: &str =
+    "https://storage.googleapis.com/chrome-for-testing-public";
+
+/// GeckoDriver releases on GitHub
+pub const GECKODRIVER_RELEASES_URL: &str = "https://github.com/mozilla/geckodriver/releases";
+
+/// Timeout in seconds for downloading a driver
+pub const DRIVER_DOWNLOAD_TIMEOUT_SECS: u64 = 120;
+
 /// Default HTTP client user agent
 pub const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
 
@@ -49,12 +75,47 @@ pub const BROWSER_LAUNCH_TIMEOUT_SECS: u64 = 60;
 /// Browser launch timeout duration
 pub const BROWSER_LAUNCH_TIMEOUT: Duration = Duration::from_secs(BROWSER_LAUNCH_TIMEOUT_SECS);
 
+/// Browser session teardown timeout in seconds
+pub const BROWSER_QUIT_TIMEOUT_SECS: u64 = 10;
+
+/// Browser session teardown timeout duration
+pub const BROWSER_QUIT_TIMEOUT: Duration = Duration::from_secs(BROWSER_QUIT_TIMEOUT_SECS);
+
+/// Browser instance ID of an attached external browser
+pub const EXTERNAL_BROWSER_ID: &str = "external";
+
+/// External browser debugging endpoint reachability check timeout in seconds
+pub const EXTERNAL_BROWSER_CHECK_TIMEOUT_SECS: u64 = 2;
+
+/// External browser debugging endpoint reachability check timeout duration
+pub const EXTERNAL_BROWSER_CHECK_TIMEOUT: Duration =
+    Duration::from_secs(EXTERNAL_BROWSER_CHECK_TIMEOUT_SECS);
+
 /// Page load wait time in seconds
 pub const PAGE_LOAD_WAIT_SECS: u64 = 2;
 
 /// Page load wait duration
 pub const PAGE_LOAD_WAIT: Duration = Duration::from_secs(PAGE_LOAD_WAIT_SECS);
 
+/// Interval between checks while waiting for a CSS selector to appear
+pub const SELECTOR_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Delay after a page script runs, letting the changes it triggers render
+pub const SCRIPT_SETTLE_WAIT: Duration = Duration::from_millis(500);
+
+/// Delay in milliseconds before the first retry of a failed plain request
+pub const DEFAULT_RETRY_BACKOFF_MS: u64 = 500;
+
+/// Plain requests run at once when fetching the content of several search results
+pub const DEFAULT_FETCH_CONCURRENCY: usize = 4;
+
+/// How long a proxy of `fetcher.proxy_pool` that could not be reached is skipped
+pub const PROXY_COOLDOWN_SECS: u64 = 60;
+pub const PROXY_COOLDOWN: Duration = Duration::from_secs(PROXY_COOLDOWN_SECS);
+
+/// HTTP statuses worth retrying: rate limiting and transient server errors
+pub const RETRYABLE_STATUS_CODES: [u16; 5] = [429, 500, 502, 503, 504];
+
 // ============================================================================
 // Test URLs
 // ============================================================================
@@ -121,11 +182,79 @@ pub const DEFAULT_FORMAT: &str = "markdown";
 /// Default search engine
 pub const DEFAULT_SEARCH_ENGINE: &str = "bing";
 
+/// Schema version of serialized search results; bump on breaking changes
+pub const SEARCH_RESULTS_SCHEMA_VERSION: u32 = 1;
+
+/// Upper bound on the number of results a single search may request
+pub const DEFAULT_MAX_SEARCH_LIMIT: usize = 100;
+
+/// Upper bound on the results pages requested for a single search
+pub const MAX_SEARCH_PAGES: usize = 20;
+
+/// Minimum length of a paragraph used as a page summary fallback
+pub const MIN_SUMMARY_PARAGRAPH_CHARS: usize = 40;
+
+/// Maximum length of the description written to YAML frontmatter
+pub const FRONTMATTER_DESCRIPTION_CHARS: usize = 300;
+
+/// Appended to converter output cut short by `max_output_chars`
+pub const TRUNCATION_MARKER: &str = "\n\n… (truncated)";
+
+/// Number of pages a fetch cache holds before evicting the oldest
+pub const DEFAULT_FETCH_CACHE_CAPACITY: usize = 256;
+
+/// How long a fetch cache serves a page before fetching it again
+pub const DEFAULT_FETCH_CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// Link depth `tarzi crawl` follows from the start page by default
+pub const DEFAULT_CRAWL_DEPTH: usize = 2;
+
+/// Upper bound on the pages a single crawl fetches by default
+pub const DEFAULT_CRAWL_MAX_PAGES: usize = 50;
+
+/// Minimum pause between crawl requests, unless robots.txt asks for more
+pub const DEFAULT_CRAWL_DELAY: Duration = Duration::from_millis(500);
+
+/// File listing the crawled pages and their links in a crawl's output directory
+pub const CRAWL_MANIFEST_FILE: &str = "manifest.json";
+
+// ============================================================================
+// CLI Exit Codes
+// ============================================================================
+
+/// Exit code for failures without a more specific category
+pub const EXIT_CODE_FAILURE: u8 = 1;
+
+/// Exit code for network and HTTP failures
+pub const EXIT_CODE_NETWORK: u8 = 2;
+
+/// Exit code for invalid configuration or arguments
+pub const EXIT_CODE_CONFIG: u8 = 3;
+
+/// Exit code when a site blocked the request (e.g. with a CAPTCHA)
+pub const EXIT_CODE_BLOCKED: u8 = 4;
+
+/// Exit code for operations that timed out
+pub const EXIT_CODE_TIMEOUT: u8 = 5;
+
+/// Exit code when a URL, page or document could not be parsed
+pub const EXIT_CODE_PARSE: u8 = 6;
+
+/// Exit code when the WebDriver could not be found, started or talked to
+pub const EXIT_CODE_DRIVER: u8 = 7;
+
+/// Exit code after Ctrl-C, following the shell's 128 + SIGINT convention
+pub const EXIT_CODE_INTERRUPTED: u8 = 130;
+
 // Default log levels
 pub const LOG_LEVEL_INFO: &str = "info";
 pub const LOG_LEVEL_DEBUG: &str = "debug";
 pub const LOG_LEVEL_WARN: &str = "warn";
 
+// Log formats
+pub const LOG_FORMAT_TEXT: &str = "text";
+pub const LOG_FORMAT_JSON: &str = "json";
+
 // Default formats
 pub const FORMAT_MARKDOWN: &str = "markdown";
 pub const FORMAT_JSON: &str = "json";
@@ -147,6 +276,34 @@ pub const SEARCH_ENGINE_GOOGLE: &str = "google";
 pub const SEARCH_ENGINE_BRAVE: &str = "brave";
 pub const SEARCH_ENGINE_BAIDU: &str = "baidu";
 pub const SEARCH_ENGINE_SOUGOU_WEIXIN: &str = "sogou_weixin";
+pub const SEARCH_ENGINE_YANDEX: &str = "yandex";
+pub const SEARCH_ENGINE_SERPAPI: &str = "serpapi";
+pub const SEARCH_ENGINE_BING_API: &str = "bing_api";
+pub const SEARCH_ENGINE_GOOGLE_CSE: &str = "google_cse";
+pub const SEARCH_ENGINE_SEARXNG: &str = "searxng";
+
+/// When a search falls back to `search.fallback_engines`
+pub const AUTOSWITCH_NONE: &str = "none";
+pub const AUTOSWITCH_SMART: &str = "smart";
+pub const AUTOSWITCH_ON_ERROR: &str = "on_error";
+pub const AUTOSWITCH_ON_EMPTY: &str = "on_empty";
+
+/// Safe search levels of `search.safe_search`
+pub const SAFE_SEARCH_OFF: &str = "off";
+pub const SAFE_SEARCH_MODERATE: &str = "moderate";
+pub const SAFE_SEARCH_STRICT: &str = "strict";
+
+/// Smoothing constant `k` of reciprocal rank fusion: a result at rank `r` of one
+/// engine scores `1 / (k + r)`, so agreement between engines outweighs a single
+/// top rank
+pub const RRF_K: f64 = 60.0;
+
+/// Engine queried through SerpApi unless `search.serpapi_engine` says otherwise
+pub const DEFAULT_SERPAPI_ENGINE: &str = "google";
+
+/// How long an API key rejected with 401 or 429 is skipped when rotating keys
+pub const API_KEY_COOLDOWN_SECS: u64 = 60;
+pub const API_KEY_COOLDOWN: Duration = Duration::from_secs(API_KEY_COOLDOWN_SECS);
 
 // ============================================================================
 // Search Engine Query Patterns
@@ -160,6 +317,40 @@ pub const BRAVE_QUERY_PATTERN: &str = "https://search.brave.com/search?q={query}
 pub const BAIDU_QUERY_PATTERN: &str = "https://www.baidu.com/s?wd={query}";
 pub const SOUGOU_WEIXIN_QUERY_PATTERN: &str =
     "https://weixin.sogou.com/weixin?type=2&s_from=input&&ie=utf8&query={query}";
+pub const YANDEX_QUERY_PATTERN: &str = "https://yandex.com/search/?text={query}";
+
+/// API query patterns; `{engine}` is the engine SerpApi should query and `{cx}` the
+/// Programmable Search Engine ID
+pub const SERPAPI_QUERY_PATTERN: &str = "https://serpapi.com/search.json?engine={engine}&q={query}";
+pub const BING_API_QUERY_PATTERN: &str = "https://api.bing.microsoft.com/v7.0/search?q={query}";
+pub const GOOGLE_CSE_QUERY_PATTERN: &str =
+    "https://www.googleapis.com/customsearch/v1?cx={cx}&q={query}";
+/// SearxNG is self-hosted: the scheme and host are replaced by `search.searxng_base_url`
+pub const SEARXNG_QUERY_PATTERN: &str = "http://localhost:8080/search?q={query}&format=json";
+
+/// Environment variables overriding the API keys set in config files
+pub const ENV_SERPAPI_KEY: &str = "TARZI_SERPAPI_KEY";
+pub const ENV_BING_API_KEY: &str = "TARZI_BING_API_KEY";
+pub const ENV_GOOGLE_CSE_KEY: &str = "TARZI_GOOGLE_CSE_KEY";
+
+/// `ProviderConfig::extra` entry holding the Programmable Search Engine ID for Google CSE
+pub const GOOGLE_CSE_CX_SETTING: &str = "cx";
+/// `ProviderConfig::extra` entry holding the comma-separated engines SearxNG should query
+pub const SEARXNG_ENGINES_SETTING: &str = "engines";
+/// `ProviderConfig::extra` entries holding the country and language results are
+/// localized for (`search.country` and `search.language`)
+pub const COUNTRY_SETTING: &str = "country";
+pub const LANGUAGE_SETTING: &str = "language";
+/// `ProviderConfig::extra` entry holding the safe search level (`search.safe_search`)
+pub const SAFE_SEARCH_SETTING: &str = "safe_search";
+/// `ProviderConfig::extra` entry holding the engine SerpApi queries (`search.serpapi_engine`)
+pub const SERPAPI_ENGINE_SETTING: &str = "serpapi_engine";
+/// `ProviderConfig::extra` entry holding the fetch mode web providers load result
+/// pages with (`fetcher.mode`)
+pub const FETCH_MODE_SETTING: &str = "fetch_mode";
+
+/// Header carrying the Azure subscription key for the Bing Web Search API
+pub const BING_API_KEY_HEADER: &str = "Ocp-Apim-Subscription-Key";
 
 // ============================================================================
 // Default Values
@@ -1,186 +1,3378 @@
-use crate::{Result, config::Config, error::TarziError};
-use pulldown_cmark::{Event, HeadingLevel, Parser as MarkdownParser, Tag};
+use crate::{
+    Result,
+    config::Config,
+    constants::{FRONTMATTER_DESCRIPTION_CHARS, MIN_SUMMARY_PARAGRAPH_CHARS, TRUNCATION_MARKER},
+    error::TarziError,
+};
+use pulldown_cmark::{
+    CodeBlockKind, Event, HeadingLevel, Options as MarkdownOptions, Parser as MarkdownParser, Tag,
+};
+use select::document::Document as HtmlDocument;
+use select::predicate::{Any, Attr, Name, Predicate};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use unicode_normalization::UnicodeNormalization as _;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Format {
     Html,
     Markdown,
+    /// Markdown preceded by a `---`-delimited YAML frontmatter block, as used by
+    /// static site generators and note-taking apps
+    MarkdownWithFrontmatter,
+    AsciiDoc,
+    /// Readable plain text without markup
+    Text,
     Json,
+    /// Newline-delimited JSON: compact JSON values, one per line. Search results
+    /// are written one per line; a page converts to a single line.
+    NdJson,
     Yaml,
+    /// Comma-separated values (RFC 4180), one row per search result; only
+    /// supported for search results
+    Csv,
+    /// PDF document with the page's text; binary, so produced by
+    /// [`Converter::convert_bytes`] rather than [`Converter::convert`]
+    Pdf,
 }
 
+/// Canonical format names, listed when an unknown format is given
+const FORMAT_NAMES: &[&str] = &[
+    "html",
+    "markdown",
+    "frontmatter",
+    "asciidoc",
+    "text",
+    "json",
+    "ndjson",
+    "yaml",
+    "csv",
+    "pdf",
+];
+
 impl FromStr for Format {
     type Err = TarziError;
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "html" => Ok(Format::Html),
-            "markdown" | "md" => Ok(Format::Markdown),
-            "json" => Ok(Format::Json),
-            "yaml" | "yml" => Ok(Format::Yaml),
-            _ => Err(TarziError::InvalidFormat(s.to_string())),
-        }
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "html" | "htm" => Ok(Format::Html),
+            "markdown" | "md" | "mdown" => Ok(Format::Markdown),
+            "frontmatter" | "markdown+frontmatter" | "md+frontmatter" => {
+                Ok(Format::MarkdownWithFrontmatter)
+            }
+            "asciidoc" | "adoc" => Ok(Format::AsciiDoc),
+            "text" | "txt" | "plain" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "ndjson" | "jsonl" => Ok(Format::NdJson),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "csv" => Ok(Format::Csv),
+            "pdf" => Ok(Format::Pdf),
+            _ => Err(TarziError::InvalidFormat(format!(
+                "'{s}' (expected one of: {})",
+                FORMAT_NAMES.join(", ")
+            ))),
+        }
+    }
+}
+
+impl Format {
+    /// File extension for output in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Markdown | Format::MarkdownWithFrontmatter => "md",
+            Format::AsciiDoc => "adoc",
+            Format::Text => "txt",
+            Format::Json => "json",
+            Format::NdJson => "ndjson",
+            Format::Yaml => "yaml",
+            Format::Csv => "csv",
+            Format::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Document {
+    pub title: Option<String>,
+    pub content: String,
+    pub links: Vec<String>,
+    pub images: Vec<String>,
+}
+
+/// Page metadata emitted as YAML frontmatter ahead of markdown output
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Frontmatter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// UTC time the page was fetched, in RFC 3339 format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetched: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Frontmatter {
+    /// Render as a `---`-delimited YAML block, followed by a blank line
+    pub fn render(&self) -> Result<String> {
+        let yaml = serde_yaml::to_string(self)?;
+        Ok(format!("---\n{yaml}---\n\n"))
+    }
+}
+
+/// Structured page metadata for link previews, from OpenGraph tags, standard
+/// `<meta>` tags and JSON-LD blocks
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PageMetadata {
+    /// `og:title`, falling back to the `<title>` element
+    pub title: Option<String>,
+    /// `og:description`, falling back to `<meta name="description">`
+    pub description: Option<String>,
+    /// `og:image` URL as written in the page
+    pub image: Option<String>,
+    /// `og:site_name`
+    pub site_name: Option<String>,
+    /// `article:published_time`, falling back to a JSON-LD `datePublished`
+    pub published_time: Option<String>,
+    /// Parsed `<script type="application/ld+json">` blocks, with top-level arrays
+    /// flattened; blocks that aren't valid JSON are skipped
+    pub json_ld: Vec<serde_json::Value>,
+}
+
+/// A heading entry in a document's table of contents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TocEntry {
+    /// Heading level, 1 for `<h1>` through 6 for `<h6>`
+    pub level: u8,
+    pub text: String,
+    /// GitHub-style slug, unique within the document
+    pub anchor: String,
+}
+
+/// A part of a document starting at a heading and running up to the next heading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Section {
+    /// Heading text; empty for content that precedes the first heading
+    pub heading: String,
+    /// Heading level, 1 for `<h1>` through 6 for `<h6>`; 0 for leading content
+    pub level: u8,
+    /// Section body rendered as markdown, without the heading itself
+    pub content_markdown: String,
+}
+
+/// Markdown dialect produced by the converter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MarkdownFlavor {
+    /// Strict CommonMark: tables and strikethrough are kept as inline HTML,
+    /// checkboxes are dropped
+    CommonMark,
+    /// GitHub Flavored Markdown: pipe tables, `~~strikethrough~~` and
+    /// `[x]` task list items
+    #[default]
+    Gfm,
+}
+
+impl FromStr for MarkdownFlavor {
+    type Err = TarziError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "commonmark" | "cm" => Ok(MarkdownFlavor::CommonMark),
+            "gfm" | "github" => Ok(MarkdownFlavor::Gfm),
+            _ => Err(TarziError::InvalidFormat(format!(
+                "Invalid markdown flavor: '{s}' (expected one of: commonmark, gfm)"
+            ))),
+        }
+    }
+}
+
+/// Unicode normalization form applied to extracted text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeNormalization {
+    /// Canonical composition: combining sequences become precomposed characters
+    Nfc,
+    /// Compatibility composition: additionally folds fullwidth forms, ligatures and
+    /// similar variants to their plain equivalents
+    Nfkc,
+}
+
+impl FromStr for UnicodeNormalization {
+    type Err = TarziError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "nfc" => Ok(UnicodeNormalization::Nfc),
+            "nfkc" => Ok(UnicodeNormalization::Nfkc),
+            _ => Err(TarziError::InvalidFormat(format!(
+                "Invalid unicode normalization: '{s}' (expected one of: nfc, nfkc)"
+            ))),
+        }
+    }
+}
+
+/// Kind of fetched content, which decides how it is converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Html,
+    Json,
+    Text,
+}
+
+impl ContentKind {
+    /// Kind of a response from its `Content-Type` header. Without a header,
+    /// a body that parses as a JSON object or array is treated as JSON.
+    pub fn detect(content_type: Option<&str>, body: &str) -> Self {
+        let Some(content_type) = content_type else {
+            let body = body.trim_start();
+            let looks_like_json = (body.starts_with('{') || body.starts_with('['))
+                && serde_json::from_str::<serde_json::Value>(body).is_ok();
+            return if looks_like_json {
+                ContentKind::Json
+            } else {
+                ContentKind::Html
+            };
+        };
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        if mime == "application/json" || mime.ends_with("+json") {
+            ContentKind::Json
+        } else if mime == "text/plain" {
+            ContentKind::Text
+        } else {
+            ContentKind::Html
+        }
+    }
+}
+
+/// Options controlling how the converter renders its output
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConverterOptions {
+    /// Prepend a markdown table of contents to markdown output
+    pub include_toc: bool,
+    /// Markdown dialect for markdown output
+    pub markdown_flavor: MarkdownFlavor,
+    /// Implementation serializing HTML to markdown
+    pub markdown_backend: MarkdownBackendKind,
+    /// Drop elements hidden via inline styles (`display:none`, `visibility:hidden`),
+    /// the `hidden`/`aria-hidden` attributes or common hidden classes, along with
+    /// `<noscript>` and `<template>` content. Detection is best-effort since the CSS
+    /// cascade is not evaluated. Off by default; recommended for content extraction.
+    pub skip_hidden: bool,
+    /// Remove iframes, ad slots (`<ins class="adsbygoogle">`, common ad and social
+    /// widget containers), tracking pixels and `<script>`/`<style>` before conversion.
+    /// Lighter than full article extraction when only the clutter should go.
+    pub remove_ads: bool,
+    /// Keep only the main article content, similar to Mozilla's Readability:
+    /// `<nav>`, `<footer>`, `<aside>`, site headers, scripts, styles, forms and
+    /// elements whose role, class or id marks menus, sidebars or cookie banners are
+    /// dropped, and the article (or the element with the most paragraph text) is
+    /// converted. Headings, paragraphs, lists and links inside it are kept.
+    pub readability: bool,
+    /// Render links as their text only in [`Format::Text`] output, instead of
+    /// `text (url)`
+    pub plain_links: bool,
+    /// Truncate output longer than this many characters at a line or word boundary
+    /// (never inside a code fence or link) and end it with a truncation marker.
+    /// JSON and YAML output stay valid: only the document's `content` is truncated.
+    pub max_output_chars: Option<usize>,
+    /// Normalize extracted text to NFC or NFKC and strip zero-width and control
+    /// characters (keeping newlines and tabs). Off by default.
+    pub normalize_unicode: Option<UnicodeNormalization>,
+    /// Resolve relative `href` and `src` attributes against this URL (or the page's
+    /// `<base href>`) so links and images stay usable out of context. Fragment-only
+    /// links are kept. Pages converted through [`Self::convert_page`] default to
+    /// their own URL.
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Converter {
+    options: ConverterOptions,
+}
+
+impl Converter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a converter with custom options
+    pub fn with_options(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Create a converter with the options from the `[converter]` config section
+    pub fn from_config(config: &Config) -> Self {
+        Self::with_options(ConverterOptions {
+            readability: config.converter.readability,
+            ..Default::default()
+        })
+    }
+
+    /// Get the converter options
+    pub fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    pub async fn convert(&self, input: &str, format: Format) -> Result<String> {
+        let output = match format {
+            Format::Html => input.to_string(),
+            Format::Markdown => self.markdown_with_toc(input)?,
+            Format::MarkdownWithFrontmatter => {
+                // The frontmatter is kept whole; only the markdown body is limited
+                let frontmatter = self.frontmatter(input).render()?;
+                let markdown = self.limit_output(self.markdown_with_toc(input)?);
+                return Ok(format!("{frontmatter}{markdown}"));
+            }
+            Format::AsciiDoc => markdown_to_asciidoc(&self.html_to_markdown(input)?),
+            Format::Text => {
+                markdown_to_text(&self.html_to_markdown(input)?, self.options.plain_links)
+            }
+            // Truncating structured output would break it; the content is limited instead
+            Format::Json => return self.html_to_json(input).await,
+            Format::NdJson => {
+                return Ok(serde_json::to_string(
+                    &self.parse_html_document(input).await?,
+                )?);
+            }
+            Format::Yaml => return self.html_to_yaml(input).await,
+            Format::Csv => return Err(search_only_format_error(format)),
+            Format::Pdf => return Err(binary_format_error(format)),
+        };
+        Ok(self.limit_output(output))
+    }
+
+    /// Convert to bytes. [`Format::Pdf`] lays out the page's text (as in
+    /// [`Format::Text`]) on A4 pages; text formats are the UTF-8 encoded output of
+    /// [`Self::convert`].
+    pub async fn convert_bytes(&self, input: &str, format: Format) -> Result<Vec<u8>> {
+        if format != Format::Pdf {
+            return Ok(self.convert(input, format).await?.into_bytes());
+        }
+        let text = markdown_to_text(&self.html_to_markdown(input)?, self.options.plain_links);
+        let title = self.frontmatter(input).title;
+        Ok(text_to_pdf(&self.limit_output(text), title.as_deref()))
+    }
+
+    /// Apply [`ConverterOptions::max_output_chars`]
+    fn limit_output(&self, output: String) -> String {
+        match self.options.max_output_chars {
+            Some(max_chars) => truncate_output(&output, max_chars),
+            None => output,
+        }
+    }
+
+    /// Convert a page fetched from `url`. Frontmatter output additionally records
+    /// the URL and the fetch time; other formats convert as [`Self::convert`].
+    pub async fn convert_page(&self, input: &str, format: Format, url: &str) -> Result<String> {
+        let converter = self.with_base_url(url);
+        if format != Format::MarkdownWithFrontmatter {
+            return converter.convert(input, format).await;
+        }
+        let frontmatter = Frontmatter {
+            url: Some(url.to_string()),
+            fetched: Some(utc_timestamp(std::time::SystemTime::now())),
+            ..converter.frontmatter(input)
+        };
+        Ok(format!(
+            "{}{}",
+            frontmatter.render()?,
+            converter.limit_output(converter.markdown_with_toc(input)?)
+        ))
+    }
+
+    /// This converter resolving relative URLs against `url`, unless a
+    /// [`ConverterOptions::base_url`] is set already or `url` is not absolute
+    fn with_base_url(&self, url: &str) -> Cow<'_, Converter> {
+        if self.options.base_url.is_some() || url::Url::parse(url).is_err() {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(Converter::with_options(ConverterOptions {
+            base_url: Some(url.to_string()),
+            ..self.options.clone()
+        }))
+    }
+
+    /// Convert fetched content according to its `Content-Type`. HTML goes through
+    /// [`Self::convert_page`]; other content is never parsed as HTML:
+    ///
+    /// - JSON is pretty-printed for `json`, `text` and `html`, converted for `yaml`,
+    ///   and wrapped in a `json` code block for markdown and AsciiDoc output.
+    /// - Plain text is returned as-is, or as the content of a [`Document`] for
+    ///   `json` and `yaml`.
+    ///
+    /// [`ConverterOptions::max_output_chars`] applies to all but JSON responses
+    /// requested as `json` or `yaml`, which are passed through whole.
+    pub async fn convert_response(
+        &self,
+        input: &str,
+        content_type: Option<&str>,
+        format: Format,
+        url: &str,
+    ) -> Result<String> {
+        match format {
+            Format::Pdf => return Err(binary_format_error(format)),
+            Format::Csv => return Err(search_only_format_error(format)),
+            _ => {}
+        }
+        match ContentKind::detect(content_type, input) {
+            ContentKind::Html => self.convert_page(input, format, url).await,
+            ContentKind::Json => match serde_json::from_str::<serde_json::Value>(input) {
+                // Truncating JSON or YAML would leave it unparseable
+                Ok(json) if matches!(format, Format::Json | Format::NdJson | Format::Yaml) => {
+                    json_to_format(&json, format)
+                }
+                Ok(json) => Ok(self.limit_output(json_to_format(&json, format)?)),
+                Err(e) => {
+                    tracing::warn!(
+                        "Response labelled JSON did not parse ({e}), keeping it as text"
+                    );
+                    text_to_format(&self.limit_output(input.to_string()), format)
+                }
+            },
+            ContentKind::Text => {
+                let text = self.normalize_text(input.to_string());
+                text_to_format(&self.limit_output(text), format)
+            }
+        }
+    }
+
+    /// Extract frontmatter metadata from a page: the `<title>` (or first `<h1>`)
+    /// and the description used by [`Self::summary`]
+    pub fn frontmatter(&self, html: &str) -> Frontmatter {
+        let document = HtmlDocument::from(html);
+        let title = document
+            .find(Name("title"))
+            .chain(document.find(Name("h1")))
+            .map(|node| self.normalize_text(normalize_whitespace(&node.text())))
+            .find(|title| !title.is_empty());
+        let description = self.normalize_text(self.summary(html, FRONTMATTER_DESCRIPTION_CHARS));
+
+        Frontmatter {
+            title,
+            description: (!description.is_empty()).then_some(description),
+            ..Default::default()
+        }
+    }
+
+    /// Extract link-preview metadata from a page's OpenGraph tags, `<title>`,
+    /// `<meta name="description">` and JSON-LD blocks. Missing fields are `None`.
+    pub fn extract_metadata(&self, html: &str) -> Result<PageMetadata> {
+        let document = HtmlDocument::from(html);
+
+        // OpenGraph tags belong in `property`, but many pages put them in `name`
+        let meta_content = |key: &str| {
+            document
+                .find(Name("meta"))
+                .filter(|node| {
+                    node.attr("property")
+                        .or_else(|| node.attr("name"))
+                        .is_some_and(|name| name.trim().eq_ignore_ascii_case(key))
+                })
+                .filter_map(|node| node.attr("content"))
+                .map(normalize_whitespace)
+                .find(|content| !content.is_empty())
+        };
+
+        let json_ld: Vec<serde_json::Value> = document
+            .find(Name("script"))
+            .filter(|node| {
+                node.attr("type")
+                    .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("application/ld+json"))
+            })
+            .filter_map(|node| match serde_json::from_str(node.text().trim()) {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    tracing::debug!("Skipping invalid JSON-LD block: {}", e);
+                    None
+                }
+            })
+            .flat_map(|value| match value {
+                serde_json::Value::Array(items) => items,
+                value => vec![value],
+            })
+            .collect();
+
+        let title = meta_content("og:title").or_else(|| {
+            document
+                .find(Name("title"))
+                .map(|node| normalize_whitespace(&node.text()))
+                .find(|title| !title.is_empty())
+        });
+        let published_time = meta_content("article:published_time").or_else(|| {
+            json_ld
+                .iter()
+                .flat_map(|value| {
+                    let graph = value["@graph"].as_array().into_iter().flatten();
+                    std::iter::once(value).chain(graph)
+                })
+                .find_map(|value| value["datePublished"].as_str())
+                .map(str::to_string)
+        });
+
+        Ok(PageMetadata {
+            title,
+            description: meta_content("og:description").or_else(|| meta_content("description")),
+            image: meta_content("og:image"),
+            site_name: meta_content("og:site_name"),
+            published_time,
+            json_ld,
+        })
+    }
+
+    /// Markdown output, prefixed with a table of contents when enabled
+    fn markdown_with_toc(&self, input: &str) -> Result<String> {
+        let markdown = self.html_to_markdown(input)?;
+        if self.options.include_toc {
+            let toc = render_toc(&self.table_of_contents(input));
+            if !toc.is_empty() {
+                return Ok(format!("{toc}\n{markdown}"));
+            }
+        }
+        Ok(markdown)
+    }
+
+    /// Convert content using the format specified in the config. Options enabled
+    /// in the `[converter]` section apply in addition to this converter's own.
+    pub async fn convert_with_config(&self, input: &str, config: &Config) -> Result<String> {
+        let format = Format::from_str(&config.fetcher.format)?;
+        if config.converter.readability && !self.options.readability {
+            let converter = Converter::with_options(ConverterOptions {
+                readability: true,
+                ..self.options.clone()
+            });
+            return converter.convert(input, format).await;
+        }
+        self.convert(input, format).await
+    }
+
+    /// Extract a table of contents from the headings (`<h1>`-`<h6>`) of an HTML document.
+    /// Duplicate heading texts get `-1`, `-2`, ... suffixed to their anchors.
+    pub fn table_of_contents(&self, html: &str) -> Vec<TocEntry> {
+        let document = HtmlDocument::from(html);
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut entries = Vec::new();
+
+        for node in document.find(Any) {
+            let Some(level) = heading_level(&node) else {
+                continue;
+            };
+            let text = normalize_whitespace(&node.text());
+            if text.is_empty() {
+                continue;
+            }
+
+            let slug = slugify(&text);
+            let count = seen.entry(slug.clone()).or_insert(0);
+            let anchor = if *count == 0 {
+                slug
+            } else {
+                format!("{slug}-{count}")
+            };
+            *count += 1;
+
+            entries.push(TocEntry {
+                level,
+                text,
+                anchor,
+            });
+        }
+
+        entries
+    }
+
+    /// Build a short plain-text summary of an HTML page, suitable for link previews.
+    /// Prefers `<meta name="description">`, then `og:description`, then the first
+    /// substantive paragraph. The result is truncated to `max_chars` characters at a
+    /// word boundary, and is empty if nothing usable is found.
+    pub fn summary(&self, html: &str, max_chars: usize) -> String {
+        let document = HtmlDocument::from(html);
+
+        let meta_content = |attr: &'static str, value: &'static str| {
+            document
+                .find(Name("meta").and(Attr(attr, value)))
+                .filter_map(|node| node.attr("content"))
+                .map(normalize_whitespace)
+                .find(|content| !content.is_empty())
+        };
+
+        let text = meta_content("name", "description")
+            .or_else(|| meta_content("property", "og:description"))
+            .or_else(|| {
+                document
+                    .find(Name("p"))
+                    .map(|node| normalize_whitespace(&node.text()))
+                    .find(|text| text.chars().count() >= MIN_SUMMARY_PARAGRAPH_CHARS)
+            })
+            .unwrap_or_default();
+
+        truncate_at_word(&text, max_chars)
+    }
+
+    /// Absolute http(s) links of an HTML page in document order, resolved against
+    /// `<base href>` or `base_url`, without fragments and with duplicates removed
+    pub fn links(&self, html: &str, base_url: &str) -> Vec<String> {
+        let document = HtmlDocument::from(html);
+        let base = document_base_url(&document, base_url);
+
+        let mut seen = HashSet::new();
+        let mut links = Vec::new();
+        for href in document
+            .find(Name("a"))
+            .filter_map(|node| node.attr("href"))
+        {
+            let Some(mut url) = resolve_url(base.as_ref(), href) else {
+                continue;
+            };
+            if !matches!(url.scheme(), "http" | "https") {
+                continue;
+            }
+            url.set_fragment(None);
+            let url = url.to_string();
+            if seen.insert(url.clone()) {
+                links.push(url);
+            }
+        }
+        links
+    }
+
+    /// Remove ads, embedded widgets, tracking pixels, scripts and styles from HTML
+    pub fn strip_ads(&self, html: &str) -> String {
+        strip_elements(html, &is_ad_element)
+    }
+
+    /// Apply the enabled pre-processing options, re-serializing the HTML only if needed
+    fn preprocess<'a>(&self, html: &'a str) -> Cow<'a, str> {
+        let ConverterOptions {
+            skip_hidden,
+            remove_ads,
+            readability,
+            ref base_url,
+            ..
+        } = self.options;
+        let html = match base_url {
+            Some(base_url) => Cow::Owned(absolutize_urls(html, base_url)),
+            None => Cow::Borrowed(html),
+        };
+        let html = if skip_hidden || remove_ads {
+            Cow::Owned(strip_elements(&html, &|node| {
+                (skip_hidden && is_hidden_element(node)) || (remove_ads && is_ad_element(node))
+            }))
+        } else {
+            html
+        };
+        if readability {
+            Cow::Owned(extract_main_content(&html))
+        } else {
+            html
+        }
+    }
+
+    /// Split a document into sections at its headings (`<h1>`-`<h6>`), rendering each
+    /// section's content as markdown. Headings nested inside containers still start a
+    /// new section; content before the first heading becomes a level-0 section.
+    pub fn sections(&self, html: &str) -> Vec<Section> {
+        let html = self.preprocess(html);
+        let headings = RefCell::new(Vec::new());
+        let marked = rewrite_elements(&html, &|node| {
+            let level = heading_level(node)?;
+            let text = normalize_whitespace(&node.text());
+            if text.is_empty() {
+                return None;
+            }
+            let mut headings = headings.borrow_mut();
+            headings.push((level, text));
+            Some(format!(
+                "<p>{SECTION_MARK_OPEN}{}{SECTION_MARK_CLOSE}</p>",
+                headings.len() - 1
+            ))
+        });
+        let headings = headings.into_inner();
+        let markdown = self.render_markdown(&marked);
+
+        let mut sections = Vec::new();
+        let mut parts = markdown.split(SECTION_MARK_OPEN);
+        let leading = parts.next().unwrap_or_default().trim();
+        if !leading.is_empty() {
+            sections.push(Section {
+                heading: String::new(),
+                level: 0,
+                content_markdown: leading.to_string(),
+            });
+        }
+        for part in parts {
+            let Some((index, content)) = part.split_once(SECTION_MARK_CLOSE) else {
+                continue;
+            };
+            let Some((level, heading)) = index.parse::<usize>().ok().and_then(|i| headings.get(i))
+            else {
+                continue;
+            };
+            sections.push(Section {
+                heading: heading.clone(),
+                level: *level,
+                content_markdown: content.trim().to_string(),
+            });
+        }
+        sections
+    }
+
+    fn html_to_markdown(&self, html: &str) -> Result<String> {
+        let html = self.preprocess(html);
+        Ok(self.render_markdown(&html))
+    }
+
+    /// Render already pre-processed HTML as markdown in the configured flavor
+    fn render_markdown(&self, html: &str) -> String {
+        let backend = self.options.markdown_backend.backend();
+        let markdown = match self.options.markdown_flavor {
+            MarkdownFlavor::Gfm => backend.to_markdown(&prepare_gfm(html)),
+            MarkdownFlavor::CommonMark => html_to_commonmark(html, backend),
+        };
+        let markdown = trim_dangling_breaks(&markdown);
+        match self.options.normalize_unicode {
+            Some(form) => normalize_unicode(&markdown, form),
+            None => markdown,
+        }
+    }
+
+    /// Apply [`ConverterOptions::normalize_unicode`] to text extracted outside of
+    /// markdown rendering
+    fn normalize_text(&self, text: String) -> String {
+        match self.options.normalize_unicode {
+            Some(form) => normalize_unicode(&text, form),
+            None => text,
+        }
+    }
+
+    async fn html_to_json(&self, html: &str) -> Result<String> {
+        let document = self.parse_html_document(html).await?;
+        let json = serde_json::to_string_pretty(&document)?;
+        Ok(json)
+    }
+
+    async fn html_to_yaml(&self, html: &str) -> Result<String> {
+        let document = self.parse_html_document(html).await?;
+        let yaml = serde_yaml::to_string(&document)?;
+        Ok(yaml)
+    }
+
+    async fn parse_html_document(&self, html: &str) -> Result<Document> {
+        // First convert to markdown
+        let markdown = self.html_to_markdown(html)?;
+
+        // Parse markdown to extract structured data
+        let mut title = None;
+        let mut content = String::new();
+        let mut links = Vec::new();
+        let mut images = Vec::new();
+
+        let parser = MarkdownParser::new(&markdown);
+        let mut in_title = false;
+
+        for event in parser {
+            match event {
+                Event::Start(Tag::Heading(HeadingLevel::H1, ..)) => {
+                    in_title = true;
+                }
+                Event::Text(text) => {
+                    if in_title {
+                        title = Some(text.to_string());
+                    } else {
+                        content.push_str(&text);
+                        content.push(' ');
+                    }
+                }
+                Event::End(Tag::Heading(HeadingLevel::H1, ..)) => {
+                    in_title = false;
+                }
+                Event::Start(Tag::Link(_, url, _)) => {
+                    links.push(url.to_string());
+                }
+                Event::Start(Tag::Image(_, url, _)) => {
+                    images.push(url.to_string());
+                }
+                Event::End(Tag::Paragraph) => {
+                    content.push('\n');
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Document {
+            title,
+            content: self.limit_output(content.trim().to_string()),
+            links,
+            images,
+        })
+    }
+}
+
+// Private-use code points survive html2md untouched, so they are used to mark
+// fragments that must be restored after conversion.
+const RAW_BLOCK_OPEN: char = '\u{E000}';
+const RAW_BLOCK_CLOSE: char = '\u{E001}';
+const STRIKE_OPEN: char = '\u{E002}';
+const STRIKE_CLOSE: char = '\u{E003}';
+const SECTION_MARK_OPEN: char = '\u{E004}';
+const SECTION_MARK_CLOSE: char = '\u{E005}';
+const LIST_MARK_OPEN: char = '\u{E006}';
+const LIST_MARK_CLOSE: char = '\u{E007}';
+const TABLE_MARK_OPEN: char = '\u{E008}';
+const TABLE_MARK_CLOSE: char = '\u{E009}';
+
+/// Indentation of each nested list level; wide enough to nest under `99. ` markers
+const LIST_INDENT: &str = "    ";
+
+fn list_or_table_regex() -> regex::Regex {
+    regex::Regex::new(r"(?i)<(?:[ou]l|table)\b").unwrap()
+}
+
+/// Convert HTML with html2md, serializing lists separately so that nesting and
+/// numbering survive intact, and tables so that header rows and pipes in cells do
+fn html_with_lists_to_markdown(html: &str) -> String {
+    if !list_or_table_regex().is_match(html) {
+        return html2md::parse_html(html);
+    }
+
+    let lists = RefCell::new(Vec::new());
+    let tables = RefCell::new(Vec::new());
+    let marked = rewrite_elements(html, &|node| match node.name() {
+        Some("ul" | "ol") => {
+            let mut lists = lists.borrow_mut();
+            lists.push(render_list(node, 0).trim_end().to_string());
+            Some(format!(
+                "<p>{LIST_MARK_OPEN}{}{LIST_MARK_CLOSE}</p>",
+                lists.len() - 1
+            ))
+        }
+        Some("table") => {
+            let mut tables = tables.borrow_mut();
+            tables.push(walk_table(node));
+            Some(format!(
+                "<p>{TABLE_MARK_OPEN}{}{TABLE_MARK_CLOSE}</p>",
+                tables.len() - 1
+            ))
+        }
+        _ => None,
+    });
+
+    let mut markdown = html2md::parse_html(&marked);
+    for (i, list) in lists.into_inner().iter().enumerate() {
+        markdown = markdown.replace(&format!("{LIST_MARK_OPEN}{i}{LIST_MARK_CLOSE}"), list);
+    }
+    for (i, table) in tables.into_inner().iter().enumerate() {
+        markdown = markdown.replace(&format!("{TABLE_MARK_OPEN}{i}{TABLE_MARK_CLOSE}"), table);
+    }
+    markdown
+}
+
+/// Render a `<ul>`/`<ol>` element as a markdown list at nesting `depth`.
+/// Ordered lists keep their `start` number.
+fn render_list(node: &select::node::Node, depth: usize) -> String {
+    let ordered = node.name() == Some("ol");
+    let mut number = node
+        .attr("start")
+        .and_then(|start| start.trim().parse::<i64>().ok())
+        .unwrap_or(1);
+    let indent = LIST_INDENT.repeat(depth);
+    let continuation = LIST_INDENT.repeat(depth + 1);
+
+    let mut output = String::new();
+    for item in node.children().filter(|child| child.name() == Some("li")) {
+        let marker = if ordered {
+            let marker = format!("{number}. ");
+            number += 1;
+            marker
+        } else {
+            "* ".to_string()
+        };
+
+        // Item text and nested lists, in document order; nested lists come pre-indented
+        let mut blocks = Vec::new();
+        let mut fragment = String::new();
+        for child in item.children() {
+            if matches!(child.name(), Some("ul" | "ol")) {
+                blocks.push((false, html2md::parse_html(&std::mem::take(&mut fragment))));
+                blocks.push((true, render_list(&child, depth + 1)));
+            } else {
+                serialize_rewritten(&child, &|_| None, &mut fragment);
+            }
+        }
+        blocks.push((false, html2md::parse_html(&fragment)));
+
+        output.push_str(&indent);
+        output.push_str(&marker);
+        let mut first_line = true;
+        for (nested, block) in &blocks {
+            if *nested {
+                if first_line {
+                    output.push('\n');
+                    first_line = false;
+                }
+                output.push_str(block);
+                continue;
+            }
+            for line in block.trim().lines() {
+                if first_line {
+                    first_line = false;
+                } else if !line.is_empty() {
+                    output.push_str(&continuation);
+                }
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+        if first_line {
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Serializes HTML to markdown; selected with [`ConverterOptions::markdown_backend`]
+pub trait MarkdownBackend: std::fmt::Debug + Send + Sync {
+    /// Convert an HTML document or fragment to markdown
+    fn to_markdown(&self, html: &str) -> String;
+}
+
+/// Available [`MarkdownBackend`] implementations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MarkdownBackendKind {
+    /// html2md, with lists serialized separately so nesting survives
+    #[default]
+    Html2Md,
+    /// A single pass over the parsed DOM. Faster on large pages; headings are
+    /// always ATX (`#`) style.
+    DomWalker,
+}
+
+impl MarkdownBackendKind {
+    pub fn backend(&self) -> &'static dyn MarkdownBackend {
+        match self {
+            MarkdownBackendKind::Html2Md => &Html2MdBackend,
+            MarkdownBackendKind::DomWalker => &DomWalkerBackend,
+        }
+    }
+}
+
+impl FromStr for MarkdownBackendKind {
+    type Err = TarziError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "html2md" => Ok(MarkdownBackendKind::Html2Md),
+            "dom_walker" | "walker" => Ok(MarkdownBackendKind::DomWalker),
+            _ => Err(TarziError::InvalidFormat(format!(
+                "Invalid markdown backend: '{s}' (expected one of: html2md, dom_walker)"
+            ))),
+        }
+    }
+}
+
+/// See [`MarkdownBackendKind::Html2Md`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Html2MdBackend;
+
+impl MarkdownBackend for Html2MdBackend {
+    fn to_markdown(&self, html: &str) -> String {
+        html_with_lists_to_markdown(html)
+    }
+}
+
+/// See [`MarkdownBackendKind::DomWalker`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DomWalkerBackend;
+
+impl MarkdownBackend for DomWalkerBackend {
+    fn to_markdown(&self, html: &str) -> String {
+        let document = HtmlDocument::from(html);
+        let roots = document.find(|node: &select::node::Node| node.parent().is_none());
+        let markdown = walk_blocks(roots);
+        let markdown = blank_lines_regex().replace_all(&markdown, "\n\n");
+        markdown.trim().to_string()
+    }
+}
+
+/// Elements the DOM walker renders as blocks of their own
+const WALKER_BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "dd",
+    "details",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "html",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "ul",
+];
+
+/// Elements the DOM walker drops with their content
+const WALKER_SKIPPED_ELEMENTS: &[&str] = &["head", "script", "style", "noscript", "template"];
+
+fn blank_lines_regex() -> regex::Regex {
+    regex::Regex::new(r"\n[ \t]*\n(?:[ \t]*\n)+").unwrap()
+}
+
+/// Render `nodes` as markdown blocks separated by blank lines
+fn walk_blocks<'a>(nodes: impl Iterator<Item = select::node::Node<'a>>) -> String {
+    collect_blocks(nodes)
+        .into_iter()
+        .map(|(_, block)| block)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Render `nodes` as markdown blocks, flagging those that are lists. Runs of
+/// inline content between block elements become paragraphs.
+fn collect_blocks<'a>(nodes: impl Iterator<Item = select::node::Node<'a>>) -> Vec<(bool, String)> {
+    let mut blocks = Vec::new();
+    let mut inline = String::new();
+    let flush = |inline: &mut String, blocks: &mut Vec<(bool, String)>| {
+        let paragraph = std::mem::take(inline)
+            .split('\n')
+            .map(str::trim_start)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let paragraph = paragraph.trim();
+        if !paragraph.is_empty() {
+            blocks.push((false, paragraph.to_string()));
+        }
+    };
+
+    for node in nodes {
+        match node.name() {
+            Some(name) if WALKER_SKIPPED_ELEMENTS.contains(&name) => {}
+            Some(name) if WALKER_BLOCK_ELEMENTS.contains(&name) => {
+                flush(&mut inline, &mut blocks);
+                let block = walk_block(&node, name);
+                if !block.trim().is_empty() {
+                    blocks.push((matches!(name, "ul" | "ol"), block));
+                }
+            }
+            _ => walk_inline(&node, &mut inline),
+        }
+    }
+    flush(&mut inline, &mut blocks);
+    blocks
+}
+
+fn walk_block(node: &select::node::Node, name: &str) -> String {
+    match name {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = heading_level(node).unwrap_or(1) as usize;
+            format!("{} {}", "#".repeat(level), inline_text(node))
+        }
+        "hr" => "---".to_string(),
+        "pre" => {
+            let text = node.text();
+            let language = node
+                .find(Name("code"))
+                .next()
+                .and_then(|code| code.attr("class"))
+                .and_then(|class| {
+                    class
+                        .split_whitespace()
+                        .find_map(|class| class.strip_prefix("language-"))
+                })
+                .unwrap_or_default()
+                .to_string();
+            let mut fence = "```".to_string();
+            while text.contains(&fence) {
+                fence.push('`');
+            }
+            format!(
+                "{fence}{language}\n{}\n{fence}",
+                text.trim_end_matches('\n')
+            )
+        }
+        "blockquote" => walk_blocks(node.children())
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    ">".to_string()
+                } else {
+                    format!("> {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "ul" | "ol" => walk_list(node, name == "ol"),
+        "table" => walk_table(node),
+        _ => walk_blocks(node.children()),
+    }
+}
+
+/// Render a list; item content after the first line is indented under the marker
+fn walk_list(node: &select::node::Node, ordered: bool) -> String {
+    let mut number = node
+        .attr("start")
+        .and_then(|start| start.trim().parse::<i64>().ok())
+        .unwrap_or(1);
+    let mut items = Vec::new();
+    for item in node.children().filter(|child| child.name() == Some("li")) {
+        let marker = if ordered {
+            number += 1;
+            format!("{}. ", number - 1)
+        } else {
+            "* ".to_string()
+        };
+        // Nested lists stay tight under their item; paragraphs are separated
+        let mut content = String::new();
+        for (nested_list, block) in collect_blocks(item.children()) {
+            if !content.is_empty() {
+                content.push_str(if nested_list { "\n" } else { "\n\n" });
+            }
+            content.push_str(&block);
+        }
+        let mut rendered = marker;
+        for (i, line) in content.lines().enumerate() {
+            if i > 0 {
+                rendered.push('\n');
+                if !line.is_empty() {
+                    rendered.push_str(LIST_INDENT);
+                }
+            }
+            rendered.push_str(line);
+        }
+        items.push(rendered);
+    }
+    items.join("\n")
+}
+
+/// Render a table as a GFM pipe table. The first row is the header when it is in
+/// `<thead>` or made of `<th>` cells; otherwise an empty header is added, since
+/// pipe tables can't do without one. Rows of nested tables are left out.
+fn walk_table(node: &select::node::Node) -> String {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut has_header = false;
+    for child in node.children() {
+        let (section, section_rows): (_, Vec<_>) = match child.name() {
+            Some("tr") => (None, vec![child]),
+            Some(section @ ("thead" | "tbody" | "tfoot")) => (
+                Some(section),
+                child
+                    .children()
+                    .filter(|row| row.name() == Some("tr"))
+                    .collect(),
+            ),
+            _ => continue,
+        };
+        for row in section_rows {
+            let cells: Vec<_> = row
+                .children()
+                .filter(|cell| matches!(cell.name(), Some("th" | "td")))
+                .collect();
+            if cells.is_empty() {
+                continue;
+            }
+            if rows.is_empty() {
+                has_header =
+                    section == Some("thead") || cells.iter().all(|cell| cell.name() == Some("th"));
+            }
+            // Cell text is kept on one line; pipes would end the cell
+            rows.push(
+                cells
+                    .iter()
+                    .map(|cell| inline_text(cell).replace('|', "\\|"))
+                    .collect(),
+            );
+        }
+    }
+    let Some(columns) = rows.iter().map(Vec::len).max() else {
+        return String::new();
+    };
+    if !has_header {
+        rows.insert(0, Vec::new());
+    }
+
+    let render_row = |cells: &[String]| {
+        let mut line = String::from("|");
+        for i in 0..columns {
+            line.push(' ');
+            line.push_str(cells.get(i).map(String::as_str).unwrap_or_default());
+            line.push_str(" |");
+        }
+        line
+    };
+    let mut lines = vec![
+        render_row(&rows[0]),
+        format!("|{}", " --- |".repeat(columns)),
+    ];
+    lines.extend(rows[1..].iter().map(|row| render_row(row)));
+    lines.join("\n")
+}
+
+/// Inline markdown of `node`'s children on a single line
+fn inline_text(node: &select::node::Node) -> String {
+    let mut text = String::new();
+    for child in node.children() {
+        walk_inline(&child, &mut text);
+    }
+    normalize_whitespace(&text)
+}
+
+/// Append the inline markdown for `node` to `out`, collapsing whitespace
+fn walk_inline(node: &select::node::Node, out: &mut String) {
+    if let Some(text) = node.as_text() {
+        for (i, word) in text.split(char::is_whitespace).enumerate() {
+            if i > 0 && !out.is_empty() && !out.ends_with([' ', '\n']) {
+                out.push(' ');
+            }
+            for c in word.chars() {
+                if matches!(c, '\\' | '*' | '_' | '`') {
+                    out.push('\\');
+                }
+                out.push(c);
+            }
+        }
+        return;
+    }
+    let Some(name) = node.name() else {
+        return;
+    };
+
+    let wrap = |out: &mut String, delimiter: &str| {
+        let mut inner = String::new();
+        for child in node.children() {
+            walk_inline(&child, &mut inner);
+        }
+        if inner.trim().is_empty() {
+            out.push_str(&inner);
+            return;
+        }
+        if inner.starts_with(' ') && !out.ends_with([' ', '\n']) && !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(delimiter);
+        out.push_str(inner.trim());
+        out.push_str(delimiter);
+        if inner.ends_with(' ') {
+            out.push(' ');
+        }
+    };
+
+    match name {
+        _ if WALKER_SKIPPED_ELEMENTS.contains(&name) => {}
+        "br" => out.push_str("  \n"),
+        "strong" | "b" => wrap(out, "**"),
+        "em" | "i" => wrap(out, "*"),
+        "del" | "s" | "strike" => wrap(out, "~~"),
+        "code" => {
+            let code = normalize_whitespace(&node.text());
+            if !code.is_empty() {
+                let delimiter = if code.contains('`') { "``" } else { "`" };
+                out.push_str(&format!("{delimiter}{code}{delimiter}"));
+            }
+        }
+        "img" => {
+            if let Some(src) = node.attr("src").filter(|src| !src.is_empty()) {
+                let alt = normalize_whitespace(node.attr("alt").unwrap_or_default());
+                out.push_str(&format!("![{alt}]({src})"));
+            }
+        }
+        "a" => {
+            let text = inline_text(node);
+            match node.attr("href").filter(|href| !href.is_empty()) {
+                Some(href) if !text.is_empty() => out.push_str(&format!("[{text}]({href})")),
+                _ => out.push_str(&text),
+            }
+        }
+        _ => {
+            for child in node.children() {
+                walk_inline(&child, out);
+            }
+        }
+    }
+}
+
+fn checkbox_regex() -> regex::Regex {
+    regex::Regex::new(r#"(?i)<input\b[^>]*\btype\s*=\s*["']?checkbox["']?[^>]*>"#).unwrap()
+}
+
+fn strike_regex() -> regex::Regex {
+    regex::Regex::new(r"(?i)<(/?)(?:del|s|strike)(?:\s[^>]*)?>").unwrap()
+}
+
+/// Rewrite HTML so html2md emits GFM task list markers and strikethrough
+fn prepare_gfm(html: &str) -> String {
+    let html = checkbox_regex().replace_all(html, |caps: &regex::Captures| {
+        if caps[0].to_lowercase().contains("checked") {
+            "[x] "
+        } else {
+            "[ ] "
+        }
+    });
+    strike_regex()
+        .replace_all(&html, |caps: &regex::Captures| format!("<{}del>", &caps[1]))
+        .into_owned()
+}
+
+/// Convert HTML to strict CommonMark, keeping GFM-only constructs as raw HTML
+fn html_to_commonmark(html: &str, backend: &dyn MarkdownBackend) -> String {
+    let table_re = regex::Regex::new(r"(?is)<table\b.*?</table\s*>").unwrap();
+    let mut tables = Vec::new();
+    let html = table_re.replace_all(html, |caps: &regex::Captures| {
+        tables.push(caps[0].to_string());
+        format!(
+            "<p>{RAW_BLOCK_OPEN}{}{RAW_BLOCK_CLOSE}</p>",
+            tables.len() - 1
+        )
+    });
+    let html = checkbox_regex().replace_all(&html, "");
+    let html = strike_regex().replace_all(&html, |caps: &regex::Captures| {
+        if caps[1].is_empty() {
+            STRIKE_OPEN.to_string()
+        } else {
+            STRIKE_CLOSE.to_string()
+        }
+    });
+
+    let mut markdown = backend
+        .to_markdown(&html)
+        .replace(STRIKE_OPEN, "<del>")
+        .replace(STRIKE_CLOSE, "</del>");
+    for (i, table) in tables.iter().enumerate() {
+        markdown = markdown.replace(&format!("{RAW_BLOCK_OPEN}{i}{RAW_BLOCK_CLOSE}"), table);
+    }
+    markdown
+}
+
+/// Re-emit markdown as AsciiDoc by walking its events, so AsciiDoc output goes
+/// through the same HTML pipeline (pre-processing, list handling) as markdown
+fn markdown_to_asciidoc(markdown: &str) -> String {
+    let options = MarkdownOptions::ENABLE_TABLES
+        | MarkdownOptions::ENABLE_STRIKETHROUGH
+        | MarkdownOptions::ENABLE_TASKLISTS;
+    let mut output = String::new();
+    // Markers of the open lists, and whether the current list item already has a block
+    let mut list_markers: Vec<char> = Vec::new();
+    let mut item_has_block = false;
+    let mut in_code_block = false;
+
+    for event in MarkdownParser::new_ext(markdown, options) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph => {
+                    if list_markers.is_empty() {
+                        end_block(&mut output);
+                    } else {
+                        if item_has_block {
+                            end_line(&mut output);
+                            output.push_str("+\n");
+                        }
+                        item_has_block = true;
+                    }
+                }
+                Tag::Heading(level, ..) => {
+                    end_block(&mut output);
+                    output.push_str(&"=".repeat(level as usize + 1));
+                    output.push(' ');
+                }
+                Tag::BlockQuote => {
+                    end_block(&mut output);
+                    output.push_str("____\n");
+                }
+                Tag::CodeBlock(kind) => {
+                    end_block(&mut output);
+                    if let CodeBlockKind::Fenced(lang) = kind
+                        && !lang.is_empty()
+                    {
+                        output.push_str(&format!("[source,{lang}]\n"));
+                    }
+                    output.push_str("----\n");
+                    in_code_block = true;
+                }
+                Tag::List(start) => {
+                    if list_markers.is_empty() {
+                        end_block(&mut output);
+                    } else {
+                        end_line(&mut output);
+                    }
+                    if let Some(start) = start.filter(|start| *start != 1) {
+                        output.push_str(&format!("[start={start}]\n"));
+                    }
+                    list_markers.push(if start.is_some() { '.' } else { '*' });
+                }
+                Tag::Item => {
+                    end_line(&mut output);
+                    let marker = list_markers.last().copied().unwrap_or('*');
+                    output.push_str(&marker.to_string().repeat(list_markers.len()));
+                    output.push(' ');
+                    item_has_block = false;
+                }
+                Tag::Table(_) => {
+                    end_block(&mut output);
+                    output.push_str("|===\n");
+                }
+                Tag::TableCell => output.push('|'),
+                Tag::Emphasis => output.push('_'),
+                Tag::Strong => output.push('*'),
+                Tag::Strikethrough => output.push_str("[.line-through]#"),
+                Tag::Link(_, url, _) => output.push_str(&format!("link:{url}[")),
+                Tag::Image(_, url, _) => output.push_str(&format!("image:{url}[")),
+                Tag::TableHead | Tag::TableRow | Tag::FootnoteDefinition(_) => {}
+            },
+            Event::End(tag) => match tag {
+                Tag::Paragraph => {
+                    if list_markers.is_empty() {
+                        end_block(&mut output);
+                    }
+                }
+                Tag::Heading(..) => end_block(&mut output),
+                Tag::BlockQuote => {
+                    end_line(&mut output);
+                    output.push_str("____\n\n");
+                }
+                Tag::CodeBlock(_) => {
+                    end_line(&mut output);
+                    output.push_str("----\n\n");
+                    in_code_block = false;
+                }
+                Tag::List(_) => {
+                    list_markers.pop();
+                    if list_markers.is_empty() {
+                        end_block(&mut output);
+                    }
+                }
+                Tag::Item => end_line(&mut output),
+                Tag::Table(_) => {
+                    end_line(&mut output);
+                    output.push_str("|===\n\n");
+                }
+                Tag::TableHead => {
+                    // A blank line after the first row marks it as the header row
+                    output.push_str("\n\n");
+                }
+                Tag::TableRow => output.push('\n'),
+                Tag::Emphasis => output.push('_'),
+                Tag::Strong => output.push('*'),
+                Tag::Strikethrough => output.push('#'),
+                Tag::Link(..) | Tag::Image(..) => output.push(']'),
+                Tag::TableCell | Tag::FootnoteDefinition(_) => {}
+            },
+            Event::Text(text) => output.push_str(&text),
+            Event::Code(code) => output.push_str(&format!("`{code}`")),
+            Event::SoftBreak => output.push(if in_code_block { '\n' } else { ' ' }),
+            Event::HardBreak => output.push_str(" +\n"),
+            Event::Rule => {
+                end_block(&mut output);
+                output.push_str("'''\n\n");
+            }
+            Event::TaskListMarker(checked) => {
+                output.push_str(if checked { "[x] " } else { "[ ] " });
+            }
+            Event::Html(_) | Event::FootnoteReference(_) => {}
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Re-emit markdown as readable plain text: uppercase headings, `- ` bullets,
+/// links as `text (url)` (or bare text with `plain_links`) and no other markup
+fn markdown_to_text(markdown: &str, plain_links: bool) -> String {
+    let options = MarkdownOptions::ENABLE_TABLES
+        | MarkdownOptions::ENABLE_STRIKETHROUGH
+        | MarkdownOptions::ENABLE_TASKLISTS;
+    let mut output = String::new();
+    // Next number of each open list; `None` for bulleted lists
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    let mut heading_start = None;
+    let mut link_start = None;
+
+    for event in MarkdownParser::new_ext(markdown, options) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Paragraph | Tag::BlockQuote | Tag::Table(_) => {
+                    if lists.is_empty() {
+                        end_block(&mut output);
+                    } else {
+                        end_line(&mut output);
+                    }
+                }
+                Tag::Heading(..) => {
+                    end_block(&mut output);
+                    heading_start = Some(output.len());
+                }
+                Tag::CodeBlock(_) => end_block(&mut output),
+                Tag::List(start) => {
+                    if lists.is_empty() {
+                        end_block(&mut output);
+                    } else {
+                        end_line(&mut output);
+                    }
+                    lists.push(start);
+                }
+                Tag::Item => {
+                    end_line(&mut output);
+                    output.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                    match lists.last_mut() {
+                        Some(Some(number)) => {
+                            output.push_str(&format!("{number}. "));
+                            *number += 1;
+                        }
+                        _ => output.push_str("- "),
+                    }
+                }
+                Tag::TableCell if !output.is_empty() && !output.ends_with('\n') => {
+                    output.push_str(" | ");
+                }
+                Tag::Link(_, url, _) => link_start = Some((output.len(), url)),
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                Tag::Paragraph | Tag::BlockQuote | Tag::Table(_) if lists.is_empty() => {
+                    end_block(&mut output);
+                }
+                Tag::Heading(..) => {
+                    if let Some(start) = heading_start.take() {
+                        let heading = output.split_off(start).to_uppercase();
+                        output.push_str(&heading);
+                    }
+                    end_block(&mut output);
+                }
+                Tag::CodeBlock(_) => end_block(&mut output),
+                Tag::List(_) => {
+                    lists.pop();
+                    if lists.is_empty() {
+                        end_block(&mut output);
+                    }
+                }
+                Tag::Item | Tag::TableHead | Tag::TableRow => end_line(&mut output),
+                Tag::Link(..) => {
+                    if let Some((start, url)) = link_start.take()
+                        && !plain_links
+                        && !url.is_empty()
+                        && !url.starts_with('#')
+                        && output[start..].trim() != url.as_ref()
+                    {
+                        output.push_str(&format!(" ({url})"));
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(text) | Event::Code(text) => output.push_str(&text),
+            Event::SoftBreak => output.push(' '),
+            Event::HardBreak => {
+                // Continue list items at their content indentation
+                output.push('\n');
+                output.push_str(&"  ".repeat(lists.len()));
+            }
+            Event::Rule => end_block(&mut output),
+            Event::TaskListMarker(checked) => {
+                output.push_str(if checked { "[x] " } else { "[ ] " });
+            }
+            Event::Html(_) | Event::FootnoteReference(_) => {}
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Drop the trailing-space hard breaks a `<br>` leaves at the end of a block,
+/// where they would only add invisible whitespace
+fn trim_dangling_breaks(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut output = String::with_capacity(markdown.len());
+    for (i, line) in lines.iter().enumerate() {
+        let block_end = lines.get(i + 1).is_none_or(|next| next.trim().is_empty());
+        if block_end && line.ends_with("  ") {
+            output.push_str(line.trim_end());
+        } else {
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
+    output.truncate(output.trim_end_matches('\n').len());
+    output
+}
+
+/// Render a JSON response in the requested format
+fn json_to_format(json: &serde_json::Value, format: Format) -> Result<String> {
+    let pretty = serde_json::to_string_pretty(json)?;
+    Ok(match format {
+        Format::Json | Format::Text | Format::Html => pretty,
+        Format::NdJson => serde_json::to_string(json)?,
+        Format::Yaml => serde_yaml::to_string(json)?,
+        Format::Markdown | Format::MarkdownWithFrontmatter => {
+            format!("```json\n{pretty}\n```")
+        }
+        Format::AsciiDoc => format!("[source,json]\n----\n{pretty}\n----"),
+        Format::Csv => return Err(search_only_format_error(format)),
+        Format::Pdf => return Err(binary_format_error(format)),
+    })
+}
+
+/// A4 page size and layout of PDF output, in points
+const PDF_PAGE_WIDTH: f32 = 595.0;
+const PDF_PAGE_HEIGHT: f32 = 842.0;
+const PDF_MARGIN: f32 = 56.0;
+const PDF_FONT_SIZE: f32 = 11.0;
+const PDF_LEADING: f32 = 14.0;
+
+/// Widths of the printable ASCII characters (space to `~`) in Helvetica, in
+/// thousandths of the font size
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Error for binary formats requested where text output is expected
+fn binary_format_error(format: Format) -> TarziError {
+    TarziError::InvalidFormat(format!(
+        "{} output is binary; use Converter::convert_bytes and write it to a file",
+        format.extension()
+    ))
+}
+
+fn search_only_format_error(format: Format) -> TarziError {
+    TarziError::InvalidFormat(format!(
+        "{} output is only supported for search results",
+        format.extension()
+    ))
+}
+
+/// Width of `text` in points when set in Helvetica at the PDF font size
+fn pdf_text_width(text: &str) -> f32 {
+    let units: u32 = text
+        .chars()
+        .map(|c| match c {
+            ' '..='~' => u32::from(HELVETICA_WIDTHS[c as usize - 0x20]),
+            _ => 556,
+        })
+        .sum();
+    units as f32 * PDF_FONT_SIZE / 1000.0
+}
+
+/// Wrap `text` into lines fitting `width` points, breaking at spaces and
+/// splitting words that are too long on their own
+fn wrap_pdf_lines(text: &str, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            if pdf_text_width(&candidate) <= width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for c in word.chars() {
+                if !line.is_empty() && pdf_text_width(&format!("{line}{c}")) > width {
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push(c);
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Encode `text` as a PDF literal string in WinAnsiEncoding. Characters outside
+/// that encoding are replaced with `?`.
+fn pdf_string(text: &str) -> Vec<u8> {
+    let mut out = vec![b'('];
+    for c in text.chars() {
+        let byte = match c {
+            '(' | ')' | '\\' => {
+                out.push(b'\\');
+                c as u8
+            }
+            ' '..='~' => c as u8,
+            '\u{A0}'..='\u{FF}' => c as u8,
+            '€' => 0x80,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '\t' => b' ',
+            _ => b'?',
+        };
+        if byte >= 0x80 {
+            out.extend_from_slice(format!("\\{byte:03o}").as_bytes());
+        } else {
+            out.push(byte);
+        }
+    }
+    out.push(b')');
+    out
+}
+
+/// Lay out plain text on A4 pages as a PDF document using the standard
+/// Helvetica font, so no fonts need to be embedded
+fn text_to_pdf(text: &str, title: Option<&str>) -> Vec<u8> {
+    let lines = wrap_pdf_lines(text, PDF_PAGE_WIDTH - 2.0 * PDF_MARGIN);
+    let lines_per_page = ((PDF_PAGE_HEIGHT - 2.0 * PDF_MARGIN) / PDF_LEADING) as usize;
+    let pages: Vec<&[String]> = if lines.is_empty() {
+        vec![&[]]
+    } else {
+        lines.chunks(lines_per_page).collect()
+    };
+
+    // Objects 1-4 are the catalog, page tree, font and info; each page then
+    // takes two objects, the page and its content stream
+    let mut objects: Vec<Vec<u8>> = Vec::new();
+    objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+    let kids: Vec<String> = (0..pages.len())
+        .map(|i| format!("{} 0 R", 5 + 2 * i))
+        .collect();
+    objects.push(
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+    );
+    objects.push(
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_vec(),
+    );
+    let mut info = b"<< /Producer (tarzi)".to_vec();
+    if let Some(title) = title {
+        info.extend_from_slice(b" /Title ");
+        info.extend(pdf_string(title));
+    }
+    info.extend_from_slice(b" >>");
+    objects.push(info);
+
+    for (i, page) in pages.iter().enumerate() {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PDF_PAGE_WIDTH} {PDF_PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                6 + 2 * i
+            )
+            .into_bytes(),
+        );
+        let mut content = format!(
+            "BT\n/F1 {PDF_FONT_SIZE} Tf\n{PDF_LEADING} TL\n{PDF_MARGIN} {} Td\n",
+            PDF_PAGE_HEIGHT - PDF_MARGIN - PDF_FONT_SIZE
+        )
+        .into_bytes();
+        for line in page.iter() {
+            content.extend(pdf_string(line));
+            content.extend_from_slice(b" Tj T*\n");
+        }
+        content.extend_from_slice(b"ET");
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend(content);
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+    let xref = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info 4 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+/// Render a plain-text response in the requested format
+fn text_to_format(text: &str, format: Format) -> Result<String> {
+    let document = || Document {
+        title: None,
+        content: text.to_string(),
+        links: Vec::new(),
+        images: Vec::new(),
+    };
+    Ok(match format {
+        Format::Json => serde_json::to_string_pretty(&document())?,
+        Format::NdJson => serde_json::to_string(&document())?,
+        Format::Yaml => serde_yaml::to_string(&document())?,
+        _ => text.to_string(),
+    })
+}
+
+/// Terminate the current line, if any
+fn end_line(output: &mut String) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+/// Terminate the current block with a blank line, if any
+fn end_block(output: &mut String) {
+    end_line(output);
+    if !output.is_empty() && !output.ends_with("\n\n") {
+        output.push('\n');
+    }
+}
+
+/// Class names conventionally used to hide elements
+const HIDDEN_CLASSES: &[&str] = &[
+    "hidden",
+    "d-none",
+    "invisible",
+    "sr-only",
+    "visually-hidden",
+    "screen-reader-text",
+];
+
+/// Class names of common ad slots and social/share widgets
+const AD_CLASSES: &[&str] = &[
+    "adsbygoogle",
+    "ad",
+    "ads",
+    "advert",
+    "advertisement",
+    "ad-container",
+    "ad-slot",
+    "ad-banner",
+    "sponsored",
+    "social-share",
+    "share-buttons",
+    "sharethis",
+    "addthis",
+    "fb-like",
+];
+
+/// Elements that only carry embeds, code or styling
+const AD_ELEMENTS: &[&str] = &["iframe", "script", "style"];
+
+/// Elements whose content is never rendered as page text
+const NON_RENDERED_ELEMENTS: &[&str] = &["noscript", "template"];
+
+/// Elements without a closing tag
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Best-effort check whether an element is hidden, based on its own attributes
+fn is_hidden_element(node: &select::node::Node) -> bool {
+    let Some(name) = node.name() else {
+        return false;
+    };
+    if NON_RENDERED_ELEMENTS.contains(&name) || node.attr("hidden").is_some() {
+        return true;
+    }
+    if node
+        .attr("aria-hidden")
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+    {
+        return true;
+    }
+    if name == "input"
+        && node
+            .attr("type")
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("hidden"))
+    {
+        return true;
+    }
+    if let Some(style) = node.attr("style") {
+        let style: String = style
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        if style.contains("display:none") || style.contains("visibility:hidden") {
+            return true;
+        }
+    }
+    node.attr("class").is_some_and(|classes| {
+        classes
+            .split_whitespace()
+            .any(|class| HIDDEN_CLASSES.contains(&class.to_lowercase().as_str()))
+    })
+}
+
+/// Check whether an element is an embed, ad slot, social widget or tracking pixel
+fn is_ad_element(node: &select::node::Node) -> bool {
+    let Some(name) = node.name() else {
+        return false;
+    };
+    if AD_ELEMENTS.contains(&name) {
+        return true;
+    }
+    if name == "img" && node.attr("width") == Some("1") && node.attr("height") == Some("1") {
+        return true;
+    }
+    node.attr("class").is_some_and(|classes| {
+        classes
+            .split_whitespace()
+            .any(|class| AD_CLASSES.contains(&class.to_lowercase().as_str()))
+    })
+}
+
+/// Elements that never belong to an article's main content
+const BOILERPLATE_ELEMENTS: &[&str] = &[
+    "nav", "footer", "aside", "script", "style", "noscript", "template", "iframe", "form", "button",
+];
+
+/// ARIA roles of the page furniture around the main content
+const BOILERPLATE_ROLES: &[&str] = &[
+    "navigation",
+    "banner",
+    "contentinfo",
+    "complementary",
+    "search",
+    "dialog",
+    "alertdialog",
+];
+
+/// Words in class names or ids that mark menus, banners and similar boilerplate
+const BOILERPLATE_WORDS: &[&str] = &[
+    "breadcrumb",
+    "breadcrumbs",
+    "comments",
+    "consent",
+    "cookie",
+    "cookies",
+    "gdpr",
+    "menu",
+    "navbar",
+    "newsletter",
+    "popup",
+    "promo",
+    "related",
+    "share",
+    "sidebar",
+    "social",
+    "subscribe",
+];
+
+/// Elements kept even when their class or id looks like boilerplate
+const CONTENT_CONTAINERS: &[&str] = &["html", "body", "main", "article"];
+
+/// Minimum length of a paragraph counted towards a container's content score
+const MIN_SCORED_PARAGRAPH_CHARS: usize = 25;
+
+/// Whether an element is navigation, a banner, a footer or other page furniture
+/// rather than article content
+fn is_boilerplate_element(node: &select::node::Node) -> bool {
+    let Some(name) = node.name() else {
+        return false;
+    };
+    if BOILERPLATE_ELEMENTS.contains(&name) {
+        return true;
+    }
+    if CONTENT_CONTAINERS.contains(&name) {
+        return false;
+    }
+    // Site headers go; an article's own header holds its title
+    if name == "header" && !has_ancestor(node, "article") {
+        return true;
+    }
+    if node
+        .attr("role")
+        .is_some_and(|role| BOILERPLATE_ROLES.contains(&role.to_lowercase().as_str()))
+    {
+        return true;
+    }
+    [node.attr("class"), node.attr("id")]
+        .into_iter()
+        .flatten()
+        .flat_map(|value| value.split(|c: char| c.is_whitespace() || c == '-' || c == '_'))
+        .any(|word| BOILERPLATE_WORDS.contains(&word.to_lowercase().as_str()))
+}
+
+fn has_ancestor(node: &select::node::Node, name: &str) -> bool {
+    let mut parent = node.parent();
+    while let Some(node) = parent {
+        if node.name() == Some(name) {
+            return true;
+        }
+        parent = node.parent();
+    }
+    false
+}
+
+/// Isolate the main content of a page, in the spirit of Mozilla's Readability:
+/// boilerplate is removed, then the largest `<article>`, else `<main>`, else the
+/// element whose paragraphs carry the most text is kept. The page's `<h1>` is
+/// kept too when it sits outside that element.
+fn extract_main_content(html: &str) -> String {
+    let cleaned = strip_elements(html, &is_boilerplate_element);
+    let document = HtmlDocument::from(cleaned.as_str());
+    let text_len = |node: &select::node::Node| node.text().trim().chars().count();
+
+    let root = document
+        .find(Name("article"))
+        .max_by_key(text_len)
+        .or_else(|| document.find(Name("main").or(Attr("role", "main"))).next())
+        .or_else(|| {
+            // Score each paragraph's parent fully and its grandparent by half
+            let mut scores: HashMap<usize, f32> = HashMap::new();
+            for paragraph in document.find(Name("p")) {
+                let len = text_len(&paragraph);
+                if len < MIN_SCORED_PARAGRAPH_CHARS {
+                    continue;
+                }
+                let score = 1.0 + (len as f32 / 100.0).min(3.0);
+                if let Some(parent) = paragraph.parent() {
+                    *scores.entry(parent.index()).or_default() += score;
+                    if let Some(grandparent) = parent.parent() {
+                        *scores.entry(grandparent.index()).or_default() += score / 2.0;
+                    }
+                }
+            }
+            scores
+                .into_iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .and_then(|(index, _)| document.nth(index))
+        });
+    let Some(root) = root else {
+        return cleaned;
+    };
+
+    let mut output = String::new();
+    if root.find(Name("h1")).next().is_none()
+        && let Some(heading) = document.find(Name("h1")).next()
+    {
+        serialize_rewritten(&heading, &|_| None, &mut output);
+    }
+    serialize_rewritten(&root, &|_| None, &mut output);
+    output
+}
+
+/// Resolve `href` against `base`, or parse it as an absolute URL without a base
+fn resolve_url(base: Option<&url::Url>, href: &str) -> Option<url::Url> {
+    match base {
+        Some(base) => base.join(href.trim()).ok(),
+        None => url::Url::parse(href.trim()).ok(),
+    }
+}
+
+/// Base URL of a document: its `<base href>` resolved against `page_url`, or
+/// `page_url` itself
+fn document_base_url(document: &HtmlDocument, page_url: &str) -> Option<url::Url> {
+    let page_url = url::Url::parse(page_url).ok();
+    document
+        .find(Name("base"))
+        .filter_map(|node| node.attr("href"))
+        .find_map(|href| resolve_url(page_url.as_ref(), href))
+        .or(page_url)
+}
+
+/// Re-serialize an HTML document with relative `href` and `src` attributes made
+/// absolute. Fragment-only links point into the document itself and are kept.
+fn absolutize_urls(html: &str, page_url: &str) -> String {
+    let Some(base) = document_base_url(&HtmlDocument::from(html), page_url) else {
+        return html.to_string();
+    };
+
+    fn rewrite(node: &select::node::Node, base: &url::Url) -> Option<String> {
+        let name = node.name()?;
+        let resolve = |attr: &str, value: &str| {
+            let value = value.trim();
+            if !matches!(attr, "href" | "src") || value.is_empty() || value.starts_with('#') {
+                return None;
+            }
+            base.join(value).ok().map(String::from)
+        };
+        if node
+            .attrs()
+            .all(|(attr, value)| resolve(attr, value).is_none())
+        {
+            return None;
+        }
+
+        let mut output = format!("<{name}");
+        for (attr, value) in node.attrs() {
+            let value = resolve(attr, value).unwrap_or_else(|| value.to_string());
+            output.push_str(&format!(" {attr}=\"{}\"", escape_html(&value, true)));
+        }
+        output.push('>');
+        if VOID_ELEMENTS.contains(&name) {
+            return Some(output);
+        }
+        for child in node.children() {
+            serialize_rewritten(&child, &|node| rewrite(node, base), &mut output);
+        }
+        output.push_str(&format!("</{name}>"));
+        Some(output)
+    }
+
+    rewrite_elements(html, &|node| rewrite(node, &base))
+}
+
+/// Re-serialize an HTML document without the elements matched by `remove`
+fn strip_elements(html: &str, remove: &dyn Fn(&select::node::Node) -> bool) -> String {
+    rewrite_elements(html, &|node| remove(node).then(String::new))
+}
+
+/// Re-serialize an HTML document, replacing each element for which `replace`
+/// returns markup (possibly empty) with that markup
+fn rewrite_elements(html: &str, replace: &dyn Fn(&select::node::Node) -> Option<String>) -> String {
+    let document = HtmlDocument::from(html);
+    let mut output = String::with_capacity(html.len());
+    for node in (0..)
+        .map_while(|i| document.nth(i))
+        .filter(|node| node.parent().is_none())
+    {
+        serialize_rewritten(&node, replace, &mut output);
+    }
+    output
+}
+
+fn serialize_rewritten(
+    node: &select::node::Node,
+    replace: &dyn Fn(&select::node::Node) -> Option<String>,
+    output: &mut String,
+) {
+    if let Some(text) = node.as_text() {
+        output.push_str(&escape_html(text, false));
+        return;
+    }
+    let Some(name) = node.name() else {
+        return;
+    };
+    if let Some(replacement) = replace(node) {
+        output.push_str(&replacement);
+        return;
+    }
+
+    output.push('<');
+    output.push_str(name);
+    for (attr, value) in node.attrs() {
+        output.push_str(&format!(" {attr}=\"{}\"", escape_html(value, true)));
+    }
+    output.push('>');
+    if VOID_ELEMENTS.contains(&name) {
+        return;
+    }
+    for child in node.children() {
+        serialize_rewritten(&child, replace, output);
+    }
+    output.push_str(&format!("</{name}>"));
+}
+
+/// Heading level of an `<h1>`-`<h6>` element
+fn heading_level(node: &select::node::Node) -> Option<u8> {
+    match node.name()? {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+fn escape_html(text: &str, attribute: bool) -> String {
+    let escaped = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    if attribute {
+        escaped.replace('"', "&quot;")
+    } else {
+        escaped
+    }
+}
+
+/// Format a time as an RFC 3339 UTC timestamp with second precision
+fn utc_timestamp(time: std::time::SystemTime) -> String {
+    let secs = time
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, rem) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+/// Normalize `text` to `form`, dropping zero-width characters and control characters
+/// other than newlines and tabs. Joiners (U+200C, U+200D) are kept: emoji sequences
+/// and several scripts depend on them.
+fn normalize_unicode(text: &str, form: UnicodeNormalization) -> String {
+    let is_kept = |c: &char| match c {
+        '\n' | '\t' => true,
+        '\u{200B}' | '\u{2060}' | '\u{FEFF}' | '\u{00AD}' => false,
+        c => !c.is_control(),
+    };
+    match form {
+        UnicodeNormalization::Nfc => text.nfc().filter(is_kept).collect(),
+        UnicodeNormalization::Nfkc => text.nfkc().filter(is_kept).collect(),
+    }
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Truncate `text` to at most `max_chars` characters, cutting at a word boundary
+/// and marking the cut with an ellipsis
+fn truncate_at_word(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    let cut: String = text.chars().take(max_chars - 1).collect();
+    let ends_on_word = text
+        .chars()
+        .nth(max_chars - 1)
+        .is_some_and(char::is_whitespace);
+    let cut = match cut.rfind(' ') {
+        Some(pos) if pos > 0 && !ends_on_word => &cut[..pos],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end())
+}
+
+/// Cut converted output to at most `max_chars` characters, marker included.
+/// The cut falls on a line or word boundary and never inside a code fence or a
+/// markdown link, so the kept part still renders as it did.
+fn truncate_output(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let budget = max_chars.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let end = text
+        .char_indices()
+        .nth(budget)
+        .map_or(text.len(), |(i, _)| i);
+    let mut cut = &text[..end];
+
+    // Prefer the last paragraph or line break, then the last space, within the budget
+    let boundary = cut
+        .rfind("\n\n")
+        .filter(|&i| i >= cut.len() / 2)
+        .or_else(|| cut.rfind('\n').filter(|&i| i >= cut.len() / 2))
+        .or_else(|| cut.rfind(char::is_whitespace));
+    if let Some(i) = boundary
+        && !text[end..].starts_with(char::is_whitespace)
+    {
+        cut = &cut[..i];
+    }
+
+    // Close a code block whose closing fence was cut off, making room for the
+    // fence; the block is dropped if none of its content would be left
+    let mut open_fence = None;
+    let mut offset = 0;
+    for line in cut.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            open_fence = match open_fence {
+                Some(_) => None,
+                None => Some((offset, offset + line.len(), &trimmed[..3])),
+            };
+        }
+        offset += line.len();
+    }
+    if let Some((start, content_start, fence)) = open_fence {
+        let mut keep = cut.len().min(end.saturating_sub(fence.len() + 1));
+        while !cut.is_char_boundary(keep) {
+            keep -= 1;
+        }
+        if keep < cut.len()
+            && let Some(line_end) = cut[..keep].rfind('\n')
+            && line_end >= content_start
+        {
+            keep = line_end;
+        }
+        if keep > content_start {
+            let code = cut[..keep].trim_end();
+            return format!("{code}\n{fence}{TRUNCATION_MARKER}");
+        }
+        cut = &cut[..start];
+    }
+
+    // Drop a link or image whose text or target was cut off
+    if let Some(open) = cut.rfind('[') {
+        let tail = &cut[open..];
+        let unfinished = match tail.find("](") {
+            Some(target) => !tail[target..].contains(')'),
+            None => !tail.contains(']'),
+        };
+        if unfinished {
+            cut = cut[..open].strip_suffix('!').unwrap_or(&cut[..open]);
+        }
+    }
+
+    format!("{}{TRUNCATION_MARKER}", cut.trim_end())
+}
+
+/// Generate a GitHub-style heading slug: lowercase, punctuation removed, spaces as hyphens
+fn slugify(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c)
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Render table of contents entries as a nested markdown list
+pub fn render_toc(entries: &[TocEntry]) -> String {
+    let min_level = entries.iter().map(|e| e.level).min().unwrap_or(1);
+    let mut toc = String::new();
+    for entry in entries {
+        let indent = "  ".repeat(usize::from(entry.level - min_level));
+        toc.push_str(&format!("{indent}- [{}](#{})\n", entry.text, entry.anchor));
+    }
+    toc
+}
+
+// Helper function for converting search results.
+// JSON and YAML output is wrapped in a versioned envelope: `{ schema_version, results }`.
+// NDJSON output has one result per line instead.
+pub fn convert_search_results(
+    results: &[crate::search::SearchResult],
+    format: Format,
+) -> Result<String> {
+    let envelope = crate::search::SearchResults::new(results.to_vec());
+    match format {
+        Format::Json => {
+            let json_results = serde_json::to_string_pretty(&envelope)?;
+            Ok(json_results)
+        }
+        Format::Yaml => {
+            let yaml_results = serde_yaml::to_string(&envelope)?;
+            Ok(yaml_results)
+        }
+        // One result per line, without the envelope, so each line stands alone
+        Format::NdJson => {
+            let lines = results
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok(lines.join("\n"))
+        }
+        Format::Csv => Ok(search_results_to_csv(results)),
+        _ => Err(TarziError::InvalidFormat(
+            "Only JSON, NDJSON, YAML and CSV formats supported for search results".to_string(),
+        )),
+    }
+}
+
+/// Render search results as RFC 4180 CSV with a `rank,title,url,snippet,date`
+/// header row and CRLF line endings
+fn search_results_to_csv(results: &[crate::search::SearchResult]) -> String {
+    let mut csv = String::from("rank,title,url,snippet,date\r\n");
+    for result in results {
+        let fields = [
+            result.rank.to_string(),
+            csv_field(&result.title),
+            csv_field(&result.url),
+            csv_field(&result.snippet),
+            csv_field(result.date.as_deref().unwrap_or_default()),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Quote a CSV field when it contains a comma, quote or line break, doubling
+/// any quotes inside it
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::SearchResult;
+
+    #[test]
+    fn test_format_parsing() {
+        // Test valid formats
+        assert_eq!(Format::from_str("html").unwrap(), Format::Html);
+        assert_eq!(Format::from_str("HTML").unwrap(), Format::Html);
+        assert_eq!(Format::from_str("markdown").unwrap(), Format::Markdown);
+        assert_eq!(Format::from_str("MARKDOWN").unwrap(), Format::Markdown);
+        assert_eq!(Format::from_str("md").unwrap(), Format::Markdown);
+        assert_eq!(Format::from_str("MD").unwrap(), Format::Markdown);
+        assert_eq!(Format::from_str("json").unwrap(), Format::Json);
+        assert_eq!(Format::from_str("JSON").unwrap(), Format::Json);
+        assert_eq!(Format::from_str("yaml").unwrap(), Format::Yaml);
+        assert_eq!(Format::from_str("YAML").unwrap(), Format::Yaml);
+        assert_eq!(Format::from_str("yml").unwrap(), Format::Yaml);
+        assert_eq!(
+            Format::from_str("frontmatter").unwrap(),
+            Format::MarkdownWithFrontmatter
+        );
+        assert_eq!(Format::from_str("YML").unwrap(), Format::Yaml);
+        assert_eq!(Format::from_str("htm").unwrap(), Format::Html);
+        assert_eq!(Format::from_str("txt").unwrap(), Format::Text);
+
+        // Test invalid formats
+        let err = Format::from_str("invalid").unwrap_err().to_string();
+        assert!(err.contains("'invalid'"));
+        assert!(err.contains("expected one of: html, markdown"));
+        assert!(Format::from_str("").is_err());
+        assert!(Format::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn test_converter_creation() {
+        let converter = Converter::new();
+        assert_eq!(converter, Converter::default());
+        assert!(!converter.options().include_toc);
+    }
+
+    #[tokio::test]
+    async fn test_tables_to_markdown() {
+        let html = r#"<p>Before</p>
+            <table>
+              <thead><tr><th>Name</th><th>Type</th><th>Notes</th></tr></thead>
+              <tbody>
+                <tr><td><code>a|b</code></td><td>str</td><td>Pipe <em>inside</em></td></tr>
+                <tr><td>c</td><td>int</td><td>Line<br>break</td></tr>
+              </tbody>
+            </table>
+            <p>After</p>"#;
+        let expected = "| Name | Type | Notes |\n\
+                        | --- | --- | --- |\n\
+                        | `a\\|b` | str | Pipe *inside* |\n\
+                        | c | int | Line break |";
+
+        for backend in [MarkdownBackendKind::Html2Md, MarkdownBackendKind::DomWalker] {
+            let converter = Converter::with_options(ConverterOptions {
+                markdown_backend: backend,
+                ..Default::default()
+            });
+            let markdown = converter.convert(html, Format::Markdown).await.unwrap();
+            assert!(
+                markdown.contains(&format!("Before\n\n{expected}\n\nAfter")),
+                "{backend:?}: {markdown}"
+            );
+        }
+
+        // Without a header row an empty one keeps the table valid
+        let markdown = Converter::new()
+            .convert(
+                "<table><tr><td>x</td><td>y</td></tr><tr><td>1</td></tr></table>",
+                Format::Markdown,
+            )
+            .await
+            .unwrap();
+        assert_eq!(markdown, "|  |  |\n| --- | --- |\n| x | y |\n| 1 |  |");
+    }
+
+    #[test]
+    fn test_markdown_flavors() {
+        let html = r#"<table><tr><th>A</th></tr><tr><td>1</td></tr></table>
+            <p><del>gone</del> <strike>old</strike></p>
+            <ul><li><input type="checkbox" checked> done</li><li><input type="checkbox"> todo</li></ul>"#;
+
+        let gfm = Converter::new().html_to_markdown(html).unwrap();
+        assert!(gfm.contains("| A |"));
+        assert!(gfm.contains("~~gone~~"));
+        assert!(gfm.contains("~~old~~"));
+        assert!(gfm.contains("* [x] done"));
+        assert!(gfm.contains("* [ ] todo"));
+
+        let commonmark = Converter::with_options(ConverterOptions {
+            markdown_flavor: MarkdownFlavor::CommonMark,
+            ..Default::default()
+        })
+        .html_to_markdown(html)
+        .unwrap();
+        assert!(commonmark.contains("<table><tr><th>A</th>"));
+        assert!(!commonmark.contains("| A |"));
+        assert!(commonmark.contains("<del>gone</del>"));
+        assert!(commonmark.contains("<del>old</del>"));
+        assert!(!commonmark.contains("~~"));
+        assert!(!commonmark.contains("[x]"));
+
+        assert_eq!(
+            MarkdownFlavor::from_str("commonmark").unwrap(),
+            MarkdownFlavor::CommonMark
+        );
+        assert_eq!(
+            MarkdownFlavor::from_str("GFM").unwrap(),
+            MarkdownFlavor::Gfm
+        );
+        assert!(MarkdownFlavor::from_str("wiki").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_markdown_with_frontmatter() {
+        let html = r#"<html><head><title>Guide: Rust</title>
+            <meta name="description" content="Getting started with Rust"></head>
+            <body><h1>Rust</h1><p>Body text</p></body></html>"#;
+        let converter = Converter::new();
+
+        let output = converter
+            .convert(html, Format::MarkdownWithFrontmatter)
+            .await
+            .unwrap();
+        assert!(output.starts_with("---\ntitle: 'Guide: Rust'\n"));
+        assert!(output.contains("description: Getting started with Rust\n---\n\n"));
+        assert!(output.contains("Body text"));
+        assert!(!output.contains("url:"));
+
+        let output = converter
+            .convert_page(
+                html,
+                Format::MarkdownWithFrontmatter,
+                "https://example.com/rust",
+            )
+            .await
+            .unwrap();
+        let yaml = output.split("---\n").nth(1).unwrap();
+        let frontmatter: Frontmatter = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(frontmatter.title.as_deref(), Some("Guide: Rust"));
+        assert_eq!(frontmatter.url.as_deref(), Some("https://example.com/rust"));
+        assert!(frontmatter.fetched.is_some_and(|t| t.ends_with('Z')));
+
+        // Falls back to the first heading and omits missing fields
+        let frontmatter = converter.frontmatter("<h1>Only heading</h1>");
+        assert_eq!(frontmatter.title.as_deref(), Some("Only heading"));
+        assert_eq!(frontmatter.description, None);
+    }
+
+    #[tokio::test]
+    async fn test_convert_asciidoc() {
+        let html = r#"<h1>Guide</h1>
+            <p>Read the <a href="https://example.com/docs">docs</a> for <strong>bold</strong>
+            and <em>italic</em> text with <code>code</code>.</p>
+            <ul><li>One<ol><li>Nested</li></ol></li><li>Two</li></ul>
+            <pre><code>let x = 1;</code></pre>"#;
+
+        let adoc = Converter::new()
+            .convert(html, Format::AsciiDoc)
+            .await
+            .unwrap();
+        assert!(adoc.starts_with("== Guide\n\n"));
+        assert!(adoc.contains("link:https://example.com/docs[docs]"));
+        assert!(adoc.contains("*bold*"));
+        assert!(adoc.contains("_italic_"));
+        assert!(adoc.contains("`code`"));
+        assert!(adoc.contains("* One\n.. Nested\n* Two\n"));
+        assert!(adoc.contains("----\nlet x = 1;\n----"));
+
+        assert_eq!(Format::from_str("adoc").unwrap(), Format::AsciiDoc);
+        assert_eq!(Format::from_str("AsciiDoc").unwrap(), Format::AsciiDoc);
+    }
+
+    #[test]
+    fn test_markdown_to_asciidoc() {
+        let adoc = markdown_to_asciidoc(
+            "## Steps\n\n5. Five\n6. Six\n\n| A | B |\n|---|---|\n| 1 | 2 |\n\n> Quote",
+        );
+        assert_eq!(
+            adoc,
+            "=== Steps\n\n[start=5]\n. Five\n. Six\n\n|===\n|A|B\n\n|1|2\n|===\n\n____\n\nQuote\n\n____"
+        );
+    }
+
+    #[test]
+    fn test_truncate_output() {
+        let marker = TRUNCATION_MARKER;
+        assert_eq!(truncate_output("short", 10), "short");
+
+        // Cuts at a word boundary and stays within the limit, marker included
+        let text = "alpha beta gamma delta epsilon zeta eta theta";
+        let cut = truncate_output(text, 30);
+        assert_eq!(cut, format!("alpha beta{marker}"));
+        assert!(cut.chars().count() <= 30);
+
+        // Never leaves a link half-written
+        let text = "See the [documentation](https://example.com/docs) for details";
+        assert_eq!(truncate_output(text, 45), format!("See the{marker}"));
+
+        // Closes a code fence that would otherwise stay open
+        let text = "Intro\n\n```rust\nlet a = 1;\nlet b = 2;\nlet c = 3;\n```\n\nAfter";
+        let cut = truncate_output(text, 50);
+        assert_eq!(cut, format!("Intro\n\n```rust\nlet a = 1;\n```{marker}"));
+        assert!(cut.chars().count() <= 50);
+    }
+
+    #[tokio::test]
+    async fn test_max_output_chars() {
+        let html = format!(
+            "<h1>Title</h1>{}",
+            "<p>Some words in a paragraph.</p>".repeat(50)
+        );
+        let converter = Converter::with_options(ConverterOptions {
+            max_output_chars: Some(100),
+            ..Default::default()
+        });
+
+        for format in [Format::Markdown, Format::Text, Format::AsciiDoc] {
+            let output = converter.convert(&html, format).await.unwrap();
+            assert!(output.chars().count() <= 100, "{format:?}: {output}");
+            assert!(output.ends_with(TRUNCATION_MARKER));
+        }
+
+        // Structured output stays parseable, with its content truncated
+        let json = converter.convert(&html, Format::Json).await.unwrap();
+        let document: Document = serde_json::from_str(&json).unwrap();
+        assert!(document.content.chars().count() <= 100);
+        assert!(document.content.ends_with(TRUNCATION_MARKER));
+    }
+
+    #[tokio::test]
+    async fn test_normalize_unicode() {
+        // "e" + combining acute, fullwidth "Ｒｕｓｔ", a zero-width space and a control char
+        let html = "<p>Cafe\u{301} \u{FF32}\u{FF55}\u{FF53}\u{FF54} zero\u{200B}width\u{7}</p>";
+
+        let output = Converter::new().convert(html, Format::Text).await.unwrap();
+        assert!(output.contains("Cafe\u{301}"));
+
+        let nfc = Converter::with_options(ConverterOptions {
+            normalize_unicode: Some(UnicodeNormalization::Nfc),
+            ..Default::default()
+        });
+        let output = nfc.convert(html, Format::Text).await.unwrap();
+        assert_eq!(
+            output.trim(),
+            "Caf\u{E9} \u{FF32}\u{FF55}\u{FF53}\u{FF54} zerowidth"
+        );
+
+        let nfkc = Converter::with_options(ConverterOptions {
+            normalize_unicode: Some(UnicodeNormalization::Nfkc),
+            ..Default::default()
+        });
+        let output = nfkc.convert(html, Format::Markdown).await.unwrap();
+        assert_eq!(output.trim(), "Caf\u{E9} Rust zerowidth");
+
+        // Emoji sequences keep their joiners
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(
+            normalize_unicode(family, UnicodeNormalization::Nfkc),
+            family
+        );
+        assert_eq!(
+            normalize_unicode("a\tb\r\nc", UnicodeNormalization::Nfc),
+            "a\tb\nc"
+        );
+        assert!(UnicodeNormalization::from_str("NFKC").is_ok());
+        assert!(UnicodeNormalization::from_str("nfd").is_err());
+    }
+
+    #[test]
+    fn test_links() {
+        let html = r#"<a href="/docs#intro">Docs</a>
+            <a href="guide.html">Guide</a>
+            <a href="https://other.org/">Other</a>
+            <a href="/docs">Docs again</a>
+            <a href="mailto:me@example.com">Mail</a>
+            <a href="javascript:void(0)">JS</a>"#;
+        let converter = Converter::new();
+        assert_eq!(
+            converter.links(html, "https://example.com/a/page"),
+            vec![
+                "https://example.com/docs",
+                "https://example.com/a/guide.html",
+                "https://other.org/",
+            ]
+        );
+
+        // <base href> takes precedence over the page URL
+        let html = r#"<base href="https://cdn.example.com/root/"><a href="x">X</a>"#;
+        assert_eq!(
+            converter.links(html, "https://example.com/"),
+            vec!["https://cdn.example.com/root/x"]
+        );
+        assert_eq!(Format::MarkdownWithFrontmatter.extension(), "md");
+    }
+
+    #[tokio::test]
+    async fn test_base_url_absolutizes_links() {
+        let html = r##"<p><a href="/docs/x">Root</a> <a href="guide.html">Relative</a>
+            <a href="//cdn.example.org/lib.js">Protocol</a> <a href="https://other.org/a">Absolute</a>
+            <a href="#intro">Fragment</a> <img src="img/y.png" alt="Y"></p>"##;
+        let converter = Converter::with_options(ConverterOptions {
+            base_url: Some("https://example.com/a/page".to_string()),
+            ..Default::default()
+        });
+        let markdown = converter.convert(html, Format::Markdown).await.unwrap();
+        assert!(
+            markdown.contains("[Root](https://example.com/docs/x)"),
+            "{markdown}"
+        );
+        assert!(markdown.contains("[Relative](https://example.com/a/guide.html)"));
+        assert!(markdown.contains("[Protocol](https://cdn.example.org/lib.js)"));
+        assert!(markdown.contains("[Absolute](https://other.org/a)"));
+        assert!(markdown.contains("[Fragment](#intro)"));
+        assert!(markdown.contains("![Y](https://example.com/a/img/y.png)"));
+
+        // <base href> takes precedence over the page URL
+        let html = r#"<base href="https://cdn.example.com/root/"><p><a href="x">X</a></p>"#;
+        let markdown = converter.convert(html, Format::Markdown).await.unwrap();
+        assert!(
+            markdown.contains("[X](https://cdn.example.com/root/x)"),
+            "{markdown}"
+        );
+
+        // Without a base URL links are left alone, but fetched pages use their own URL
+        let html = r#"<p><a href="/docs/x">Root</a></p>"#;
+        let converter = Converter::new();
+        let markdown = converter.convert(html, Format::Markdown).await.unwrap();
+        assert!(markdown.contains("[Root](/docs/x)"));
+        let markdown = converter
+            .convert_page(html, Format::Markdown, "http://localhost:8080/a/")
+            .await
+            .unwrap();
+        assert!(
+            markdown.contains("[Root](http://localhost:8080/docs/x)"),
+            "{markdown}"
+        );
+    }
+
+    #[test]
+    fn test_content_kind_detection() {
+        let detect = ContentKind::detect;
+        assert_eq!(
+            detect(Some("text/html; charset=utf-8"), "{}"),
+            ContentKind::Html
+        );
+        assert_eq!(detect(Some("application/json"), ""), ContentKind::Json);
+        assert_eq!(detect(Some("application/ld+json"), ""), ContentKind::Json);
+        assert_eq!(detect(Some("Text/Plain"), ""), ContentKind::Text);
+        assert_eq!(detect(None, " [1, 2]"), ContentKind::Json);
+        assert_eq!(detect(None, "{not json"), ContentKind::Html);
+        assert_eq!(detect(None, "<p>hi</p>"), ContentKind::Html);
     }
-}
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Document {
-    pub title: Option<String>,
-    pub content: String,
-    pub links: Vec<String>,
-    pub images: Vec<String>,
-}
+    #[tokio::test]
+    async fn test_convert_response_by_content_type() {
+        let converter = Converter::new();
+        let url = "https://example.com/api";
+        let json = r#"{"name":"tarzi"}"#;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct Converter;
+        let output = converter
+            .convert_response(json, Some("application/json"), Format::Json, url)
+            .await
+            .unwrap();
+        assert_eq!(output, "{\n  \"name\": \"tarzi\"\n}");
+        let output = converter
+            .convert_response(json, Some("application/json"), Format::Markdown, url)
+            .await
+            .unwrap();
+        assert_eq!(output, "```json\n{\n  \"name\": \"tarzi\"\n}\n```");
+        let output = converter
+            .convert_response(json, Some("application/json"), Format::Yaml, url)
+            .await
+            .unwrap();
+        assert_eq!(output, "name: tarzi\n");
 
-impl Converter {
-    pub fn new() -> Self {
-        Self
+        let text = "a <b> not html\n* not a list";
+        let output = converter
+            .convert_response(text, Some("text/plain"), Format::Markdown, url)
+            .await
+            .unwrap();
+        assert_eq!(output, text);
+        let output = converter
+            .convert_response(text, Some("text/plain"), Format::Json, url)
+            .await
+            .unwrap();
+        let document: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(document["content"], text);
+
+        let output = converter
+            .convert_response("<h1>Hi</h1>", Some("text/html"), Format::Markdown, url)
+            .await
+            .unwrap();
+        assert!(output.starts_with("Hi\n"));
     }
 
-    pub async fn convert(&self, input: &str, format: Format) -> Result<String> {
-        match format {
-            Format::Html => Ok(input.to_string()),
-            Format::Markdown => self.html_to_markdown(input),
-            Format::Json => self.html_to_json(input).await,
-            Format::Yaml => self.html_to_yaml(input).await,
+    #[tokio::test]
+    async fn test_line_breaks() {
+        let html = "<p>a<br>b</p><p>c</p>";
+        for flavor in [MarkdownFlavor::Gfm, MarkdownFlavor::CommonMark] {
+            let converter = Converter::with_options(ConverterOptions {
+                markdown_flavor: flavor,
+                ..Default::default()
+            });
+            let markdown = converter.convert(html, Format::Markdown).await.unwrap();
+            assert_eq!(markdown, "a  \nb\n\nc");
         }
+
+        let converter = Converter::new();
+        let markdown = converter
+            .convert("<div>x<br>y<br></div><div>z</div>", Format::Markdown)
+            .await
+            .unwrap();
+        assert_eq!(markdown, "x  \ny\n\nz");
+
+        let text = converter
+            .convert("<p>a<br>b</p><ul><li>x<br>y</li></ul>", Format::Text)
+            .await
+            .unwrap();
+        assert_eq!(text, "a\nb\n\n- x\n  y");
     }
 
-    /// Convert content using the format specified in the config
-    pub async fn convert_with_config(&self, input: &str, config: &Config) -> Result<String> {
-        let format = Format::from_str(&config.fetcher.format)?;
-        self.convert(input, format).await
+    #[tokio::test]
+    async fn test_convert_text() {
+        let html = r#"<h2>Getting   started</h2>
+            <p>See the <a href="https://example.com/docs">docs</a> and <strong>read</strong>
+            <a href="https://example.com">https://example.com</a>.</p>
+            <ul><li>One<ol><li>Nested</li></ol></li><li>Two</li></ul>
+            <p>Last</p>"#;
+
+        let text = Converter::new().convert(html, Format::Text).await.unwrap();
+        assert_eq!(
+            text,
+            "GETTING STARTED\n\nSee the docs (https://example.com/docs) and read https://example.com.\n\n- One\n  1. Nested\n- Two\n\nLast"
+        );
+
+        let text = Converter::with_options(ConverterOptions {
+            plain_links: true,
+            ..Default::default()
+        })
+        .convert(html, Format::Text)
+        .await
+        .unwrap();
+        assert!(text.contains("See the docs and read"));
+
+        assert_eq!(Format::from_str("text").unwrap(), Format::Text);
+        assert_eq!(Format::from_str("txt").unwrap(), Format::Text);
     }
 
-    fn html_to_markdown(&self, html: &str) -> Result<String> {
-        let markdown = html2md::parse_html(html);
-        Ok(markdown)
+    #[test]
+    fn test_utc_timestamp() {
+        use std::time::{Duration, UNIX_EPOCH};
+        assert_eq!(utc_timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            utc_timestamp(UNIX_EPOCH + Duration::from_secs(951_827_696)),
+            "2000-02-29T12:34:56Z"
+        );
     }
 
-    async fn html_to_json(&self, html: &str) -> Result<String> {
-        let document = self.parse_html_document(html).await?;
-        let json = serde_json::to_string_pretty(&document)?;
-        Ok(json)
+    #[test]
+    fn test_nested_lists() {
+        let html = r#"<ol start="5">
+                <li>Five
+                    <ul>
+                        <li>Bullet<ol><li>Deep one</li><li>Deep two</li></ol></li>
+                        <li>Bullet two</li>
+                    </ul>
+                </li>
+                <li>Six</li>
+            </ol>
+            <p>After</p>"#;
+
+        let markdown = Converter::new().html_to_markdown(html).unwrap();
+        assert_eq!(
+            markdown,
+            "5. Five\n    * Bullet\n        1. Deep one\n        2. Deep two\n    * Bullet two\n6. Six\n\nAfter"
+        );
+
+        // Multi-paragraph items keep continuation lines inside the item
+        let html = "<ul><li><p>First</p><p>Second</p></li><li>Next</li></ul>";
+        let markdown = Converter::new().html_to_markdown(html).unwrap();
+        assert_eq!(markdown, "* First\n\n    Second\n* Next");
     }
 
-    async fn html_to_yaml(&self, html: &str) -> Result<String> {
-        let document = self.parse_html_document(html).await?;
-        let yaml = serde_yaml::to_string(&document)?;
-        Ok(yaml)
+    #[tokio::test]
+    async fn test_dom_walker_backend() {
+        let html = r#"<html><head><title>T</title><style>p {}</style></head><body>
+            <h2>Intro</h2>
+            <p>Some <strong>bold</strong>, <em>em</em> and <code>code</code> with a
+               <a href="https://example.com">link</a>.<br>Next line</p>
+            <blockquote><p>Quoted</p></blockquote>
+            <pre><code class="language-rust">fn main() {}
+</code></pre>
+            <table><tr><th>A</th><th>B</th></tr><tr><td>1</td><td>x|y</td></tr></table>
+            <ul><li>One<ul><li>Nested</li></ul></li><li><del>Two</del> snake_case</li></ul>
+            <img src="/i.png" alt="Pic">
+        </body></html>"#;
+
+        assert_eq!(
+            DomWalkerBackend.to_markdown(html),
+            "## Intro\n\n\
+             Some **bold**, *em* and `code` with a [link](https://example.com).  \nNext line\n\n\
+             > Quoted\n\n\
+             ```rust\nfn main() {}\n```\n\n\
+             | A | B |\n| --- | --- |\n| 1 | x\\|y |\n\n\
+             * One\n    * Nested\n* ~~Two~~ snake\\_case\n\n\
+             ![Pic](/i.png)"
+        );
+
+        // Selected through the converter options, for every output format
+        let converter = Converter::with_options(ConverterOptions {
+            markdown_backend: MarkdownBackendKind::DomWalker,
+            ..Default::default()
+        });
+        let text = converter.convert(html, Format::Text).await.unwrap();
+        assert!(text.contains("Some bold, em and code"));
+        let json = converter
+            .convert("<h1>Title</h1><p>Body</p>", Format::Json)
+            .await
+            .unwrap();
+        let document: Document = serde_json::from_str(&json).unwrap();
+        assert_eq!(document.title.as_deref(), Some("Title"));
+
+        assert_eq!(
+            MarkdownBackendKind::from_str("walker").unwrap(),
+            MarkdownBackendKind::DomWalker
+        );
+        assert!(MarkdownBackendKind::from_str("pandoc").is_err());
     }
 
-    async fn parse_html_document(&self, html: &str) -> Result<Document> {
-        // First convert to markdown
-        let markdown = self.html_to_markdown(html)?;
+    #[tokio::test]
+    async fn test_pdf_output() {
+        assert_eq!(Format::from_str("PDF").unwrap(), Format::Pdf);
+        let html = "<title>Report (draft)</title><h1>Hello</h1><p>Caf\u{e9} \u{2014} world</p>";
 
-        // Parse markdown to extract structured data
-        let mut title = None;
-        let mut content = String::new();
-        let mut links = Vec::new();
-        let mut images = Vec::new();
+        // PDF is binary and only available through convert_bytes
+        let converter = Converter::new();
+        assert!(matches!(
+            converter.convert(html, Format::Pdf).await,
+            Err(TarziError::InvalidFormat(_))
+        ));
+        let text = converter.convert_bytes(html, Format::Text).await.unwrap();
+        assert_eq!(
+            text,
+            converter
+                .convert(html, Format::Text)
+                .await
+                .unwrap()
+                .into_bytes()
+        );
 
-        let parser = MarkdownParser::new(&markdown);
-        let mut in_title = false;
+        let pdf = converter.convert_bytes(html, Format::Pdf).await.unwrap();
+        let raw = String::from_utf8_lossy(&pdf);
+        assert!(raw.starts_with("%PDF-1.4\n"));
+        assert!(raw.ends_with("%%EOF\n"));
+        assert!(raw.contains("/Title (Report \\(draft\\))"));
+        assert!(raw.contains("(HELLO) Tj"));
+        assert!(raw.contains("(Caf\\351 \\227 world) Tj"));
+        assert!(raw.contains("/Count 1"));
+
+        // The cross-reference table points at the objects
+        let startxref: usize = raw.lines().rev().nth(1).unwrap().parse().unwrap();
+        assert!(raw[startxref..].starts_with("xref"));
+        let first_object: usize = raw[startxref..].lines().nth(3).unwrap()[..10]
+            .parse()
+            .unwrap();
+        assert!(raw[first_object..].starts_with("1 0 obj"));
+
+        // Long text wraps and flows onto further pages
+        // (60 paragraphs with blank lines between them fill 119 of 52 lines per page)
+        let long = "<p>word</p>".repeat(60);
+        let pdf = converter.convert_bytes(&long, Format::Pdf).await.unwrap();
+        assert!(String::from_utf8_lossy(&pdf).contains("/Count 3"));
+        for line in wrap_pdf_lines(&"lorem ipsum ".repeat(100), 200.0) {
+            assert!(pdf_text_width(&line) <= 200.0);
+        }
+    }
 
-        for event in parser {
-            match event {
-                Event::Start(Tag::Heading(HeadingLevel::H1, ..)) => {
-                    in_title = true;
-                }
-                Event::Text(text) => {
-                    if in_title {
-                        title = Some(text.to_string());
-                    } else {
-                        content.push_str(&text);
-                        content.push(' ');
-                    }
-                }
-                Event::End(Tag::Heading(HeadingLevel::H1, ..)) => {
-                    in_title = false;
-                }
-                Event::Start(Tag::Link(_, url, _)) => {
-                    links.push(url.to_string());
-                }
-                Event::Start(Tag::Image(_, url, _)) => {
-                    images.push(url.to_string());
-                }
-                Event::End(Tag::Paragraph) => {
-                    content.push('\n');
-                }
-                _ => {}
-            }
+    /// A news article page with the usual boilerplate around the story
+    const ARTICLE_PAGE: &str = r#"<html><head><title>Story</title><script>track()</script></head>
+        <body class="menu-open">
+            <header class="site-header"><a href="/">Daily News</a></header>
+            <nav><ul><li><a href="/world">World</a></li><li><a href="/sport">Sport</a></li></ul></nav>
+            <div id="cookie-banner">We use cookies. <button>Accept</button></div>
+            <h1>Rivers Rise After Storm</h1>
+            <article>
+                <p>Heavy rain pushed rivers over their banks across the region on Monday, officials said.</p>
+                <h2>Evacuations</h2>
+                <p>Residents near the <a href="https://example.com/river">river</a> were asked to leave.</p>
+                <ul><li>Schools closed</li><li>Roads flooded</li></ul>
+                <div class="share-buttons"><a href="/share">Share this story</a></div>
+            </article>
+            <aside><h3>Most read</h3><p>Something else entirely</p></aside>
+            <footer>Copyright Daily News</footer>
+        </body></html>"#;
+
+    #[tokio::test]
+    async fn test_readability() {
+        let converter = Converter::with_options(ConverterOptions {
+            readability: true,
+            ..Default::default()
+        });
+        let markdown = converter
+            .convert(ARTICLE_PAGE, Format::Markdown)
+            .await
+            .unwrap();
+
+        assert!(markdown.contains("Rivers Rise After Storm"), "{markdown}");
+        assert!(markdown.contains("Heavy rain pushed rivers"));
+        assert!(markdown.contains("Evacuations"));
+        assert!(markdown.contains("[river](https://example.com/river)"));
+        assert!(markdown.contains("Schools closed") && markdown.contains("Roads flooded"));
+        for boilerplate in [
+            "Daily News",
+            "World",
+            "cookies",
+            "Accept",
+            "Share this story",
+            "Most read",
+            "Copyright",
+            "track()",
+        ] {
+            assert!(!markdown.contains(boilerplate), "{boilerplate}: {markdown}");
         }
 
-        Ok(Document {
-            title,
-            content: content.trim().to_string(),
-            links,
-            images,
-        })
+        // Without an <article>, the element carrying the most paragraph text wins
+        let html = r#"<div class="links"><p><a href="/a">A</a> | <a href="/b">B</a></p></div>
+            <div class="story"><p>The first paragraph of the story has plenty of text in it.</p>
+            <p>A second paragraph continues the story with more words.</p></div>"#;
+        let text = converter.convert(html, Format::Text).await.unwrap();
+        assert!(text.contains("first paragraph") && text.contains("second paragraph"));
+        assert!(!text.contains("A | B"), "{text}");
+
+        // Enabled through the [converter] section of the config
+        let mut config = Config::new();
+        config.fetcher.format = "markdown".to_string();
+        config.converter.readability = true;
+        let markdown = Converter::new()
+            .convert_with_config(ARTICLE_PAGE, &config)
+            .await
+            .unwrap();
+        assert!(!markdown.contains("Copyright"));
+        let markdown = Converter::from_config(&config)
+            .convert(ARTICLE_PAGE, Format::Markdown)
+            .await
+            .unwrap();
+        assert!(!markdown.contains("Copyright"));
     }
-}
 
-impl Default for Converter {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn test_table_of_contents() {
+        let converter = Converter::new();
+        let html = r#"
+            <h1>Getting Started</h1>
+            <h2>Install &amp; Setup</h2>
+            <h2>Usage</h2>
+            <h3>Usage</h3>
+            <h2>Usage</h2>
+            <h2>   </h2>
+        "#;
+
+        let toc = converter.table_of_contents(html);
+        let anchors: Vec<&str> = toc.iter().map(|e| e.anchor.as_str()).collect();
+        assert_eq!(
+            anchors,
+            vec![
+                "getting-started",
+                "install--setup",
+                "usage",
+                "usage-1",
+                "usage-2"
+            ]
+        );
+        assert_eq!(toc[0].level, 1);
+        assert_eq!(toc[1].text, "Install & Setup");
+        assert_eq!(toc[3].level, 3);
+
+        assert!(converter.table_of_contents("<p>No headings</p>").is_empty());
     }
-}
 
-// Helper function for converting search results
-pub fn convert_search_results(
-    results: &[crate::search::SearchResult],
-    format: Format,
-) -> Result<String> {
-    match format {
-        Format::Json => {
-            let json_results = serde_json::to_string_pretty(results)?;
-            Ok(json_results)
+    #[tokio::test]
+    async fn test_convert_markdown_with_toc() {
+        let converter = Converter::with_options(ConverterOptions {
+            include_toc: true,
+            ..Default::default()
+        });
+        let html = "<h1>Title</h1><p>Intro</p><h2>Part One</h2><p>Body</p>";
+
+        let result = converter.convert(html, Format::Markdown).await.unwrap();
+        assert!(result.starts_with("- [Title](#title)\n  - [Part One](#part-one)\n"));
+        assert!(result.contains("Intro"));
+
+        // Documents without headings are left untouched
+        let result = converter
+            .convert("<p>Plain</p>", Format::Markdown)
+            .await
+            .unwrap();
+        assert!(!result.starts_with("- ["));
+    }
+
+    #[test]
+    fn test_skip_hidden_elements() {
+        let html = r#"<div>
+            <p>Visible text</p>
+            <p style="display: none">Inline none</p>
+            <p style="VISIBILITY:hidden">Inline hidden</p>
+            <div hidden><p>Hidden attribute</p></div>
+            <nav aria-hidden="true">Aria menu</nav>
+            <span class="menu d-none">Class hidden</span>
+            <noscript>Enable JavaScript</noscript>
+            <p>Kept &amp; <a href="/a?x=1&amp;y=2">linked</a></p>
+        </div>"#;
+
+        let default = Converter::new().html_to_markdown(html).unwrap();
+        assert!(default.contains("Inline none"));
+        assert!(default.contains("Aria menu"));
+
+        let converter = Converter::with_options(ConverterOptions {
+            skip_hidden: true,
+            ..Default::default()
+        });
+        let markdown = converter.html_to_markdown(html).unwrap();
+        assert!(markdown.contains("Visible text"));
+        assert!(markdown.contains("Kept & [linked](/a?x=1&y=2)"));
+        for hidden in [
+            "Inline none",
+            "Inline hidden",
+            "Hidden attribute",
+            "Aria menu",
+            "Class hidden",
+            "Enable JavaScript",
+        ] {
+            assert!(!markdown.contains(hidden), "{hidden} should be skipped");
         }
-        Format::Yaml => {
-            let yaml_results = serde_yaml::to_string(results)?;
-            Ok(yaml_results)
+    }
+
+    #[test]
+    fn test_remove_ads() {
+        let html = r#"<article>
+            <h1>Title</h1>
+            <p>Article body</p>
+            <iframe src="https://ads.example.com/frame"></iframe>
+            <ins class="adsbygoogle" data-ad-slot="1">Ad slot</ins>
+            <div class="sidebar ad-container"><p>Buy now</p></div>
+            <div class="social-share"><a href="https://twitter.com/share">Tweet</a></div>
+            <img src="https://t.example.com/pixel.gif" width="1" height="1">
+            <img src="/photo.png" alt="Photo">
+            <script>trackPageView();</script>
+            <style>.ad { display: block; }</style>
+        </article>"#;
+
+        let converter = Converter::new();
+        let stripped = converter.strip_ads(html);
+        assert!(stripped.contains("Article body"));
+        assert!(stripped.contains("/photo.png"));
+        for removed in [
+            "<iframe",
+            "Ad slot",
+            "Buy now",
+            "Tweet",
+            "pixel.gif",
+            "trackPageView",
+            "display: block",
+        ] {
+            assert!(!stripped.contains(removed), "{removed} should be removed");
         }
-        _ => Err(TarziError::InvalidFormat(
-            "Only JSON and YAML formats supported for search results".to_string(),
-        )),
+
+        let converter = Converter::with_options(ConverterOptions {
+            remove_ads: true,
+            ..Default::default()
+        });
+        let markdown = converter.html_to_markdown(html).unwrap();
+        assert!(markdown.contains("Article body"));
+        assert!(!markdown.contains("Buy now"));
+        assert!(!markdown.contains("Tweet"));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::search::SearchResult;
+    #[test]
+    fn test_sections() {
+        let converter = Converter::new();
+        let html = r#"<body>
+            <p>Intro text</p>
+            <article>
+                <h1>Guide</h1>
+                <p>Overview of <b>everything</b>.</p>
+                <section><h2>Install</h2><ul><li>Step one</li></ul></section>
+                <section><h2>Usage</h2></section>
+            </article>
+            <h3>  Notes
+            </h3><p>Last words</p>
+        </body>"#;
+
+        let sections = converter.sections(html);
+        let outline: Vec<(&str, u8)> = sections
+            .iter()
+            .map(|s| (s.heading.as_str(), s.level))
+            .collect();
+        assert_eq!(
+            outline,
+            vec![
+                ("", 0),
+                ("Guide", 1),
+                ("Install", 2),
+                ("Usage", 2),
+                ("Notes", 3)
+            ]
+        );
+        assert_eq!(sections[0].content_markdown, "Intro text");
+        assert_eq!(sections[1].content_markdown, "Overview of **everything**.");
+        assert!(sections[2].content_markdown.contains("Step one"));
+        assert!(!sections[2].content_markdown.contains("Install"));
+        assert_eq!(sections[3].content_markdown, "");
+        assert_eq!(sections[4].content_markdown, "Last words");
+
+        assert!(converter.sections("").is_empty());
+    }
 
     #[test]
-    fn test_format_parsing() {
-        // Test valid formats
-        assert_eq!(Format::from_str("html").unwrap(), Format::Html);
-        assert_eq!(Format::from_str("HTML").unwrap(), Format::Html);
-        assert_eq!(Format::from_str("markdown").unwrap(), Format::Markdown);
-        assert_eq!(Format::from_str("MARKDOWN").unwrap(), Format::Markdown);
-        assert_eq!(Format::from_str("md").unwrap(), Format::Markdown);
-        assert_eq!(Format::from_str("MD").unwrap(), Format::Markdown);
-        assert_eq!(Format::from_str("json").unwrap(), Format::Json);
-        assert_eq!(Format::from_str("JSON").unwrap(), Format::Json);
-        assert_eq!(Format::from_str("yaml").unwrap(), Format::Yaml);
-        assert_eq!(Format::from_str("YAML").unwrap(), Format::Yaml);
-        assert_eq!(Format::from_str("yml").unwrap(), Format::Yaml);
-        assert_eq!(Format::from_str("YML").unwrap(), Format::Yaml);
+    fn test_summary_sources() {
+        let converter = Converter::new();
+        let paragraph =
+            "<p>Short.</p><p>This paragraph is long enough to be used as a summary.</p>";
 
-        // Test invalid formats
-        assert!(Format::from_str("invalid").is_err());
-        assert!(Format::from_str("").is_err());
-        assert!(Format::from_str("xml").is_err());
+        let html = format!(
+            r#"<head><meta name="description" content=" Meta  description "><meta property="og:description" content="OG description"></head><body>{paragraph}</body>"#
+        );
+        assert_eq!(converter.summary(&html, 100), "Meta description");
+
+        let html = format!(
+            r#"<head><meta property="og:description" content="OG description"></head><body>{paragraph}</body>"#
+        );
+        assert_eq!(converter.summary(&html, 100), "OG description");
+
+        assert_eq!(
+            converter.summary(paragraph, 100),
+            "This paragraph is long enough to be used as a summary."
+        );
+        assert_eq!(converter.summary("<p>Too short</p>", 100), "");
     }
 
     #[test]
-    fn test_converter_creation() {
+    fn test_extract_metadata() {
+        let converter = Converter::new();
+        let html = r#"<html><head>
+            <title>Fallback title</title>
+            <meta name="description" content="Plain description">
+            <meta property="og:title" content="Rust 1.80 released">
+            <meta property="og:description" content=" The  Rust team is happy to announce 1.80 ">
+            <meta property="og:image" content="https://blog.rust-lang.org/images/rust-logo.png">
+            <meta name="og:site_name" content="Rust Blog">
+            <script type="application/ld+json">
+              {"@context": "https://schema.org", "@type": "BlogPosting",
+               "headline": "Rust 1.80 released", "datePublished": "2024-07-25"}
+            </script>
+            <script type="application/ld+json">[{"@type": "BreadcrumbList"}, {"@type": "Organization"}]</script>
+            <script type="application/ld+json">{ not json }</script>
+            <script>var ignored = {"datePublished": "1999-01-01"};</script>
+          </head><body><h1>Rust 1.80</h1></body></html>"#;
+
+        let metadata = converter.extract_metadata(html).unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("Rust 1.80 released"));
+        assert_eq!(
+            metadata.description.as_deref(),
+            Some("The Rust team is happy to announce 1.80")
+        );
+        assert_eq!(
+            metadata.image.as_deref(),
+            Some("https://blog.rust-lang.org/images/rust-logo.png")
+        );
+        assert_eq!(metadata.site_name.as_deref(), Some("Rust Blog"));
+        // No article:published_time, so the JSON-LD date is used
+        assert_eq!(metadata.published_time.as_deref(), Some("2024-07-25"));
+        assert_eq!(metadata.json_ld.len(), 3);
+        assert_eq!(metadata.json_ld[0]["@type"], "BlogPosting");
+        assert_eq!(metadata.json_ld[2]["@type"], "Organization");
+    }
+
+    #[test]
+    fn test_extract_metadata_fallbacks() {
+        let converter = Converter::new();
+        let html = r#"<head><title> Plain  page </title>
+            <meta name="description" content="Plain description">
+            <meta property="article:published_time" content="2024-01-02T03:04:05Z"></head>"#;
+
+        let metadata = converter.extract_metadata(html).unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("Plain page"));
+        assert_eq!(metadata.description.as_deref(), Some("Plain description"));
+        assert_eq!(
+            metadata.published_time.as_deref(),
+            Some("2024-01-02T03:04:05Z")
+        );
+        assert!(metadata.image.is_none());
+        assert!(metadata.site_name.is_none());
+        assert!(metadata.json_ld.is_empty());
+
+        assert_eq!(
+            converter.extract_metadata("").unwrap(),
+            PageMetadata::default()
+        );
+    }
+
+    #[test]
+    fn test_summary_truncation() {
         let converter = Converter::new();
-        assert_eq!(converter, Converter);
+        let html =
+            r#"<meta name="description" content="The quick brown fox jumps over the lazy dog">"#;
+
+        let summary = converter.summary(html, 20);
+        assert_eq!(summary, "The quick brown fox…");
+        assert!(summary.chars().count() <= 20);
+        assert_eq!(converter.summary(html, 0), "");
     }
 
     #[test]
@@ -382,12 +3574,14 @@ mod tests {
                 url: "https://example1.com".to_string(),
                 snippet: "Snippet 1".to_string(),
                 rank: 1,
+                date: None,
             },
             SearchResult {
                 title: "Test Result 2".to_string(),
                 url: "https://example2.com".to_string(),
                 snippet: "Snippet 2".to_string(),
                 rank: 2,
+                date: None,
             },
         ];
 
@@ -401,6 +3595,102 @@ mod tests {
         assert!(json_result.contains("\"url\""));
         assert!(json_result.contains("\"snippet\""));
         assert!(json_result.contains("\"rank\""));
+        assert!(json_result.contains("\"schema_version\""));
+    }
+
+    #[test]
+    fn test_convert_search_results_ndjson() {
+        let results: Vec<SearchResult> = (1..=3)
+            .map(|rank| SearchResult {
+                title: format!("Result {rank}"),
+                url: format!("https://example{rank}.com"),
+                snippet: "Line one\nline two".to_string(),
+                rank,
+                date: None,
+            })
+            .collect();
+
+        let ndjson = convert_search_results(&results, Format::NdJson).unwrap();
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), results.len());
+        for (line, result) in lines.iter().zip(&results) {
+            let parsed: SearchResult = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed.url, result.url);
+            assert_eq!(parsed.snippet, result.snippet);
+        }
+        assert_eq!(Format::from_str("jsonl").unwrap(), Format::NdJson);
+        assert_eq!(convert_search_results(&[], Format::NdJson).unwrap(), "");
+    }
+
+    /// Minimal RFC 4180 reader: quoted fields may hold commas, doubled quotes and
+    /// line breaks
+    fn read_csv(csv: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut chars = csv.chars().peekable();
+        let mut quoted = false;
+        while let Some(c) = chars.next() {
+            match (quoted, c) {
+                (true, '"') if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                (true, '"') => quoted = false,
+                (true, c) => field.push(c),
+                (false, '"') => quoted = true,
+                (false, ',') => row.push(std::mem::take(&mut field)),
+                (false, '\r') if chars.peek() == Some(&'\n') => {}
+                (false, '\n') => {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                (false, c) => field.push(c),
+            }
+        }
+        rows
+    }
+
+    #[tokio::test]
+    async fn test_convert_search_results_csv() {
+        let results = vec![
+            SearchResult {
+                title: "Rust \"book\"".to_string(),
+                url: "https://doc.rust-lang.org/book/".to_string(),
+                snippet: "Ownership, borrowing, \"lifetimes\"\nand more".to_string(),
+                rank: 1,
+                date: Some("2024-07-25".to_string()),
+            },
+            SearchResult {
+                title: "Plain".to_string(),
+                url: "https://example.com".to_string(),
+                snippet: String::new(),
+                rank: 2,
+                date: None,
+            },
+        ];
+
+        let csv = convert_search_results(&results, Format::Csv).unwrap();
+        assert!(csv.starts_with("rank,title,url,snippet,date\r\n"));
+        assert!(csv.contains("\"Rust \"\"book\"\"\""));
+
+        let rows = read_csv(&csv);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], ["rank", "title", "url", "snippet", "date"]);
+        assert_eq!(
+            rows[1],
+            [
+                "1",
+                "Rust \"book\"",
+                "https://doc.rust-lang.org/book/",
+                "Ownership, borrowing, \"lifetimes\"\nand more",
+                "2024-07-25"
+            ]
+        );
+        assert_eq!(rows[2], ["2", "Plain", "https://example.com", "", ""]);
+
+        let converter = Converter::new();
+        assert!(converter.convert("<p>Hi</p>", Format::Csv).await.is_err());
     }
 
     #[test]
@@ -410,6 +3700,7 @@ mod tests {
             url: "https://yaml-test.com".to_string(),
             snippet: "YAML snippet".to_string(),
             rank: 1,
+            date: None,
         }];
 
         let yaml_result = convert_search_results(&results, Format::Yaml).unwrap();
@@ -421,6 +3712,7 @@ mod tests {
         assert!(yaml_result.contains("url:"));
         assert!(yaml_result.contains("snippet:"));
         assert!(yaml_result.contains("rank:"));
+        assert!(yaml_result.contains("schema_version: 1"));
     }
 
     #[test]
@@ -430,6 +3722,7 @@ mod tests {
             url: "https://test.com".to_string(),
             snippet: "Snippet".to_string(),
             rank: 1,
+            date: None,
         }];
 
         // Test with unsupported formats
@@ -443,7 +3736,12 @@ mod tests {
         let format = Format::Json;
 
         let result = convert_search_results(&results, format).unwrap();
-        assert_eq!(result, "[]");
+        let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(
+            value["schema_version"],
+            crate::constants::SEARCH_RESULTS_SCHEMA_VERSION
+        );
+        assert_eq!(value["results"], serde_json::json!([]));
     }
 
     #[test]
@@ -1,3 +1,8 @@
+use crate::constants::{
+    EXIT_CODE_BLOCKED, EXIT_CODE_CONFIG, EXIT_CODE_DRIVER, EXIT_CODE_FAILURE,
+    EXIT_CODE_INTERRUPTED, EXIT_CODE_NETWORK, EXIT_CODE_PARSE, EXIT_CODE_TIMEOUT,
+};
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -53,11 +58,141 @@ pub enum TarziError {
     #[error("Driver process error: {0}")]
     DriverProcess(String),
 
+    #[error("Operation timed out: {0}")]
+    Timeout(String),
+
     #[error("Network error: {0}")]
     Network(String),
 
     #[error("Parse error: {0}")]
     Parse(String),
+
+    #[error("Blocked by anti-bot measures: {0}")]
+    Blocked(String),
+
+    #[error("Interrupted")]
+    Interrupted,
+
+    /// The server answered 429 Too Many Requests; `retry_after` is how long its
+    /// `Retry-After` header asked to wait, if it sent one
+    #[error("Rate limited{}", retry_after.map(|d| format!(", retry after {}s", d.as_secs())).unwrap_or_default())]
+    RateLimited { retry_after: Option<Duration> },
+}
+
+impl TarziError {
+    /// Process exit code for this error, letting scripts branch on the failure
+    /// category: network 2, configuration 3, blocked 4, timeout 5, parse 6,
+    /// driver 7, interrupted 130, anything else 1
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            TarziError::Timeout(_) => EXIT_CODE_TIMEOUT,
+            TarziError::Http(e) if e.is_timeout() => EXIT_CODE_TIMEOUT,
+            TarziError::Http(_) | TarziError::Network(_) | TarziError::RateLimited { .. } => {
+                EXIT_CODE_NETWORK
+            }
+            TarziError::Config(_)
+            | TarziError::InvalidFormat(_)
+            | TarziError::InvalidMode(_)
+            | TarziError::InvalidEngine(_) => EXIT_CODE_CONFIG,
+            TarziError::Blocked(_) => EXIT_CODE_BLOCKED,
+            TarziError::Parse(_)
+            | TarziError::Url(_)
+            | TarziError::Json(_)
+            | TarziError::Yaml(_) => EXIT_CODE_PARSE,
+            TarziError::Driver(_)
+            | TarziError::DriverNotFound(_)
+            | TarziError::DriverProcess(_)
+            | TarziError::WebDriver(_) => EXIT_CODE_DRIVER,
+            TarziError::Interrupted => EXIT_CODE_INTERRUPTED,
+            _ => EXIT_CODE_FAILURE,
+        }
+    }
+
+    /// [`TarziError::RateLimited`] for a 429 response with these headers
+    pub(crate) fn rate_limited(headers: &reqwest::header::HeaderMap) -> Self {
+        let retry_after = headers
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        TarziError::RateLimited { retry_after }
+    }
+}
+
+/// `Retry-After` value: delay seconds, or an HTTP date (a date in the past means
+/// no wait)
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
 }
 
 pub type Result<T> = std::result::Result<T, TarziError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(
+            TarziError::Network("down".to_string()).exit_code(),
+            EXIT_CODE_NETWORK
+        );
+        assert_eq!(
+            TarziError::InvalidFormat("xml".to_string()).exit_code(),
+            EXIT_CODE_CONFIG
+        );
+        assert_eq!(
+            TarziError::Blocked("captcha".to_string()).exit_code(),
+            EXIT_CODE_BLOCKED
+        );
+        assert_eq!(
+            TarziError::Timeout("page load".to_string()).exit_code(),
+            EXIT_CODE_TIMEOUT
+        );
+        assert_eq!(TarziError::Interrupted.exit_code(), EXIT_CODE_INTERRUPTED);
+        assert_eq!(
+            TarziError::RateLimited { retry_after: None }.exit_code(),
+            EXIT_CODE_NETWORK
+        );
+        assert_eq!(
+            TarziError::Parse("bad".to_string()).exit_code(),
+            EXIT_CODE_PARSE
+        );
+        assert_eq!(
+            TarziError::DriverNotFound("chromedriver".to_string()).exit_code(),
+            EXIT_CODE_DRIVER
+        );
+        assert_eq!(
+            TarziError::Search("no results".to_string()).exit_code(),
+            EXIT_CODE_FAILURE
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("5"), Some(Duration::from_secs(5)));
+        assert_eq!(parse_retry_after(" 120 "), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        let later = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(60));
+        let delay = parse_retry_after(&later).unwrap();
+        assert!(delay > Duration::from_secs(55) && delay <= Duration::from_secs(60));
+        assert_eq!(parse_retry_after("soon"), None);
+        assert_eq!(
+            TarziError::RateLimited {
+                retry_after: Some(Duration::from_secs(5))
+            }
+            .to_string(),
+            "Rate limited, retry after 5s"
+        );
+    }
+}
@@ -1,24 +1,31 @@
-use super::driver::{DriverConfig, DriverInfo, DriverManager, DriverType};
+use super::driver::{
+    DriverConfig, DriverInfo, DriverManager, DriverStatus, DriverType, PersistentDriver,
+};
+use super::proxy::{proxy_credentials, strip_credentials};
 use crate::{
     Result,
     config::Config,
     constants::{
-        BROWSER_LAUNCH_TIMEOUT, CHROME_DRIVER_ARGS, CHROMEDRIVER_DEFAULT_PORT, DEFAULT_TIMEOUT,
-        FIREFOX_DRIVER_ARGS, GECKODRIVER_DEFAULT_PORT, WEBDRIVER_CHECK_TIMEOUT,
+        BROWSER_LAUNCH_TIMEOUT, BROWSER_QUIT_TIMEOUT, CHROME_DRIVER_ARGS,
+        CHROMEDRIVER_DEFAULT_PORT, DEFAULT_TIMEOUT, EXTERNAL_BROWSER_CHECK_TIMEOUT,
+        EXTERNAL_BROWSER_ID, FIREFOX_DRIVER_ARGS, GECKODRIVER_DEFAULT_PORT,
+        WEBDRIVER_CHECK_TIMEOUT,
     },
     error::TarziError,
 };
-use std::{collections::HashMap, path::PathBuf};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 use tempfile::TempDir;
-use thirtyfour::{ChromiumLikeCapabilities, DesiredCapabilities, WebDriver};
+use thirtyfour::{ChromiumLikeCapabilities, DesiredCapabilities, TimeoutConfiguration, WebDriver};
 use tracing::{error, info, warn};
 
 /// Browser instance manager
 #[derive(Debug)]
 pub struct BrowserManager {
-    browsers: HashMap<String, (WebDriver, TempDir)>,
+    browsers: HashMap<String, (WebDriver, Option<TempDir>)>,
     driver_manager: Option<DriverManager>,
     managed_driver_info: Option<DriverInfo>,
+    /// Self-managed driver that died mid-run; tried last when restarting
+    failed_driver: Option<DriverType>,
     config: Option<Config>,
 }
 
@@ -28,6 +35,7 @@ impl BrowserManager {
             browsers: HashMap::new(),
             driver_manager: None,
             managed_driver_info: None,
+            failed_driver: None,
             config: None,
         }
     }
@@ -38,10 +46,73 @@ impl BrowserManager {
             browsers: HashMap::new(),
             driver_manager: None,
             managed_driver_info: None,
+            failed_driver: None,
             config: Some(config.clone()),
         }
     }
 
+    /// Whether fetches reuse the browser's long-lived page (`fetcher.reuse_page`,
+    /// implied by `fetcher.reuse_browser`)
+    pub fn reuse_page(&self) -> bool {
+        self.config
+            .as_ref()
+            .is_none_or(|config| config.fetcher.reuse_page || config.fetcher.reuse_browser)
+    }
+
+    /// Whether a search-and-fetch run uses one browser, shut down when the run
+    /// ends (`fetcher.reuse_browser`)
+    pub fn reuse_browser(&self) -> bool {
+        self.config
+            .as_ref()
+            .is_some_and(|config| config.fetcher.reuse_browser)
+    }
+
+    /// Prepare the page a fetch navigates. A reused page is kept while it is alive
+    /// and replaced if it was closed; without reuse each fetch gets a fresh tab,
+    /// closed again by [`Self::release_page`].
+    pub async fn prepare_page(browser: &WebDriver, reuse_page: bool) -> Result<()> {
+        if reuse_page {
+            if browser.window().await.is_ok() {
+                return Ok(());
+            }
+            warn!("Reused browser page is gone, opening a new one");
+        }
+        Self::open_page(browser).await
+    }
+
+    /// Open a new tab and make it the page subsequent navigations use
+    pub async fn open_page(browser: &WebDriver) -> Result<()> {
+        let handle = browser
+            .new_tab()
+            .await
+            .map_err(|e| TarziError::Browser(format!("Failed to open a new page: {e}")))?;
+        browser
+            .switch_to_window(handle)
+            .await
+            .map_err(|e| TarziError::Browser(format!("Failed to switch to the new page: {e}")))
+    }
+
+    /// Close the per-fetch tab opened by [`Self::prepare_page`] when pages are not
+    /// reused. The last window is kept open so the session stays usable.
+    pub async fn release_page(browser: &WebDriver, reuse_page: bool) {
+        if reuse_page {
+            return;
+        }
+        let (Ok(current), Ok(windows)) = (browser.window().await, browser.windows().await) else {
+            return;
+        };
+        let Some(remaining) = windows.into_iter().find(|handle| *handle != current) else {
+            return;
+        };
+        if let Err(e) = browser.close_window().await {
+            warn!("Failed to close browser page: {}", e);
+            return;
+        }
+        if let Err(e) = browser.switch_to_window(remaining).await {
+            warn!("Failed to switch to remaining browser page: {}", e);
+        }
+    }
+
     /// Create a new browser instance with a specific user data directory
     pub async fn create_browser_with_user_data(
         &mut self,
@@ -105,21 +176,6 @@ impl BrowserManager {
 
         info!("Browser config created successfully");
 
-        // Create or use provided temp directory for browser data
-        let temp_dir = if let Some(user_data_path) = user_data_dir {
-            info!("Using provided user data directory: {:?}", user_data_path);
-            // Create a temp dir as a placeholder - the actual user data dir is configured in capabilities
-            TempDir::new().map_err(|e| {
-                error!("Failed to create placeholder directory: {}", e);
-                TarziError::Browser(format!("Failed to create placeholder directory: {e}"))
-            })?
-        } else {
-            TempDir::new().map_err(|e| {
-                error!("Failed to create temporary directory: {}", e);
-                TarziError::Browser(format!("Failed to create temporary directory: {e}"))
-            })?
-        };
-
         let browser = match browser_result {
             Ok(Ok(result)) => {
                 info!("Browser launched successfully with ID: {}", instance_id);
@@ -132,11 +188,31 @@ impl BrowserManager {
                 )));
             }
             Err(_) => {
-                error!("Timeout while launching browser (60 seconds)");
-                return Err(TarziError::Browser(
-                    "Timeout while launching browser".to_string(),
-                ));
+                error!(
+                    "Timeout while launching browser ({} seconds)",
+                    BROWSER_LAUNCH_TIMEOUT.as_secs()
+                );
+                return Err(TarziError::Timeout(format!(
+                    "WebDriver session creation did not complete within {} seconds; \
+                     the driver at {webdriver_url} may be unresponsive",
+                    BROWSER_LAUNCH_TIMEOUT.as_secs()
+                )));
+            }
+        };
+
+        self.apply_driver_timeouts(&browser).await;
+
+        // A provided user data dir is owned by the caller; only allocate a
+        // temporary directory (removed with the instance) when none was given
+        let temp_dir = match user_data_dir {
+            Some(user_data_path) => {
+                info!("Using provided user data directory: {:?}", user_data_path);
+                None
             }
+            None => Some(TempDir::new().map_err(|e| {
+                error!("Failed to create temporary directory: {}", e);
+                TarziError::Browser(format!("Failed to create temporary directory: {e}"))
+            })?),
         };
 
         self.browsers
@@ -145,6 +221,23 @@ impl BrowserManager {
         Ok(instance_id)
     }
 
+    /// Configure the session's own script and page load timeouts from the config.
+    /// These govern how long the driver itself waits, independently of the
+    /// tarzi-level timeouts wrapped around each operation.
+    async fn apply_driver_timeouts(&self, browser: &WebDriver) {
+        let Some(timeouts) = self.config.as_ref().and_then(driver_timeouts) else {
+            return;
+        };
+        info!(
+            "Setting WebDriver timeouts (script: {:?}, page load: {:?})",
+            timeouts.script(),
+            timeouts.page_load()
+        );
+        if let Err(e) = browser.update_timeouts(timeouts).await {
+            warn!("Failed to set WebDriver timeouts: {}", e);
+        }
+    }
+
     /// Get driver type from configuration
     fn get_driver_type_from_config(&self) -> &str {
         if let Some(config) = &self.config {
@@ -207,15 +300,22 @@ impl BrowserManager {
         // Add proxy configuration if available
         if let Some(config) = &self.config {
             let proxy = crate::config::get_proxy_from_env_or_config(&config.fetcher.proxy);
-            if let Some(proxy_url) = proxy {
-                if !proxy_url.is_empty() {
-                    info!("Configuring browser with proxy: {}", proxy_url);
-                    caps.add_arg(&format!("--proxy-server={proxy_url}"))
-                        .map_err(|e| {
-                            error!("Failed to add proxy-server arg: {}", e);
-                            TarziError::Browser(format!("Failed to add proxy-server arg: {e}"))
-                        })?;
+            if let Some(proxy_url) = proxy
+                && !proxy_url.is_empty()
+            {
+                if proxy_credentials(&proxy_url).is_some() {
+                    warn!(
+                        "Proxy credentials are not supported in browser mode, connecting \
+                         without them; use an unauthenticated or IP-allowlisted proxy"
+                    );
                 }
+                let proxy_url = strip_credentials(&proxy_url);
+                info!("Configuring browser with proxy: {}", proxy_url);
+                caps.add_arg(&format!("--proxy-server={proxy_url}"))
+                    .map_err(|e| {
+                        error!("Failed to add proxy-server arg: {}", e);
+                        TarziError::Browser(format!("Failed to add proxy-server arg: {e}"))
+                    })?;
             }
         }
         Ok(())
@@ -259,13 +359,14 @@ impl BrowserManager {
 
     /// Remove a browser instance by ID
     pub async fn remove_browser(&mut self, instance_id: &str) -> Result<bool> {
-        if let Some((driver, _temp_dir)) = self.browsers.remove(instance_id) {
+        if let Some((driver, temp_dir)) = self.browsers.remove(instance_id) {
             info!("Removed browser instance: {}", instance_id);
-            driver.quit().await.map_err(|e| {
+            let quit_result = quit_with_timeout(driver).await;
+            cleanup_temp_dir(instance_id, temp_dir);
+            quit_result.map_err(|e| {
                 error!("Failed to quit browser: {}", e);
-                TarziError::Browser(format!("Failed to quit browser: {e}"))
+                e
             })?;
-            // The temp_dir will be automatically cleaned up when dropped
             Ok(true)
         } else {
             warn!("Browser instance not found: {}", instance_id);
@@ -273,8 +374,18 @@ impl BrowserManager {
         }
     }
 
-    /// Get or create a browser instance
+    /// Get or create a browser instance. With `fetcher.browser_debugger_address`
+    /// configured, the running browser is attached to instead and `headless` has
+    /// no effect.
     pub async fn get_or_create_browser(&mut self, headless: bool) -> Result<&WebDriver> {
+        let debugger_address = self
+            .config
+            .as_ref()
+            .and_then(|config| config.fetcher.browser_debugger_address.clone())
+            .filter(|address| !address.is_empty());
+        if let Some(endpoint) = debugger_address {
+            return self.connect_external_browser(&endpoint).await;
+        }
         if self.browsers.is_empty() {
             info!("Creating new browser instance (headless: {})...", headless);
             let instance_id = self
@@ -287,6 +398,56 @@ impl BrowserManager {
         Ok(&self.browsers.values().next().unwrap().0)
     }
 
+    /// Attach to an already running Chrome through its remote debugging endpoint
+    /// (`host:port`, or the `ws://` URL printed by `--remote-debugging-port`),
+    /// storing the session under the `"external"` instance ID. The session is
+    /// created through chromedriver, which leaves the browser running on quit.
+    pub async fn connect_external_browser(&mut self, endpoint: &str) -> Result<&WebDriver> {
+        if !self.browsers.contains_key(EXTERNAL_BROWSER_ID) {
+            // Fail fast rather than waiting for chromedriver to give up on the address
+            if !check_external_browser_prerequisites(endpoint).await {
+                return Err(TarziError::Browser(format!(
+                    "External browser debugging endpoint {endpoint} is not reachable. \
+                     Start Chrome with --remote-debugging-port or fix \
+                     fetcher.browser_debugger_address."
+                )));
+            }
+            let address = debugger_address(endpoint);
+            info!("Attaching to external browser at {}", address);
+            let webdriver_url = self.get_or_create_webdriver_endpoint().await?;
+
+            let mut caps = DesiredCapabilities::chrome();
+            caps.set_debugger_address(&address).map_err(|e| {
+                TarziError::Browser(format!("Failed to set debugger address {endpoint}: {e}"))
+            })?;
+            let browser = match tokio::time::timeout(
+                BROWSER_LAUNCH_TIMEOUT,
+                WebDriver::new(&webdriver_url, caps),
+            )
+            .await
+            {
+                Ok(Ok(browser)) => browser,
+                Ok(Err(e)) => {
+                    return Err(TarziError::Browser(format!(
+                        "Failed to connect to external browser at {endpoint}: {e}"
+                    )));
+                }
+                Err(_) => {
+                    return Err(TarziError::Browser(format!(
+                        "Timed out connecting to external browser at {endpoint} after {} seconds",
+                        BROWSER_LAUNCH_TIMEOUT.as_secs()
+                    )));
+                }
+            };
+
+            self.apply_driver_timeouts(&browser).await;
+            self.browsers
+                .insert(EXTERNAL_BROWSER_ID.to_string(), (browser, None));
+            info!("Attached to external browser at {}", address);
+        }
+        Ok(&self.browsers[EXTERNAL_BROWSER_ID].0)
+    }
+
     /// Create multiple browser instances for parallel processing
     pub async fn create_multiple_browsers(
         &mut self,
@@ -329,54 +490,44 @@ impl BrowserManager {
     /// 1. External: configured by web_driver_url - if set, use it exclusively and fail if unavailable
     /// 2. Self-managed: managed by DriverManager - used only if web_driver_url is not set
     async fn get_or_create_webdriver_endpoint(&mut self) -> Result<String> {
-        if let Some(config) = &self.config {
-            if let Some(ref url) = config.fetcher.web_driver_url {
-                if !url.is_empty() {
-                    // External driver type: web_driver_url is explicitly configured
-                    info!("Using external WebDriver URL from config: {}", url);
-                    if is_webdriver_available_at_url(url).await {
-                        info!(
-                            "External WebDriver server is available and ready at: {}",
-                            url
-                        );
-                        return Ok(url.clone());
-                    } else {
-                        error!(
-                            "External WebDriver URL '{}' is configured but server is not available",
-                            url
-                        );
-                        return Err(TarziError::Browser(format!(
-                            "External WebDriver server is not available at configured URL: {url}. \
+        if let Some(config) = &self.config
+            && let Some(ref url) = config.fetcher.web_driver_url
+            && !url.is_empty()
+        {
+            // External driver type: web_driver_url is explicitly configured
+            info!("Using external WebDriver URL from config: {}", url);
+            if is_webdriver_available_at_url(url).await {
+                info!(
+                    "External WebDriver server is available and ready at: {}",
+                    url
+                );
+                return Ok(url.clone());
+            } else {
+                error!(
+                    "External WebDriver URL '{}' is configured but server is not available",
+                    url
+                );
+                return Err(TarziError::Driver(format!(
+                    "External WebDriver server is not available at configured URL: {url}. \
                              Please ensure the WebDriver server is running at this URL, or remove \
                              the web_driver_url configuration to use self-managed drivers."
-                        )));
-                    }
-                }
+                )));
             }
         }
 
         // Self-managed driver type: no web_driver_url configured, use DriverManager
         info!("No external WebDriver URL configured, using self-managed driver");
 
+        // Reuse a driver kept running by `tarzi driver start`
+        if let Some(driver) = PersistentDriver::load()
+            && is_webdriver_available_at_url(&driver.endpoint).await
+        {
+            info!("Using persistent WebDriver at: {}", driver.endpoint);
+            return Ok(driver.endpoint);
+        }
+
         // Try to find an already running WebDriver first (from previous self-managed instance)
-        let default_url = if let Some(config) = &self.config {
-            let web_driver = &config.fetcher.web_driver;
-            match web_driver.as_str() {
-                "geckodriver" | "firefox" => {
-                    format!("http://localhost:{GECKODRIVER_DEFAULT_PORT}")
-                }
-                "chromedriver" | "chrome" => {
-                    format!("http://localhost:{CHROMEDRIVER_DEFAULT_PORT}")
-                }
-                _ => {
-                    // Fallback to GeckoDriver for unknown driver types
-                    format!("http://localhost:{GECKODRIVER_DEFAULT_PORT}")
-                }
-            }
-        } else {
-            // No config available, use GeckoDriver as default
-            format!("http://localhost:{GECKODRIVER_DEFAULT_PORT}")
-        };
+        let default_url = self.default_driver_url();
 
         info!(
             "Checking for existing self-managed WebDriver at: {}",
@@ -398,25 +549,24 @@ impl BrowserManager {
         // Initialize DriverManager if not already done
         if self.driver_manager.is_none() {
             info!("Initializing DriverManager for self-managed driver");
-            self.driver_manager = Some(DriverManager::new());
+            let auto_download = self
+                .config
+                .as_ref()
+                .is_some_and(|config| config.fetcher.auto_download_driver);
+            self.driver_manager = Some(DriverManager::new().with_auto_download(auto_download));
         }
 
         // Try to start a driver using DriverManager
         let driver_manager = self.driver_manager.as_ref().unwrap();
 
-        // Determine which driver to try first based on configuration
-        let (primary_driver, fallback_driver) = if let Some(config) = &self.config {
-            match config.fetcher.web_driver.as_str() {
-                "geckodriver" | "firefox" => (DriverType::Firefox, DriverType::Chrome),
-                _ => (DriverType::Chrome, DriverType::Firefox),
-            }
-        } else {
-            (DriverType::Chrome, DriverType::Firefox)
-        };
-
         // Try drivers in order: primary first, then fallback
-        for driver_type in [primary_driver, fallback_driver] {
-            match self.try_start_driver(driver_manager, &driver_type) {
+        for driver_type in self.driver_order() {
+            // Downloads the driver first when it is missing and auto download is on
+            let started = match driver_manager.ensure_driver(&driver_type).await {
+                Ok(_) => self.try_start_driver(driver_manager, &driver_type),
+                Err(e) => Err(e),
+            };
+            match started {
                 Ok(driver_info) => {
                     info!(
                         "Successfully started self-managed {:?} at: {}",
@@ -433,31 +583,128 @@ impl BrowserManager {
         }
 
         // If all attempts failed, return an error with helpful guidance
-        Err(TarziError::Browser(
+        Err(TarziError::Driver(
             "No self-managed WebDriver could be started. Please either:\n\
             1. Install ChromeDriver (https://chromedriver.chromium.org/) or GeckoDriver (https://github.com/mozilla/geckodriver/releases) and ensure they're in your PATH, or\n\
-            2. Configure web_driver_url in your tarzi.toml file to use an external WebDriver server".to_string()
+            2. Configure web_driver_url in your tarzi.toml file to use an external WebDriver server, or\n\
+            3. Set auto_download_driver = true in the [fetcher] section to download a driver automatically (needs the driver-download feature)".to_string()
         ))
     }
 
+    /// Endpoint of an already running self-managed driver, at the default port of
+    /// the configured driver type (GeckoDriver when unknown or unconfigured)
+    fn default_driver_url(&self) -> String {
+        let web_driver = self.config.as_ref().map(|c| c.fetcher.web_driver.as_str());
+        match web_driver {
+            Some("chromedriver" | "chrome") => {
+                format!("http://localhost:{CHROMEDRIVER_DEFAULT_PORT}")
+            }
+            _ => format!("http://localhost:{GECKODRIVER_DEFAULT_PORT}"),
+        }
+    }
+
+    /// WebDriver endpoint browser sessions connect to: `fetcher.web_driver_url` if
+    /// set, else the driver started by this manager, else a persistent driver
+    /// ([`PersistentDriver`]), else the default driver port
+    pub fn driver_endpoint(&self) -> String {
+        if let Some(url) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.fetcher.web_driver_url.as_ref())
+            .filter(|url| !url.is_empty())
+        {
+            return url.clone();
+        }
+        match (&self.managed_driver_info, PersistentDriver::load()) {
+            (Some(info), _) => info.endpoint.clone(),
+            (None, Some(driver)) => driver.endpoint,
+            (None, None) => self.default_driver_url(),
+        }
+    }
+
+    /// Current status of the driver started by this manager, or `None` if it has
+    /// not started one (e.g. an external or already running driver is used)
+    pub fn driver_status(&self) -> Option<DriverStatus> {
+        let info = self.managed_driver_info.as_ref()?;
+        Some(
+            self.driver_manager
+                .as_ref()
+                .and_then(|manager| manager.get_driver_info(info.port))
+                .map_or(DriverStatus::Stopped, |info| info.status),
+        )
+    }
+
+    /// Whether a WebDriver server answers at [`Self::driver_endpoint`]
+    pub async fn is_driver_reachable(&self) -> bool {
+        is_webdriver_available_at_url(&self.driver_endpoint()).await
+    }
+
+    /// Self-managed driver types in the order they should be tried: the configured
+    /// driver first, then the fallback. A driver that died mid-run is tried last.
+    fn driver_order(&self) -> [DriverType; 2] {
+        let order = match self.config.as_ref().map(|c| c.fetcher.web_driver.as_str()) {
+            Some("geckodriver" | "firefox") => [DriverType::Firefox, DriverType::Chrome],
+            _ => [DriverType::Chrome, DriverType::Firefox],
+        };
+        match &self.failed_driver {
+            Some(failed) if *failed == order[0] => [order[1].clone(), order[0].clone()],
+            _ => order,
+        }
+    }
+
+    /// Recover from a dead browser session: discard all sessions and, if the
+    /// self-managed driver no longer responds, stop it so the next session is
+    /// created on a restarted driver (preferring the fallback driver type).
+    pub async fn recover(&mut self) {
+        let browser_ids: Vec<String> = self.browsers.keys().cloned().collect();
+        for instance_id in browser_ids {
+            if let Some((driver, temp_dir)) = self.browsers.remove(&instance_id) {
+                info!(
+                    "Discarding browser instance after session failure: {}",
+                    instance_id
+                );
+                if let Err(e) = quit_with_timeout(driver).await {
+                    warn!("Failed to quit browser instance {}: {}", instance_id, e);
+                }
+                cleanup_temp_dir(&instance_id, temp_dir);
+            }
+        }
+
+        let Some(driver_info) = self.managed_driver_info.clone() else {
+            return;
+        };
+        if is_webdriver_available_at_url(&driver_info.endpoint).await {
+            return;
+        }
+        warn!(
+            "Self-managed {:?} at {} is not responding; it will be replaced",
+            driver_info.config.driver_type, driver_info.endpoint
+        );
+        if let Some(driver_manager) = &self.driver_manager
+            && let Err(e) = driver_manager.stop_driver(driver_info.port)
+        {
+            warn!("Failed to stop unresponsive driver: {}", e);
+        }
+        self.failed_driver = Some(driver_info.config.driver_type);
+        self.managed_driver_info = None;
+    }
+
     /// Try to start a driver of the given type
     fn try_start_driver(
         &self,
         driver_manager: &DriverManager,
         driver_type: &DriverType,
     ) -> Result<DriverInfo> {
-        // Check if driver binary exists
-        driver_manager.check_driver_binary(driver_type)?;
-
-        let (port, args) = match driver_type {
-            DriverType::Chrome => (CHROMEDRIVER_DEFAULT_PORT, CHROME_DRIVER_ARGS),
-            DriverType::Firefox => (GECKODRIVER_DEFAULT_PORT, FIREFOX_DRIVER_ARGS),
-            _ => (GECKODRIVER_DEFAULT_PORT, FIREFOX_DRIVER_ARGS),
+        let args = match driver_type {
+            DriverType::Chrome => CHROME_DRIVER_ARGS,
+            _ => FIREFOX_DRIVER_ARGS,
         };
 
+        // A free port rather than the default one, which another process's
+        // driver may already hold
         let config = DriverConfig {
             driver_type: driver_type.clone(),
-            port,
+            port: None,
             args: args.iter().map(|s| s.to_string()).collect(),
             timeout: DEFAULT_TIMEOUT,
             verbose: false,
@@ -471,13 +718,13 @@ impl BrowserManager {
         if let (Some(driver_manager), Some(driver_info)) =
             (&mut self.driver_manager, &self.managed_driver_info)
         {
-            match driver_manager.stop_driver(driver_info.config.port) {
+            match driver_manager.stop_driver(driver_info.port) {
                 Ok(()) => {
                     self.managed_driver_info = None;
                 }
                 Err(e) => {
                     warn!("Failed to stop managed driver: {}", e);
-                    return Err(TarziError::Browser(format!(
+                    return Err(TarziError::Driver(format!(
                         "Failed to stop managed driver: {e}"
                     )));
                 }
@@ -491,7 +738,7 @@ impl BrowserManager {
         if let (Some(driver_manager), Some(driver_info)) =
             (&mut self.driver_manager, &self.managed_driver_info)
         {
-            match driver_manager.stop_driver(driver_info.config.port) {
+            match driver_manager.stop_driver(driver_info.port) {
                 Ok(()) => {
                     self.managed_driver_info = None;
                 }
@@ -505,7 +752,9 @@ impl BrowserManager {
     /// Clear all browser instances (for Drop paths)
     /// This should be called after stop_managed_driver_sync() to ensure proper cleanup
     pub fn clear_browsers(&mut self) {
-        self.browsers.clear();
+        for (instance_id, (_driver, temp_dir)) in self.browsers.drain() {
+            cleanup_temp_dir(&instance_id, temp_dir);
+        }
     }
 
     /// Check if this browser manager has a managed driver
@@ -552,11 +801,12 @@ impl BrowserManager {
         // Clean up all browser instances
         let browser_ids: Vec<String> = self.browsers.keys().cloned().collect();
         for instance_id in browser_ids {
-            if let Some((driver, _temp_dir)) = self.browsers.remove(&instance_id) {
+            if let Some((driver, temp_dir)) = self.browsers.remove(&instance_id) {
                 info!("Shutting down browser instance: {}", instance_id);
-                if let Err(e) = driver.quit().await {
+                if let Err(e) = quit_with_timeout(driver).await {
                     error!("Failed to quit browser instance {}: {}", instance_id, e);
                 }
+                cleanup_temp_dir(&instance_id, temp_dir);
             }
         }
         // Clean up managed driver
@@ -564,7 +814,7 @@ impl BrowserManager {
             (&mut self.driver_manager, &self.managed_driver_info)
         {
             info!("Shutting down managed driver: {}", driver_info.endpoint);
-            if let Err(e) = driver_manager.stop_driver(driver_info.config.port) {
+            if let Err(e) = driver_manager.stop_driver(driver_info.port) {
                 error!("Failed to stop managed driver: {}", e);
             }
             self.managed_driver_info = None;
@@ -586,6 +836,140 @@ impl Drop for BrowserManager {
     }
 }
 
+/// Build the WebDriver timeout configuration from the fetcher config, if any timeout is set.
+/// Unset timeouts are left at the driver's defaults.
+fn driver_timeouts(config: &Config) -> Option<TimeoutConfiguration> {
+    let script = config.fetcher.script_timeout.map(Duration::from_secs);
+    let page_load = config.fetcher.page_load_timeout.map(Duration::from_secs);
+    if script.is_none() && page_load.is_none() {
+        return None;
+    }
+    Some(TimeoutConfiguration::new(script, page_load, None))
+}
+
+/// Host and port of an external browser's debugging endpoint, given as `host:port`
+/// or a DevTools URL such as `ws://127.0.0.1:9222/devtools/browser/<id>`
+fn debugger_socket(endpoint: &str) -> Option<(String, u16)> {
+    let endpoint = endpoint.trim();
+    if endpoint.contains("://") {
+        let url = url::Url::parse(endpoint).ok()?;
+        if !matches!(url.scheme(), "ws" | "wss" | "http" | "https") {
+            return None;
+        }
+        Some((url.host_str()?.to_string(), url.port_or_known_default()?))
+    } else {
+        let (host, port) = endpoint.rsplit_once(':')?;
+        let port = port.trim_end_matches('/').parse().ok()?;
+        (!host.is_empty()).then(|| (host.to_string(), port))
+    }
+}
+
+/// The `host:port` chromedriver's `debuggerAddress` expects
+fn debugger_address(endpoint: &str) -> String {
+    match debugger_socket(endpoint) {
+        Some((host, port)) => format!("{host}:{port}"),
+        None => endpoint.trim().to_string(),
+    }
+}
+
+/// Check that an external browser's debugging endpoint is well-formed and accepts
+/// connections within [`EXTERNAL_BROWSER_CHECK_TIMEOUT`]
+pub async fn check_external_browser_prerequisites(endpoint: &str) -> bool {
+    let Some((host, port)) = debugger_socket(endpoint) else {
+        warn!("Invalid external browser endpoint: {}", endpoint);
+        return false;
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    match tokio::time::timeout(
+        EXTERNAL_BROWSER_CHECK_TIMEOUT,
+        tokio::net::TcpStream::connect((host, port)),
+    )
+    .await
+    {
+        Ok(Ok(_)) => true,
+        Ok(Err(e)) => {
+            warn!(
+                "External browser at {}:{} is not reachable: {}",
+                host, port, e
+            );
+            false
+        }
+        Err(_) => {
+            warn!(
+                "Timed out connecting to external browser at {}:{}",
+                host, port
+            );
+            false
+        }
+    }
+}
+
+/// Remove a browser instance's temporary data directory, logging any failure
+fn cleanup_temp_dir(instance_id: &str, temp_dir: Option<TempDir>) {
+    if let Some(temp_dir) = temp_dir {
+        let path = temp_dir.path().to_path_buf();
+        if let Err(e) = temp_dir.close() {
+            warn!(
+                "Failed to remove temporary directory {:?} for browser instance {}: {}",
+                path, instance_id, e
+            );
+        }
+    }
+}
+
+/// Quit a WebDriver session, giving up if the driver does not respond in time
+async fn quit_with_timeout(driver: WebDriver) -> Result<()> {
+    match tokio::time::timeout(BROWSER_QUIT_TIMEOUT, driver.quit()).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(TarziError::Browser(format!("Failed to quit browser: {e}"))),
+        Err(_) => Err(TarziError::Timeout(format!(
+            "WebDriver session did not quit within {} seconds",
+            BROWSER_QUIT_TIMEOUT.as_secs()
+        ))),
+    }
+}
+
+/// Error messages indicating the WebDriver session or driver itself is gone, as
+/// opposed to a failure of the page being fetched
+const SESSION_ERROR_PATTERNS: &[&str] = &[
+    "invalid session id",
+    "no such session",
+    "session deleted",
+    "session not created",
+    "no such window",
+    "chrome not reachable",
+    "browsing context has been discarded",
+    "disconnected",
+    "connection refused",
+    "error sending request",
+];
+
+/// Check whether an error means the browser session or driver died and the
+/// operation may succeed on a fresh session
+pub fn is_session_error(error: &TarziError) -> bool {
+    match error {
+        TarziError::Browser(message) => {
+            let message = message.to_lowercase();
+            SESSION_ERROR_PATTERNS
+                .iter()
+                .any(|pattern| message.contains(pattern))
+        }
+        _ => false,
+    }
+}
+
+/// Check whether an error means the page's renderer crashed while the session
+/// itself survived, so the fetch may succeed on a new page
+pub fn is_page_crash_error(error: &TarziError) -> bool {
+    match error {
+        TarziError::Browser(message) => {
+            let message = message.to_lowercase();
+            message.contains("tab crashed") || message.contains("target crashed")
+        }
+        _ => false,
+    }
+}
+
 /// Helper function to check if webdriver is available at a specific URL
 async fn is_webdriver_available_at_url(url: &str) -> bool {
     use reqwest;
@@ -680,6 +1064,25 @@ mod tests {
         // Test initial state
         assert!(!manager.has_managed_driver());
         assert!(manager.get_managed_driver_info().is_none());
+        assert_eq!(manager.driver_status(), None);
+    }
+
+    /// Test the endpoint reported for external, configured and default drivers
+    #[test]
+    fn test_driver_endpoint() {
+        let mut config = Config::new();
+        config.fetcher.web_driver = "chromedriver".to_string();
+        let manager = BrowserManager::from_config(&config);
+        assert_eq!(manager.driver_endpoint(), "http://localhost:9515");
+
+        config.fetcher.web_driver = "geckodriver".to_string();
+        let manager = BrowserManager::from_config(&config);
+        assert_eq!(manager.driver_endpoint(), "http://localhost:4444");
+
+        config.fetcher.web_driver_url = Some("http://remote:4444".to_string());
+        let manager = BrowserManager::from_config(&config);
+        assert_eq!(manager.driver_endpoint(), "http://remote:4444");
+        assert_eq!(manager.driver_status(), None);
     }
 
     /// Test driver type logic in get_or_create_webdriver_endpoint
@@ -796,6 +1199,120 @@ mod tests {
     }
 
     /// Test external WebDriver URL detection
+    #[test]
+    fn test_debugger_address() {
+        assert_eq!(debugger_address("127.0.0.1:9222"), "127.0.0.1:9222");
+        assert_eq!(
+            debugger_address("ws://127.0.0.1:9222/devtools/browser/2c6e1b6a-5d1f"),
+            "127.0.0.1:9222"
+        );
+        assert_eq!(
+            debugger_address(" http://localhost:9333/ "),
+            "localhost:9333"
+        );
+        assert_eq!(debugger_address("localhost"), "localhost");
+    }
+
+    /// Test the reachability probe for external browser endpoints
+    #[tokio::test]
+    async fn test_check_external_browser_prerequisites() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open = listener.local_addr().unwrap();
+        assert!(check_external_browser_prerequisites(&open.to_string()).await);
+        assert!(
+            check_external_browser_prerequisites(&format!("ws://{open}/devtools/browser/abc"))
+                .await
+        );
+
+        // Well-formed, but nothing listens on the port any more
+        let closed = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap()
+        };
+        assert!(!check_external_browser_prerequisites(&closed.to_string()).await);
+        assert!(!check_external_browser_prerequisites(&format!("ws://{closed}/devtools")).await);
+
+        // Malformed endpoints fail before any connection attempt
+        assert!(!check_external_browser_prerequisites("localhost").await);
+        assert!(!check_external_browser_prerequisites("ftp://127.0.0.1:9222").await);
+        assert!(!check_external_browser_prerequisites("127.0.0.1:notaport").await);
+    }
+
+    /// Test that an unreachable WebDriver server fails as a driver error, so the
+    /// CLI exits with the driver exit code
+    #[tokio::test]
+    async fn test_unavailable_webdriver_is_driver_error() {
+        let closed = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap()
+        };
+        let mut config = Config::new();
+        config.fetcher.web_driver_url = Some(format!("http://{closed}"));
+        let mut manager = BrowserManager::from_config(&config);
+
+        let err = manager.get_or_create_browser(true).await.unwrap_err();
+        assert!(matches!(err, TarziError::Driver(_)), "{err:?}");
+        assert_eq!(err.exit_code(), crate::constants::EXIT_CODE_DRIVER);
+        assert!(!manager.has_browsers());
+    }
+
+    /// Test that a browser that can't be attached to reports its endpoint
+    #[tokio::test]
+    async fn test_connect_external_browser_failure() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // A WebDriver server that is ready but can't reach the debugger address
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap();
+                let (status, body) = if buf[..n].starts_with(b"GET /status") {
+                    ("200 OK", r#"{"value":{"ready":true,"message":""}}"#)
+                } else {
+                    (
+                        "500 Internal Server Error",
+                        r#"{"value":{"error":"session not created","message":"cannot connect to chrome","stacktrace":""}}"#,
+                    )
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        // The debugging port accepts connections, so the reachability check passes
+        let debugger = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!(
+            "ws://{}/devtools/browser/abc",
+            debugger.local_addr().unwrap()
+        );
+
+        let mut config = Config::new();
+        config.fetcher.web_driver_url = Some(format!("http://{addr}"));
+        config.fetcher.browser_debugger_address = Some(endpoint.clone());
+        let mut manager = BrowserManager::from_config(&config);
+
+        let err = manager.get_or_create_browser(true).await.unwrap_err();
+        assert!(matches!(err, TarziError::Browser(_)));
+        assert!(
+            err.to_string()
+                .contains("Failed to connect to external browser")
+        );
+        assert!(err.to_string().contains(&endpoint));
+        assert!(!manager.has_browsers());
+
+        // A closed debugging port fails before a session is requested
+        drop(debugger);
+        let err = manager.get_or_create_browser(true).await.unwrap_err();
+        assert!(err.to_string().contains("is not reachable"));
+        assert!(err.to_string().contains(&endpoint));
+    }
+
     #[test]
     fn test_external_webdriver_url_detection() {
         // Test with external URL
@@ -870,4 +1387,105 @@ mod tests {
         let ids = manager.get_browser_ids();
         assert_eq!(ids.len(), 0);
     }
+
+    /// Test that a driver which died mid-run is tried after the fallback
+    #[test]
+    fn test_driver_order_after_failure() {
+        let mut config = Config::default();
+        config.fetcher.web_driver = "geckodriver".to_string();
+        let mut manager = BrowserManager::from_config(&config);
+        assert_eq!(
+            manager.driver_order(),
+            [DriverType::Firefox, DriverType::Chrome]
+        );
+
+        manager.failed_driver = Some(DriverType::Firefox);
+        assert_eq!(
+            manager.driver_order(),
+            [DriverType::Chrome, DriverType::Firefox]
+        );
+
+        // A failed fallback does not change the order
+        manager.failed_driver = Some(DriverType::Chrome);
+        assert_eq!(
+            manager.driver_order(),
+            [DriverType::Firefox, DriverType::Chrome]
+        );
+    }
+
+    /// Test classification of session/driver errors
+    #[test]
+    fn test_is_session_error() {
+        assert!(is_session_error(&TarziError::Browser(
+            "Failed to navigate to https://example.com: invalid session id".to_string()
+        )));
+        assert!(is_session_error(&TarziError::Browser(
+            "Failed to get content: Connection refused (os error 111)".to_string()
+        )));
+        assert!(!is_session_error(&TarziError::Browser(
+            "Network error while navigating: nssFailure".to_string()
+        )));
+        assert!(!is_session_error(&TarziError::Timeout(
+            "no such session".to_string()
+        )));
+    }
+
+    /// Test detecting a crashed page as distinct from a dead session
+    #[test]
+    fn test_is_page_crash_error() {
+        let crash = TarziError::Browser(
+            "Failed to navigate to https://example.com: unknown error: session deleted because of page crash\nfrom tab crashed".to_string(),
+        );
+        assert!(is_page_crash_error(&crash));
+        assert!(!is_page_crash_error(&TarziError::Browser(
+            "Failed to navigate to https://example.com: invalid session id".to_string()
+        )));
+    }
+
+    /// Test that page reuse follows the fetcher config and defaults to on
+    #[test]
+    fn test_reuse_page() {
+        assert!(BrowserManager::new().reuse_page());
+
+        let mut config = Config::default();
+        config.fetcher.reuse_page = false;
+        assert!(!BrowserManager::from_config(&config).reuse_page());
+        assert!(!BrowserManager::from_config(&config).reuse_browser());
+
+        // Reusing the browser navigates its one page
+        config.fetcher.reuse_browser = true;
+        assert!(BrowserManager::from_config(&config).reuse_page());
+        assert!(BrowserManager::from_config(&config).reuse_browser());
+    }
+
+    /// Test WebDriver timeout configuration derived from the fetcher config
+    #[test]
+    fn test_driver_timeouts() {
+        let mut config = Config::default();
+        assert!(driver_timeouts(&config).is_none());
+
+        config.fetcher.page_load_timeout = Some(120);
+        let timeouts = driver_timeouts(&config).unwrap();
+        assert_eq!(timeouts.page_load(), Some(Duration::from_secs(120)));
+        assert_eq!(timeouts.script(), None);
+        assert_eq!(timeouts.implicit(), None);
+
+        config.fetcher.script_timeout = Some(90);
+        let timeouts = driver_timeouts(&config).unwrap();
+        assert_eq!(timeouts.script(), Some(Duration::from_secs(90)));
+    }
+
+    /// Test that temporary browser data directories are removed on cleanup
+    #[test]
+    fn test_cleanup_temp_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().to_path_buf();
+        assert!(path.exists());
+
+        cleanup_temp_dir("browser_test", Some(temp_dir));
+        assert!(!path.exists());
+
+        // Instances using a caller-provided user data dir have nothing to clean up
+        cleanup_temp_dir("browser_test", None);
+    }
 }
@@ -0,0 +1,201 @@
+//! In-memory cache of fetched content that can be shared between fetchers
+//!
+//! A [`FetchCache`] is a cheap handle around shared state: clones refer to the
+//! same entries, so every `WebFetcher` or `SearchEngine` given a clone of one
+//! cache (e.g. all request handlers of a server) reads and fills the same cache.
+
+use super::types::{FetchMode, FetchRequest};
+use crate::constants::{DEFAULT_FETCH_CACHE_CAPACITY, DEFAULT_FETCH_CACHE_TTL};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Identity of a cacheable request: everything besides the URL that changes
+/// what a fetch returns
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    mode: FetchMode,
+    proxy: Option<String>,
+    wait_for: Option<String>,
+    script: Option<String>,
+    url: String,
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    content: HashMap<CacheKey, (String, Instant)>,
+    /// Keys in insertion order, oldest first, for eviction
+    order: VecDeque<CacheKey>,
+}
+
+/// Thread-safe cache of raw fetched content keyed by request, evicting the
+/// oldest entries beyond its capacity and entries older than its TTL
+#[derive(Debug, Clone)]
+pub struct FetchCache {
+    entries: Arc<Mutex<CacheEntries>>,
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl FetchCache {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_FETCH_CACHE_CAPACITY)
+    }
+
+    /// Create a cache holding at most `capacity` pages
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(CacheEntries::default())),
+            capacity,
+            ttl: DEFAULT_FETCH_CACHE_TTL,
+        }
+    }
+
+    /// Serve cached pages for at most `ttl` after they were fetched
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Cache key for a request, or `None` for requests that must not be cached
+    /// because custom headers may change the response. Fetchers don't cache at
+    /// all when they send credentials, cookies or headers of their own.
+    pub fn key(request: &FetchRequest) -> Option<CacheKey> {
+        if !request.headers.is_empty() {
+            return None;
+        }
+        Some(CacheKey {
+            mode: request.mode,
+            proxy: request.proxy.clone(),
+            wait_for: request.wait_for.clone(),
+            script: request.script.clone(),
+            url: request.url.clone(),
+        })
+    }
+
+    /// Cached content for `key`, unless it is missing or older than the TTL
+    pub fn get(&self, key: &CacheKey) -> Option<String> {
+        let mut entries = self.lock();
+        match entries.content.get(key) {
+            Some((content, inserted)) if inserted.elapsed() < self.ttl => Some(content.clone()),
+            Some(_) => {
+                entries.content.remove(key);
+                entries.order.retain(|k| k != key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: CacheKey, content: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.lock();
+        if entries
+            .content
+            .insert(key.clone(), (content, Instant::now()))
+            .is_none()
+        {
+            entries.order.push_back(key);
+        }
+        while entries.order.len() > self.capacity {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.content.remove(&oldest);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().content.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.lock();
+        entries.content.clear();
+        entries.order.clear();
+    }
+
+    /// A panic while holding the lock cannot leave the entries inconsistent in a
+    /// harmful way, so a poisoned lock is simply recovered
+    fn lock(&self) -> MutexGuard<'_, CacheEntries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for FetchCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(url: &str) -> CacheKey {
+        FetchCache::key(&FetchRequest::new(url)).unwrap()
+    }
+
+    #[test]
+    fn test_clones_share_entries() {
+        let cache = FetchCache::new();
+        let shared = cache.clone();
+        cache.insert(key("https://a.example"), "content".to_string());
+
+        assert_eq!(
+            shared.get(&key("https://a.example")).as_deref(),
+            Some("content")
+        );
+        assert_eq!(shared.len(), 1);
+
+        shared.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_evicts_oldest_entries() {
+        let (a, b, c) = (
+            key("https://a.example"),
+            key("https://b.example"),
+            key("https://c.example"),
+        );
+        let cache = FetchCache::with_capacity(2);
+        cache.insert(a.clone(), "1".to_string());
+        cache.insert(b.clone(), "2".to_string());
+        cache.insert(a.clone(), "3".to_string());
+        cache.insert(c.clone(), "4".to_string());
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&a), None);
+        assert_eq!(cache.get(&b).as_deref(), Some("2"));
+        assert_eq!(cache.get(&c).as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn test_expires_entries_after_ttl() {
+        let cache = FetchCache::new().with_ttl(Duration::ZERO);
+        cache.insert(key("https://a.example"), "1".to_string());
+        assert_eq!(cache.get(&key("https://a.example")), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_request_keys() {
+        let plain = FetchRequest::new("https://example.com").mode(FetchMode::PlainRequest);
+        let browser = FetchRequest::new("https://example.com");
+        assert_ne!(FetchCache::key(&plain), FetchCache::key(&browser));
+
+        // Fields are kept apart, so values can't run into each other
+        let spaced = FetchRequest::new("b").script("a");
+        let joined = FetchRequest::new("a b");
+        assert_ne!(FetchCache::key(&spaced), FetchCache::key(&joined));
+
+        let with_headers = plain.header("Accept", "text/html");
+        assert_eq!(FetchCache::key(&with_headers), None);
+    }
+}
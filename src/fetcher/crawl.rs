@@ -0,0 +1,524 @@
+//! Breadth-first site crawling
+//!
+//! The crawler starts from one URL and follows the links of each fetched page up
+//! to a maximum depth, optionally staying on the start page's domain. It honours
+//! robots.txt (including `Crawl-delay`), pauses between requests and stops after a
+//! maximum number of pages. Converted pages can be written to an output directory
+//! together with a manifest recording the link graph.
+
+use super::types::{FetchMode, FetchResponse};
+use super::webfetcher::WebFetcher;
+use crate::Result;
+use crate::constants::{
+    CRAWL_MANIFEST_FILE, DEFAULT_CRAWL_DELAY, DEFAULT_CRAWL_DEPTH, DEFAULT_CRAWL_MAX_PAGES,
+};
+use crate::converter::{ContentKind, Converter, Format};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use url::Url;
+
+/// User agent token matched against robots.txt groups
+const ROBOTS_USER_AGENT: &str = "tarzi";
+
+/// Maximum length of the URL-derived part of a crawled page's file name
+const MAX_FILE_SLUG_CHARS: usize = 60;
+
+/// Settings for a crawl
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrawlOptions {
+    /// Link depth to follow; 0 fetches only the start page
+    pub max_depth: usize,
+    /// Stop after this many pages have been fetched
+    pub max_pages: usize,
+    /// Only follow links on the start page's host (a leading `www.` is ignored)
+    pub same_domain: bool,
+    /// Skip URLs disallowed by robots.txt and honour its `Crawl-delay`
+    pub respect_robots: bool,
+    /// Minimum pause between requests
+    pub delay: Duration,
+    /// How pages are fetched
+    pub mode: FetchMode,
+    /// Format pages are converted to when written to the output directory
+    pub format: Format,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_CRAWL_DEPTH,
+            max_pages: DEFAULT_CRAWL_MAX_PAGES,
+            same_domain: false,
+            respect_robots: true,
+            delay: DEFAULT_CRAWL_DELAY,
+            mode: FetchMode::PlainRequest,
+            format: Format::Markdown,
+        }
+    }
+}
+
+/// A page visited during a crawl
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrawledPage {
+    pub url: String,
+    /// Links followed from the start page to reach this one
+    pub depth: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// File the converted page was written to, relative to the output directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Absolute links found on the page, whether or not they were followed
+    pub links: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of a crawl, written as `manifest.json` to the output directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrawlManifest {
+    pub start_url: String,
+    pub pages: Vec<CrawledPage>,
+}
+
+/// Rules from a robots.txt file that apply to tarzi. Paths are matched as
+/// prefixes; `*` and `$` patterns are not interpreted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RobotsRules {
+    allow: Vec<String>,
+    disallow: Vec<String>,
+    pub crawl_delay: Option<Duration>,
+}
+
+impl RobotsRules {
+    /// Parse robots.txt, using the group naming `user_agent` or else the `*` group
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let user_agent = user_agent.to_lowercase();
+        let mut specific = None;
+        let mut wildcard = None;
+        let mut agents: Vec<String> = Vec::new();
+        let mut rules = RobotsRules::default();
+        let mut in_rules = false;
+
+        let mut finish_group = |agents: &mut Vec<String>, rules: &mut RobotsRules| {
+            for agent in agents.drain(..) {
+                if agent == "*" {
+                    wildcard.get_or_insert_with(|| rules.clone());
+                } else if user_agent.contains(&agent) {
+                    specific.get_or_insert_with(|| rules.clone());
+                }
+            }
+            *rules = RobotsRules::default();
+        };
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim().to_lowercase().as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        finish_group(&mut agents, &mut rules);
+                        in_rules = false;
+                    }
+                    agents.push(value.to_lowercase());
+                }
+                "allow" => {
+                    in_rules = true;
+                    if !value.is_empty() {
+                        rules.allow.push(value.to_string());
+                    }
+                }
+                "disallow" => {
+                    in_rules = true;
+                    if !value.is_empty() {
+                        rules.disallow.push(value.to_string());
+                    }
+                }
+                "crawl-delay" => {
+                    in_rules = true;
+                    rules.crawl_delay = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|secs| secs.is_finite() && *secs >= 0.0)
+                        .map(Duration::from_secs_f64);
+                }
+                _ => {}
+            }
+        }
+        finish_group(&mut agents, &mut rules);
+
+        specific.or(wildcard).unwrap_or_default()
+    }
+
+    /// Whether `path` (with its query) may be fetched. The longest matching rule
+    /// wins; `Allow` wins a tie.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        let longest = |rules: &[String]| {
+            rules
+                .iter()
+                .filter(|rule| path.starts_with(rule.as_str()))
+                .map(String::len)
+                .max()
+        };
+        match (longest(&self.allow), longest(&self.disallow)) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(allow), Some(disallow)) => allow >= disallow,
+        }
+    }
+}
+
+/// Breadth-first crawler built on a [`WebFetcher`] and a [`Converter`]
+#[derive(Debug)]
+pub struct Crawler {
+    fetcher: WebFetcher,
+    converter: Converter,
+    options: CrawlOptions,
+    robots: HashMap<String, RobotsRules>,
+}
+
+impl Crawler {
+    pub fn new(fetcher: WebFetcher, options: CrawlOptions) -> Self {
+        Self {
+            fetcher,
+            converter: Converter::new(),
+            options,
+            robots: HashMap::new(),
+        }
+    }
+
+    /// Convert pages with `converter` instead of the default one
+    pub fn with_converter(mut self, converter: Converter) -> Self {
+        self.converter = converter;
+        self
+    }
+
+    pub fn options(&self) -> &CrawlOptions {
+        &self.options
+    }
+
+    /// Crawl from `start_url`. With `output_dir`, each fetched page is converted
+    /// and written there, followed by the manifest. Pages that fail to fetch or
+    /// convert are recorded with their error and do not stop the crawl.
+    pub async fn crawl(
+        &mut self,
+        start_url: &str,
+        output_dir: Option<&Path>,
+    ) -> Result<CrawlManifest> {
+        let start = Url::parse(start_url)?;
+        if let Some(dir) = output_dir {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut queue = VecDeque::from([(start.to_string(), 0)]);
+        let mut seen = HashSet::from([start.to_string()]);
+        let mut pages = Vec::new();
+        let mut last_request: Option<Instant> = None;
+
+        while let Some((url, depth)) = queue.pop_front() {
+            if pages.len() >= self.options.max_pages {
+                info!("Crawl stopped after {} pages", pages.len());
+                break;
+            }
+            let parsed = Url::parse(&url)?;
+            let mut delay = self.options.delay;
+            if self.options.respect_robots {
+                let rules = self.robots_rules(&parsed).await;
+                if !rules.is_allowed(&path_and_query(&parsed)) {
+                    info!("Skipping {} (disallowed by robots.txt)", url);
+                    continue;
+                }
+                delay = delay.max(rules.crawl_delay.unwrap_or_default());
+            }
+            if let Some(elapsed) = last_request.map(|last| last.elapsed())
+                && elapsed < delay
+            {
+                tokio::time::sleep(delay - elapsed).await;
+            }
+            last_request = Some(Instant::now());
+
+            info!("Crawling {} (depth {})", url, depth);
+            let mut page = CrawledPage {
+                url,
+                depth,
+                status: None,
+                file: None,
+                links: Vec::new(),
+                error: None,
+            };
+            match self
+                .fetcher
+                .fetch_response(&page.url, self.options.mode)
+                .await
+            {
+                Ok(response) => {
+                    page.status = Some(response.status);
+                    let content_type = response.content_type.as_deref();
+                    if ContentKind::detect(content_type, &response.body) == ContentKind::Html {
+                        page.links = self.converter.links(&response.body, &response.url);
+                    }
+                    if let Some(dir) = output_dir {
+                        let file = page_file_name(pages.len(), &parsed, self.options.format);
+                        match self.save_page(&response, &dir.join(&file)).await {
+                            Ok(()) => page.file = Some(file),
+                            Err(e) => page.error = Some(e.to_string()),
+                        }
+                    }
+                    if depth < self.options.max_depth {
+                        for link in &page.links {
+                            if self.in_scope(&start, link) && seen.insert(link.clone()) {
+                                queue.push_back((link.clone(), depth + 1));
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to crawl {}: {}", page.url, e);
+                    page.error = Some(e.to_string());
+                }
+            }
+            pages.push(page);
+        }
+
+        let manifest = CrawlManifest {
+            start_url: start.to_string(),
+            pages,
+        };
+        if let Some(dir) = output_dir {
+            let json = serde_json::to_string_pretty(&manifest)?;
+            std::fs::write(dir.join(CRAWL_MANIFEST_FILE), json)?;
+        }
+        Ok(manifest)
+    }
+
+    /// Clean up browser and driver resources used for the crawl
+    pub async fn shutdown(&mut self) {
+        self.fetcher.shutdown().await;
+    }
+
+    async fn save_page(&self, response: &FetchResponse, path: &Path) -> Result<()> {
+        let output = self
+            .converter
+            .convert_response(
+                &response.body,
+                response.content_type.as_deref(),
+                self.options.format,
+                &response.url,
+            )
+            .await?;
+        std::fs::write(path, output)?;
+        Ok(())
+    }
+
+    /// Whether `link` should be followed from a crawl that started at `start`
+    fn in_scope(&self, start: &Url, link: &str) -> bool {
+        if !self.options.same_domain {
+            return true;
+        }
+        let host = |url: &Url| {
+            url.host_str()
+                .map(|host| host.trim_start_matches("www.").to_lowercase())
+        };
+        Url::parse(link).is_ok_and(|link| host(&link) == host(start))
+    }
+
+    /// robots.txt rules for the origin of `url`, fetched once per origin. A missing
+    /// or unreadable robots.txt allows everything.
+    async fn robots_rules(&mut self, url: &Url) -> RobotsRules {
+        let origin = url.origin().ascii_serialization();
+        if let Some(rules) = self.robots.get(&origin) {
+            return rules.clone();
+        }
+
+        let robots_url = format!("{origin}/robots.txt");
+        let rules = match self.fetcher.http_client().get(&robots_url).send().await {
+            Ok(response) if response.status().is_success() => match response.text().await {
+                Ok(text) => RobotsRules::parse(&text, ROBOTS_USER_AGENT),
+                Err(_) => RobotsRules::default(),
+            },
+            _ => RobotsRules::default(),
+        };
+        self.robots.insert(origin, rules.clone());
+        rules
+    }
+}
+
+fn path_and_query(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    }
+}
+
+/// File name for the `index`-th crawled page, e.g. `003-example-com-docs.md`
+fn page_file_name(index: usize, url: &Url, format: Format) -> String {
+    let raw = format!("{}{}", url.host_str().unwrap_or_default(), url.path());
+    let mut slug = String::new();
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug: String = slug
+        .trim_matches('-')
+        .chars()
+        .take(MAX_FILE_SLUG_CHARS)
+        .collect();
+    let slug = if slug.is_empty() {
+        "page"
+    } else {
+        slug.trim_end_matches('-')
+    };
+    format!("{index:03}-{slug}.{}", format.extension())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serve `routes` (path, content type, body) over HTTP until the test ends;
+    /// unknown paths get a 404
+    async fn serve_site(routes: &'static [(&'static str, &'static str, &'static str)]) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 2048];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+                let response = match routes.iter().find(|(route, ..)| *route == path) {
+                    Some((_, content_type, body)) => format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\n\
+                         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    ),
+                    None => {
+                        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                            .to_string()
+                    }
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn test_robots_rules() {
+        let robots = "# comment\n\
+            User-agent: *\n\
+            Disallow: /private\n\
+            Allow: /private/open\n\
+            Crawl-delay: 2\n\
+            \n\
+            User-agent: otherbot\n\
+            User-agent: tarzi\n\
+            Disallow: /\n";
+
+        let rules = RobotsRules::parse(robots, "tarzi/0.1");
+        assert!(!rules.is_allowed("/anything"));
+
+        let rules = RobotsRules::parse(robots, "somebot");
+        assert!(rules.is_allowed("/docs"));
+        assert!(!rules.is_allowed("/private/notes"));
+        assert!(rules.is_allowed("/private/open/page"));
+        assert_eq!(rules.crawl_delay, Some(Duration::from_secs(2)));
+
+        // An empty Disallow allows everything
+        let rules = RobotsRules::parse("User-agent: *\nDisallow:\n", "tarzi");
+        assert!(rules.is_allowed("/"));
+    }
+
+    #[test]
+    fn test_page_file_name() {
+        let url = Url::parse("https://www.example.com/docs/Intro.html?x=1").unwrap();
+        assert_eq!(
+            page_file_name(3, &url, Format::Markdown),
+            "003-www-example-com-docs-intro-html.md"
+        );
+        let url = Url::parse("http://127.0.0.1:8080/").unwrap();
+        assert_eq!(page_file_name(0, &url, Format::Json), "000-127-0-0-1.json");
+    }
+
+    /// Test a depth-limited crawl that honours robots.txt and writes a manifest
+    #[tokio::test]
+    async fn test_crawl_site() {
+        static ROUTES: &[(&str, &str, &str)] = &[
+            (
+                "/robots.txt",
+                "text/plain",
+                "User-agent: *\nDisallow: /private\n",
+            ),
+            (
+                "/",
+                "text/html",
+                "<h1>Home</h1><a href=\"/a\">A</a><a href=\"/private\">P</a>\
+                 <a href=\"https://example.invalid/\">Away</a>",
+            ),
+            (
+                "/a",
+                "text/html",
+                "<h1>A</h1><a href=\"/\">Home</a><a href=\"/b\">B</a>",
+            ),
+            ("/b", "text/html", "<h1>B</h1>"),
+        ];
+        let base = serve_site(ROUTES).await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let options = CrawlOptions {
+            max_depth: 1,
+            same_domain: true,
+            delay: Duration::ZERO,
+            ..Default::default()
+        };
+        let mut crawler = Crawler::new(WebFetcher::new(), options);
+        let manifest = crawler
+            .crawl(&format!("{base}/"), Some(dir.path()))
+            .await
+            .unwrap();
+
+        // /private is disallowed, the external link is out of scope and /b is too deep
+        let urls: Vec<_> = manifest
+            .pages
+            .iter()
+            .map(|page| page.url.as_str())
+            .collect();
+        assert_eq!(urls, vec![format!("{base}/"), format!("{base}/a")]);
+        assert_eq!(manifest.pages[1].depth, 1);
+        assert!(
+            manifest.pages[0]
+                .links
+                .contains(&"https://example.invalid/".to_string())
+        );
+
+        let file = manifest.pages[1].file.as_ref().unwrap();
+        let content = std::fs::read_to_string(dir.path().join(file)).unwrap();
+        assert!(content.contains("[Home]("));
+        let saved: CrawlManifest = serde_json::from_str(
+            &std::fs::read_to_string(dir.path().join(CRAWL_MANIFEST_FILE)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(saved, manifest);
+
+        // The page cap stops the crawl
+        let options = CrawlOptions {
+            max_pages: 1,
+            delay: Duration::ZERO,
+            ..Default::default()
+        };
+        let mut crawler = Crawler::new(WebFetcher::new(), options);
+        let manifest = crawler.crawl(&format!("{base}/"), None).await.unwrap();
+        assert_eq!(manifest.pages.len(), 1);
+        assert!(manifest.pages[0].file.is_none());
+    }
+}
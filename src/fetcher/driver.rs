@@ -4,12 +4,14 @@
 //! multiple browser drivers (chromedriver, geckodriver, etc.) with lifecycle management,
 //! status checking, and automatic cleanup.
 
+use super::driver_download;
 use crate::{
     Result, TarziError,
-    constants::{CHROMEDRIVER_DEFAULT_PORT, DEFAULT_TIMEOUT_SECS},
+    constants::{CHROMEDRIVER, DEFAULT_TIMEOUT_SECS, GECKODRIVER, PERSISTENT_DRIVER_STATE_FILE},
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -54,8 +56,9 @@ impl std::str::FromStr for DriverType {
 pub struct DriverConfig {
     /// Type of driver
     pub driver_type: DriverType,
-    /// Port to run the driver on
-    pub port: u16,
+    /// Port to run the driver on; `None` picks a free ephemeral port, so drivers
+    /// started by separate processes don't collide
+    pub port: Option<u16>,
     /// Additional command line arguments
     pub args: Vec<String>,
     /// Timeout for driver operations (in seconds)
@@ -68,7 +71,7 @@ impl Default for DriverConfig {
     fn default() -> Self {
         Self {
             driver_type: DriverType::Chrome,
-            port: CHROMEDRIVER_DEFAULT_PORT,
+            port: None,
             args: Vec::new(),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             verbose: false,
@@ -96,6 +99,8 @@ pub struct DriverInfo {
     pub config: DriverConfig,
     /// Current status of the driver
     pub status: DriverStatus,
+    /// Port the driver listens on, picked at start when the config has none
+    pub port: u16,
     /// Process ID of the driver
     pub pid: Option<u32>,
     /// Time when the driver was started
@@ -104,6 +109,158 @@ pub struct DriverInfo {
     pub endpoint: String,
 }
 
+/// A driver started by [`DriverManager::start_persistent_driver`] that keeps
+/// running after the starting process exits, so later processes can reuse it.
+/// Its details are kept in a state file in the per-user state directory (see
+/// [`crate::utils::user_state_dir`]), readable by the user only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistentDriver {
+    pub driver_type: DriverType,
+    pub port: u16,
+    pub pid: u32,
+    pub endpoint: String,
+}
+
+impl PersistentDriver {
+    /// Path of the state file describing the persistent driver
+    pub fn state_path() -> Result<PathBuf> {
+        crate::utils::user_state_dir()
+            .map(|dir| dir.join(PERSISTENT_DRIVER_STATE_FILE))
+            .ok_or_else(|| {
+                TarziError::Config(
+                    "No per-user directory for the persistent driver state; \
+                     set XDG_RUNTIME_DIR or HOME"
+                        .to_string(),
+                )
+            })
+    }
+
+    /// The recorded persistent driver, if one was started and not stopped
+    pub fn load() -> Option<Self> {
+        let content = std::fs::read_to_string(Self::state_path().ok()?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = Self::state_path()?;
+        if let Some(dir) = path.parent() {
+            crate::utils::create_private_dir(dir)?;
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        std::io::Write::write_all(&mut file, serde_json::to_string_pretty(self)?.as_bytes())?;
+        Ok(())
+    }
+
+    fn clear() {
+        if let Ok(path) = Self::state_path() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    /// Whether the recorded pid is still a chromedriver or geckodriver process of
+    /// the current user, so a stale or tampered record never kills another process
+    fn is_own_driver_process(&self) -> bool {
+        #[cfg(unix)]
+        {
+            // `ps` reports the same columns on Linux and macOS
+            let ps = |pid: u32, columns: &str| {
+                Command::new("ps")
+                    .args(["-o", columns, "-p", &pid.to_string()])
+                    .output()
+                    .ok()
+                    .filter(|output| output.status.success())
+                    .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            };
+            let (Some(process), Some(own_uid)) =
+                (ps(self.pid, "uid=,comm="), ps(std::process::id(), "uid="))
+            else {
+                return false;
+            };
+            let mut columns = process.split_whitespace();
+            let uid = columns.next();
+            let command = columns.collect::<Vec<_>>().join(" ");
+            let name = Path::new(&command)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            uid == Some(own_uid.as_str()) && [CHROMEDRIVER, GECKODRIVER].contains(&name.as_str())
+        }
+        #[cfg(not(unix))]
+        {
+            Command::new("tasklist")
+                .args(["/FI", &format!("PID eq {}", self.pid), "/NH"])
+                .output()
+                .map(|output| {
+                    let tasks = String::from_utf8_lossy(&output.stdout).to_lowercase();
+                    tasks.contains(CHROMEDRIVER) || tasks.contains(GECKODRIVER)
+                })
+                .unwrap_or(false)
+        }
+    }
+}
+
+/// A driver binary looked up in PATH
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriverBinary {
+    /// Driver type
+    pub driver_type: DriverType,
+    /// Path of the binary, `None` when it was not found
+    pub path: Option<PathBuf>,
+    /// First line of the binary's `--version` output
+    pub version: Option<String>,
+}
+
+/// Table of detected driver binaries, one line per driver
+pub fn format_driver_list(binaries: &[DriverBinary]) -> String {
+    let mut output = format!("{:<14} {:<10} {:<40} VERSION\n", "DRIVER", "STATUS", "PATH");
+    for binary in binaries {
+        let line = match &binary.path {
+            Some(path) => format!(
+                "{:<14} {:<10} {:<40} {}",
+                binary.driver_type.to_string(),
+                "found",
+                path.display().to_string(),
+                binary.version.as_deref().unwrap_or("-")
+            ),
+            None => format!(
+                "{:<14} {:<10} {:<40} -",
+                binary.driver_type.to_string(),
+                "not found",
+                "-"
+            ),
+        };
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// `port`, or a free port assigned by the OS when `None`. The probe listener is
+/// closed before the driver binds the port.
+fn resolve_port(port: Option<u16>) -> Result<u16> {
+    match port {
+        Some(port) => Ok(port),
+        None => {
+            let listener = std::net::TcpListener::bind(("127.0.0.1", 0))?;
+            Ok(listener.local_addr()?.port())
+        }
+    }
+}
+
+/// Version reported by a driver binary, e.g. "ChromeDriver 120.0.6099.109 (...)"
+fn driver_binary_version(path: &Path) -> Option<String> {
+    let output = Command::new(path).arg("--version").output().ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
+
 /// A running web driver process
 #[derive(Debug)]
 struct DriverProcess {
@@ -125,6 +282,11 @@ pub struct DriverManager {
     drivers: Arc<Mutex<HashMap<u16, DriverProcess>>>,
     /// Default configuration
     default_config: DriverConfig,
+    /// Download a driver missing from PATH (`fetcher.auto_download_driver`)
+    auto_download: bool,
+    /// Directory downloaded drivers are kept in; `None` when the user has no
+    /// cache directory, so drivers are neither cached nor downloaded
+    cache_dir: Option<PathBuf>,
 }
 
 impl DriverManager {
@@ -133,6 +295,8 @@ impl DriverManager {
         Self {
             drivers: Arc::new(Mutex::new(HashMap::new())),
             default_config: DriverConfig::default(),
+            auto_download: false,
+            cache_dir: driver_download::default_cache_dir(),
         }
     }
 
@@ -141,9 +305,23 @@ impl DriverManager {
         Self {
             drivers: Arc::new(Mutex::new(HashMap::new())),
             default_config: config,
+            auto_download: false,
+            cache_dir: driver_download::default_cache_dir(),
         }
     }
 
+    /// Download drivers missing from PATH in [`Self::ensure_driver`]
+    pub fn with_auto_download(mut self, auto_download: bool) -> Self {
+        self.auto_download = auto_download;
+        self
+    }
+
+    /// Keep downloaded drivers in `cache_dir` instead of the user's cache directory
+    pub fn with_cache_dir(mut self, cache_dir: PathBuf) -> Self {
+        self.cache_dir = Some(cache_dir);
+        self
+    }
+
     /// Start a web driver with default configuration
     pub fn start_driver(&self) -> Result<DriverInfo> {
         self.start_driver_with_config(self.default_config.clone())
@@ -151,20 +329,18 @@ impl DriverManager {
 
     /// Start a web driver with custom configuration
     pub fn start_driver_with_config(&self, config: DriverConfig) -> Result<DriverInfo> {
-        // Check if driver binary exists
-        self.check_driver_binary(&config.driver_type)?;
+        let binary = self.find_driver(&config.driver_type)?;
+
+        let port = resolve_port(config.port)?;
 
         // Check if port is already in use
-        if self.is_port_in_use(config.port) {
-            return Err(TarziError::Driver(format!(
-                "Port {} is already in use",
-                config.port
-            )));
+        if self.is_port_in_use(port) {
+            return Err(TarziError::Driver(format!("Port {port} is already in use")));
         }
 
         // Build command
-        let mut cmd = Command::new(self.get_driver_binary_name(&config.driver_type));
-        cmd.arg(format!("--port={}", config.port));
+        let mut cmd = Command::new(binary);
+        cmd.arg(format!("--port={port}"));
 
         // Add driver-specific arguments
         self.add_driver_specific_args(&mut cmd, &config);
@@ -187,7 +363,98 @@ impl DriverManager {
             ))
         })?;
 
-        self.create_and_store_driver_process(child, config)
+        self.create_and_store_driver_process(child, config, port)
+    }
+
+    /// Start a driver that is not tied to this manager: it keeps running after the
+    /// manager is dropped and the process exits, until
+    /// [`Self::stop_persistent_driver`] is called. Browser fetches in any process
+    /// reuse it while it responds.
+    pub fn start_persistent_driver(&self, config: DriverConfig) -> Result<PersistentDriver> {
+        if let Some(driver) = PersistentDriver::load()
+            && self.is_driver_healthy(&driver.endpoint)
+        {
+            return Err(TarziError::Driver(format!(
+                "A persistent {} is already running at {} (pid {})",
+                driver.driver_type, driver.endpoint, driver.pid
+            )));
+        }
+        let binary = self.find_driver(&config.driver_type)?;
+
+        let port = resolve_port(config.port)?;
+        let mut cmd = Command::new(binary);
+        cmd.arg(format!("--port={port}"));
+        self.add_driver_specific_args(&mut cmd, &config);
+        cmd.args(&config.args);
+        // No pipes: nothing would read them once this process has exited
+        cmd.stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .stdin(Stdio::null());
+
+        let mut child = cmd.spawn().map_err(|e| {
+            TarziError::DriverProcess(format!(
+                "Failed to start {} driver: {}",
+                config.driver_type, e
+            ))
+        })?;
+        let endpoint = format!("http://127.0.0.1:{port}");
+        if let Err(e) = self.wait_for_driver_ready(&endpoint, config.timeout) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(e);
+        }
+
+        let driver = PersistentDriver {
+            driver_type: config.driver_type,
+            port,
+            pid: child.id(),
+            endpoint,
+        };
+        driver.save()?;
+        log::info!(
+            "Started persistent {} on port {} (pid {})",
+            driver.driver_type,
+            driver.port,
+            driver.pid
+        );
+        Ok(driver)
+    }
+
+    /// Stop the driver started by [`Self::start_persistent_driver`], possibly in
+    /// another process
+    pub fn stop_persistent_driver() -> Result<PersistentDriver> {
+        let driver = PersistentDriver::load()
+            .ok_or_else(|| TarziError::Driver("No persistent driver is running".to_string()))?;
+        if !driver.is_own_driver_process() {
+            // Gone, or the pid was reused by an unrelated process: never kill it
+            PersistentDriver::clear();
+            log::warn!(
+                "Persistent driver (pid {}) is not running; cleared its record",
+                driver.pid
+            );
+            return Ok(driver);
+        }
+
+        #[cfg(windows)]
+        let status = Command::new("taskkill")
+            .args(["/PID", &driver.pid.to_string(), "/F"])
+            .status();
+        #[cfg(not(windows))]
+        let status = Command::new("kill").arg(driver.pid.to_string()).status();
+
+        PersistentDriver::clear();
+        match status {
+            Ok(status) if status.success() => {
+                log::info!(
+                    "Stopped persistent {} (pid {})",
+                    driver.driver_type,
+                    driver.pid
+                );
+            }
+            // Already gone, e.g. killed by hand: clearing the stale record is enough
+            _ => log::warn!("Persistent driver (pid {}) was not running", driver.pid),
+        }
+        Ok(driver)
     }
 
     /// Stop a driver by port
@@ -248,6 +515,7 @@ impl DriverManager {
             DriverInfo {
                 config: driver_process.config.clone(),
                 status,
+                port,
                 pid: Some(driver_process.child.id()),
                 started_at: driver_process.started_at,
                 endpoint: format!("http://127.0.0.1:{port}"),
@@ -271,6 +539,7 @@ impl DriverManager {
                 DriverInfo {
                     config: driver_process.config.clone(),
                     status,
+                    port: *port,
                     pid: Some(driver_process.child.id()),
                     started_at: driver_process.started_at,
                     endpoint: format!("http://127.0.0.1:{port}"),
@@ -279,15 +548,15 @@ impl DriverManager {
             .collect()
     }
 
-    /// Check if a driver binary is installed
-    pub fn check_driver_binary(&self, driver_type: &DriverType) -> Result<()> {
+    /// Check if a driver binary is installed, returning its path
+    pub fn check_driver_binary(&self, driver_type: &DriverType) -> Result<PathBuf> {
         let binary_name = self.get_driver_binary_name(driver_type);
 
         // Try to find the binary in PATH
         match which::which(&binary_name) {
             Ok(path) => {
                 log::debug!("Found {binary_name} at {path:?}");
-                Ok(())
+                Ok(path)
             }
             Err(_) => Err(TarziError::DriverNotFound(
                 self.create_driver_not_found_message(driver_type, &binary_name),
@@ -295,6 +564,81 @@ impl DriverManager {
         }
     }
 
+    /// Path of the driver binary to launch: the one in PATH, else one downloaded
+    /// earlier (matching the installed Chrome's version for chromedriver), else
+    /// the driver-not-found error. Drivers are started with the binary found here;
+    /// call [`Self::ensure_driver`] first to download a missing one.
+    pub fn find_driver(&self, driver_type: &DriverType) -> Result<PathBuf> {
+        self.find_driver_with(driver_type, |driver_type| {
+            self.check_driver_binary(driver_type)
+        })
+    }
+
+    fn find_driver_with(
+        &self,
+        driver_type: &DriverType,
+        lookup: impl Fn(&DriverType) -> Result<PathBuf>,
+    ) -> Result<PathBuf> {
+        let not_found = match lookup(driver_type) {
+            Ok(path) => return Ok(path),
+            Err(e) => e,
+        };
+        let browser_major = driver_download::installed_browser_major(driver_type);
+        if let Some(path) = self.cache_dir.as_ref().and_then(|cache_dir| {
+            driver_download::cached_driver(cache_dir, driver_type, browser_major)
+        }) {
+            log::debug!("Using cached {driver_type} at {path:?}");
+            return Ok(path);
+        }
+        Err(not_found)
+    }
+
+    /// Like [`Self::find_driver`], but when auto download is enabled a driver that
+    /// can't be found is downloaded to the cache
+    pub async fn ensure_driver(&self, driver_type: &DriverType) -> Result<PathBuf> {
+        let not_found = match self.find_driver(driver_type) {
+            Ok(path) => return Ok(path),
+            Err(e) => e,
+        };
+        if !self.auto_download {
+            return Err(not_found);
+        }
+        let cache_dir = self.cache_dir.clone().ok_or_else(|| {
+            TarziError::Config(format!(
+                "Cannot download {driver_type}: no per-user cache directory \
+                 (set XDG_CACHE_HOME or HOME)"
+            ))
+        })?;
+        let browser_major = driver_download::installed_browser_major(driver_type);
+        driver_download::download_driver(cache_dir, driver_type.clone(), browser_major).await
+    }
+
+    /// Look up the binary and version of every supported driver
+    pub fn detect_drivers(&self) -> Vec<DriverBinary> {
+        Self::detect_drivers_with(
+            |driver_type| self.check_driver_binary(driver_type),
+            driver_binary_version,
+        )
+    }
+
+    fn detect_drivers_with(
+        lookup: impl Fn(&DriverType) -> Result<PathBuf>,
+        version: impl Fn(&Path) -> Option<String>,
+    ) -> Vec<DriverBinary> {
+        Self::supported_drivers()
+            .into_iter()
+            .map(|driver_type| {
+                let path = lookup(&driver_type).ok();
+                let version = path.as_deref().and_then(&version);
+                DriverBinary {
+                    driver_type,
+                    path,
+                    version,
+                }
+            })
+            .collect()
+    }
+
     /// Check if a port is in use by this manager
     pub fn is_port_in_use(&self, port: u16) -> bool {
         let drivers = self.drivers.lock().unwrap();
@@ -344,10 +688,11 @@ impl DriverManager {
         &self,
         child: Child,
         config: DriverConfig,
+        port: u16,
     ) -> Result<DriverInfo> {
         let pid = child.id();
         let started_at = Instant::now();
-        let endpoint = format!("http://127.0.0.1:{}", config.port);
+        let endpoint = format!("http://127.0.0.1:{port}");
 
         // Store the driver process
         let driver_process = DriverProcess {
@@ -358,7 +703,7 @@ impl DriverManager {
 
         {
             let mut drivers = self.drivers.lock().unwrap();
-            drivers.insert(config.port, driver_process);
+            drivers.insert(port, driver_process);
         }
 
         // Wait for driver to be ready
@@ -367,6 +712,7 @@ impl DriverManager {
         Ok(DriverInfo {
             config,
             status: DriverStatus::Running,
+            port,
             pid: Some(pid),
             started_at,
             endpoint,
@@ -440,7 +786,7 @@ impl DriverManager {
     pub fn create_config(driver_type: DriverType, port: u16) -> DriverConfig {
         DriverConfig {
             driver_type,
-            port,
+            port: Some(port),
             args: Vec::new(),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             verbose: false,
@@ -470,6 +816,7 @@ impl Drop for DriverManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::CHROMEDRIVER_DEFAULT_PORT;
 
     #[test]
     fn test_driver_type_from_str() {
@@ -493,6 +840,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_persistent_driver_state() {
+        let driver = PersistentDriver {
+            driver_type: DriverType::Firefox,
+            port: 4444,
+            pid: 4242,
+            endpoint: "http://127.0.0.1:4444".to_string(),
+        };
+        let state = serde_json::to_string(&driver).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PersistentDriver>(&state).unwrap(),
+            driver
+        );
+        let state_path = PersistentDriver::state_path().unwrap();
+        assert!(state_path.ends_with(crate::constants::PERSISTENT_DRIVER_STATE_FILE));
+        assert!(!state_path.starts_with(std::env::temp_dir()));
+        // A pid that is not a driver of ours is never treated as the driver
+        let not_a_driver = PersistentDriver {
+            pid: std::process::id(),
+            ..driver
+        };
+        assert!(!not_a_driver.is_own_driver_process());
+    }
+
     #[test]
     fn test_driver_type_display() {
         assert_eq!(DriverType::Chrome.to_string(), "chromedriver");
@@ -507,7 +878,7 @@ mod tests {
     fn test_driver_config_default() {
         let config = DriverConfig::default();
         assert_eq!(config.driver_type, DriverType::Chrome);
-        assert_eq!(config.port, CHROMEDRIVER_DEFAULT_PORT);
+        assert_eq!(config.port, None);
         assert_eq!(config.timeout, Duration::from_secs(DEFAULT_TIMEOUT_SECS));
         assert!(!config.verbose);
         assert!(config.args.is_empty());
@@ -517,14 +888,14 @@ mod tests {
     fn test_driver_manager_new() {
         let manager = DriverManager::new();
         assert_eq!(manager.default_config.driver_type, DriverType::Chrome);
-        assert_eq!(manager.default_config.port, CHROMEDRIVER_DEFAULT_PORT);
+        assert_eq!(manager.default_config.port, None);
     }
 
     #[test]
     fn test_driver_manager_with_config() {
         let config = DriverConfig {
             driver_type: DriverType::Firefox,
-            port: 19515, // Use a different port for testing
+            port: Some(19515), // Use a different port for testing
             args: vec!["--verbose".to_string()],
             timeout: Duration::from_secs(10),
             verbose: true,
@@ -546,11 +917,72 @@ mod tests {
         assert_eq!(drivers.len(), 2);
     }
 
+    #[test]
+    fn test_detect_drivers_formatting() {
+        let binaries = DriverManager::detect_drivers_with(
+            |driver_type| match driver_type {
+                DriverType::Chrome => Ok(PathBuf::from("/usr/bin/chromedriver")),
+                _ => Err(TarziError::DriverNotFound("not in PATH".to_string())),
+            },
+            |_| Some("ChromeDriver 120.0.6099.109".to_string()),
+        );
+        assert_eq!(binaries.len(), 2);
+        assert_eq!(
+            binaries[0].path.as_deref(),
+            Some(Path::new("/usr/bin/chromedriver"))
+        );
+        assert_eq!(binaries[1].path, None);
+        assert_eq!(binaries[1].version, None);
+
+        let output = format_driver_list(&binaries);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("DRIVER"));
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<_>>(),
+            [
+                "chromedriver",
+                "found",
+                "/usr/bin/chromedriver",
+                "ChromeDriver",
+                "120.0.6099.109"
+            ]
+        );
+        assert_eq!(
+            lines[2],
+            format!("{:<14} {:<10} {:<40} -", "geckodriver", "not found", "-")
+        );
+    }
+
+    #[test]
+    fn test_find_driver_cache_hit() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let manager = DriverManager::new().with_cache_dir(cache_dir.path().to_path_buf());
+        // Stands in for a PATH without geckodriver
+        let not_in_path =
+            |_: &DriverType| Err(TarziError::DriverNotFound("not in PATH".to_string()));
+        assert!(matches!(
+            manager.find_driver_with(&DriverType::Firefox, not_in_path),
+            Err(TarziError::DriverNotFound(_))
+        ));
+
+        let dir = cache_dir.path().join("geckodriver").join("0.36.0");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("geckodriver"), b"").unwrap();
+        // Found without downloading, even with auto download disabled
+        assert_eq!(
+            manager
+                .find_driver_with(&DriverType::Firefox, not_in_path)
+                .unwrap(),
+            dir.join("geckodriver")
+        );
+    }
+
     #[test]
     fn test_create_config() {
         let config = DriverManager::create_config(DriverType::Firefox, 19515);
         assert_eq!(config.driver_type, DriverType::Firefox);
-        assert_eq!(config.port, 19515);
+        assert_eq!(config.port, Some(19515));
         assert_eq!(config.timeout, Duration::from_secs(DEFAULT_TIMEOUT_SECS));
         assert!(!config.verbose);
     }
@@ -644,7 +1076,7 @@ pub mod test_helpers {
     pub fn create_test_manager() -> DriverManager {
         let config = DriverConfig {
             driver_type: DriverType::Chrome,
-            port: 19515, // Use a different port for testing
+            port: Some(19515), // Use a different port for testing
             args: vec!["--disable-gpu".to_string(), "--no-sandbox".to_string()],
             timeout: Duration::from_secs(10),
             verbose: true,
@@ -0,0 +1,330 @@
+//! Driver downloads for [`DriverManager::ensure_driver`]
+//!
+//! Drivers are cached as `<cache dir>/<binary>/<version>/<binary>`: chromedriver
+//! from Chrome for Testing, matching the installed Chrome's major version when it
+//! can be detected, and geckodriver from its latest GitHub release. Downloading
+//! needs the `driver-download` feature; drivers already in the cache are found
+//! without it.
+//!
+//! [`DriverManager::ensure_driver`]: super::driver::DriverManager::ensure_driver
+
+use super::driver::DriverType;
+#[cfg(feature = "driver-download")]
+use crate::constants::{
+    CHROME_FOR_TESTING_URL, CHROMEDRIVER_DOWNLOAD_URL, DRIVER_DOWNLOAD_TIMEOUT_SECS,
+    GECKODRIVER_RELEASES_URL,
+};
+use crate::{
+    Result, TarziError,
+    constants::{CHROMEDRIVER, DRIVER_CACHE_DIR, GECKODRIVER},
+    utils::user_cache_dir,
+};
+#[cfg(feature = "driver-download")]
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+#[cfg(feature = "driver-download")]
+use std::time::Duration;
+
+/// Chrome binaries whose `--version` tells which chromedriver is compatible
+const CHROME_BINARIES: &[&str] = &[
+    "google-chrome",
+    "google-chrome-stable",
+    "chromium",
+    "chromium-browser",
+    "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+];
+
+/// Default driver cache: `drivers` in the user's cache directory, see
+/// [`user_cache_dir`]. `None` when the user has no cache directory.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    user_cache_dir().map(|dir| dir.join(DRIVER_CACHE_DIR))
+}
+
+/// Major version of the browser the driver must match, when it is installed and
+/// the driver is versioned with it (only Chrome; geckodriver supports a range of
+/// Firefox versions)
+pub(crate) fn installed_browser_major(driver_type: &DriverType) -> Option<u32> {
+    if *driver_type != DriverType::Chrome {
+        return None;
+    }
+    CHROME_BINARIES.iter().find_map(|binary| {
+        let output = Command::new(binary).arg("--version").output().ok()?;
+        parse_major_version(&String::from_utf8_lossy(&output.stdout))
+    })
+}
+
+/// Major version in `--version` output, e.g. 120 for "Google Chrome 120.0.6099.109"
+fn parse_major_version(output: &str) -> Option<u32> {
+    output
+        .split_whitespace()
+        .find(|word| word.contains('.'))
+        .and_then(|version| version.split('.').next())
+        .and_then(|major| major.parse().ok())
+}
+
+/// A driver downloaded earlier: for `browser_major`, one of that major version,
+/// otherwise the newest one in the cache
+pub(crate) fn cached_driver(
+    cache_dir: &Path,
+    driver_type: &DriverType,
+    browser_major: Option<u32>,
+) -> Option<PathBuf> {
+    let binary = binary_name(driver_type)?;
+    let entries = std::fs::read_dir(cache_dir.join(binary)).ok()?;
+    let mut versions: Vec<(Vec<u32>, PathBuf)> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let version = entry
+                .file_name()
+                .to_str()?
+                .split('.')
+                .map(|part| part.parse().ok())
+                .collect::<Option<Vec<u32>>>()?;
+            let path = entry.path().join(binary);
+            path.is_file().then_some((version, path))
+        })
+        .filter(|(version, _)| browser_major.is_none_or(|major| version.first() == Some(&major)))
+        .collect();
+    versions.sort();
+    versions.pop().map(|(_, path)| path)
+}
+
+/// Download the driver for this OS and architecture into the cache, returning
+/// the path of the binary
+#[cfg(feature = "driver-download")]
+pub(crate) async fn download_driver(
+    cache_dir: PathBuf,
+    driver_type: DriverType,
+    browser_major: Option<u32>,
+) -> Result<PathBuf> {
+    // The blocking client must not run on an async runtime thread
+    tokio::task::spawn_blocking(move || match driver_type {
+        DriverType::Chrome => download_chromedriver(&cache_dir, browser_major),
+        DriverType::Firefox => download_geckodriver(&cache_dir),
+        DriverType::Generic(name) => Err(TarziError::DriverNotFound(format!(
+            "{name} cannot be downloaded; only chromedriver and geckodriver can"
+        ))),
+    })
+    .await
+    .map_err(|e| TarziError::Driver(format!("Driver download task failed: {e}")))?
+}
+
+/// Downloading needs the `driver-download` feature
+#[cfg(not(feature = "driver-download"))]
+pub(crate) async fn download_driver(
+    _cache_dir: PathBuf,
+    driver_type: DriverType,
+    _browser_major: Option<u32>,
+) -> Result<PathBuf> {
+    Err(TarziError::DriverNotFound(format!(
+        "{driver_type} cannot be downloaded: tarzi was built without the \
+         driver-download feature; please install it manually"
+    )))
+}
+
+fn binary_name(driver_type: &DriverType) -> Option<&'static str> {
+    match driver_type {
+        DriverType::Chrome => Some(CHROMEDRIVER),
+        DriverType::Firefox => Some(GECKODRIVER),
+        DriverType::Generic(_) => None,
+    }
+}
+
+#[cfg(feature = "driver-download")]
+fn http_client() -> Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(DRIVER_DOWNLOAD_TIMEOUT_SECS))
+        .build()?)
+}
+
+#[cfg(feature = "driver-download")]
+fn download_chromedriver(cache_dir: &Path, browser_major: Option<u32>) -> Result<PathBuf> {
+    let platform = match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => "linux64",
+        ("macos", "x86_64") => "mac-x64",
+        ("macos", "aarch64") => "mac-arm64",
+        ("windows", "x86_64") => "win64",
+        (os, arch) => return Err(unsupported_platform(CHROMEDRIVER, os, arch)),
+    };
+    let client = http_client()?;
+
+    // The release matching the installed Chrome, else the latest stable one
+    let release = browser_major.map_or_else(
+        || "LATEST_RELEASE_STABLE".to_string(),
+        |major| format!("LATEST_RELEASE_{major}"),
+    );
+    let version_response = client
+        .get(format!("{CHROME_FOR_TESTING_URL}/{release}"))
+        .send()?;
+    let version_response = match version_response.error_for_status() {
+        Ok(response) => response,
+        Err(e) if browser_major.is_some() => {
+            log::warn!("No chromedriver release for Chrome {release}: {e}; using the latest");
+            client
+                .get(format!("{CHROME_FOR_TESTING_URL}/LATEST_RELEASE_STABLE"))
+                .send()?
+                .error_for_status()?
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let version = version_response.text()?.trim().to_string();
+
+    let url =
+        format!("{CHROMEDRIVER_DOWNLOAD_URL}/{version}/{platform}/chromedriver-{platform}.zip");
+    log::info!("Downloading chromedriver {version} from {url}");
+    let archive = client.get(&url).send()?.error_for_status()?.bytes()?;
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(archive))
+        .map_err(|e| TarziError::Driver(format!("Invalid chromedriver archive: {e}")))?;
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| TarziError::Driver(format!("Invalid chromedriver archive: {e}")))?;
+        let is_binary = Path::new(entry.name())
+            .file_stem()
+            .is_some_and(|stem| stem == CHROMEDRIVER);
+        if is_binary && entry.is_file() {
+            let mut binary = Vec::new();
+            entry.read_to_end(&mut binary)?;
+            return install(cache_dir, CHROMEDRIVER, &version, &binary);
+        }
+    }
+    Err(TarziError::Driver(format!(
+        "chromedriver archive {url} contains no chromedriver binary"
+    )))
+}
+
+#[cfg(feature = "driver-download")]
+fn download_geckodriver(cache_dir: &Path) -> Result<PathBuf> {
+    let platform = match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("linux", "x86_64") => "linux64",
+        ("linux", "aarch64") => "linux-aarch64",
+        ("macos", "x86_64") => "macos",
+        ("macos", "aarch64") => "macos-aarch64",
+        (os, arch) => return Err(unsupported_platform(GECKODRIVER, os, arch)),
+    };
+    let client = http_client()?;
+
+    // The latest release page redirects to the release's tag, e.g. .../tag/v0.36.0
+    let latest = client
+        .get(format!("{GECKODRIVER_RELEASES_URL}/latest"))
+        .send()?
+        .error_for_status()?;
+    let tag = latest
+        .url()
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|tag| tag.starts_with('v'))
+        .map(str::to_string)
+        .ok_or_else(|| {
+            TarziError::Driver(format!(
+                "Could not determine the latest geckodriver release from {}",
+                latest.url()
+            ))
+        })?;
+
+    let url =
+        format!("{GECKODRIVER_RELEASES_URL}/download/{tag}/geckodriver-{tag}-{platform}.tar.gz");
+    log::info!("Downloading geckodriver {tag} from {url}");
+    let archive = client.get(&url).send()?.error_for_status()?.bytes()?;
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(archive.as_ref()));
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry
+            .path()?
+            .file_name()
+            .is_some_and(|name| name == GECKODRIVER)
+        {
+            let mut binary = Vec::new();
+            entry.read_to_end(&mut binary)?;
+            return install(cache_dir, GECKODRIVER, tag.trim_start_matches('v'), &binary);
+        }
+    }
+    Err(TarziError::Driver(format!(
+        "geckodriver archive {url} contains no geckodriver binary"
+    )))
+}
+
+#[cfg(feature = "driver-download")]
+fn unsupported_platform(binary: &str, os: &str, arch: &str) -> TarziError {
+    TarziError::DriverNotFound(format!(
+        "No {binary} download is available for {os}/{arch}; please install it manually"
+    ))
+}
+
+/// Write `binary` to `<cache dir>/<name>/<version>/<name>`, through a temporary
+/// file so a concurrent lookup never sees a partial binary
+#[cfg(feature = "driver-download")]
+fn install(cache_dir: &Path, name: &str, version: &str, binary: &[u8]) -> Result<PathBuf> {
+    let dir = cache_dir.join(name).join(version);
+    crate::utils::create_private_dir(&dir)?;
+    let mut file = tempfile::NamedTempFile::new_in(&dir)?;
+    std::io::Write::write_all(&mut file, binary)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.as_file()
+            .set_permissions(std::fs::Permissions::from_mode(0o755))?;
+    }
+    let path = dir.join(name);
+    file.persist(&path).map_err(|e| TarziError::Io(e.error))?;
+    log::info!("Installed {name} {version} at {}", path.display());
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_binary(cache_dir: &Path, name: &str, version: &str) -> PathBuf {
+        let dir = cache_dir.join(name).join(version);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, b"").unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_major_version() {
+        assert_eq!(
+            parse_major_version("Google Chrome 120.0.6099.109 \n"),
+            Some(120)
+        );
+        assert_eq!(
+            parse_major_version("Chromium 131.0.6778.85 snap"),
+            Some(131)
+        );
+        assert_eq!(parse_major_version("no version here"), None);
+    }
+
+    #[test]
+    fn test_cached_driver() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            cached_driver(cache_dir.path(), &DriverType::Chrome, None),
+            None
+        );
+
+        let old = cache_binary(cache_dir.path(), CHROMEDRIVER, "119.0.6045.105");
+        let new = cache_binary(cache_dir.path(), CHROMEDRIVER, "120.0.6099.109");
+        // A version directory without a binary, e.g. an interrupted download
+        std::fs::create_dir_all(cache_dir.path().join(CHROMEDRIVER).join("121.0.1.1")).unwrap();
+
+        assert_eq!(
+            cached_driver(cache_dir.path(), &DriverType::Chrome, None),
+            Some(new)
+        );
+        assert_eq!(
+            cached_driver(cache_dir.path(), &DriverType::Chrome, Some(119)),
+            Some(old)
+        );
+        assert_eq!(
+            cached_driver(cache_dir.path(), &DriverType::Chrome, Some(121)),
+            None
+        );
+        assert_eq!(
+            cached_driver(cache_dir.path(), &DriverType::Firefox, None),
+            None
+        );
+    }
+}
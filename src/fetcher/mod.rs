@@ -3,13 +3,26 @@
 //! This module provides functionality for fetching web content using different methods:
 //! - Plain HTTP requests
 //! - Browser automation (headless and headed)
+//! - Breadth-first crawling of a site
 
 pub mod browser;
+pub mod cache;
+pub mod crawl;
 pub mod driver;
+pub mod driver_download;
+pub mod proxy;
+pub mod streaming;
 pub mod types;
 pub mod webfetcher;
 
 // Re-export main types and functions
-pub use driver::{DriverConfig, DriverInfo, DriverManager, DriverStatus, DriverType};
-pub use types::{FetchMode, WebFetcher};
+pub use cache::{CacheKey, FetchCache};
+pub use crawl::{CrawlManifest, CrawlOptions, CrawledPage, Crawler, RobotsRules};
+pub use driver::{
+    DriverBinary, DriverConfig, DriverInfo, DriverManager, DriverStatus, DriverType,
+    PersistentDriver, format_driver_list,
+};
+pub use proxy::ProxyPool;
+pub use streaming::{ExtractedContent, StreamingExtractor};
+pub use types::{FetchMode, FetchRequest, FetchResponse, WebFetcher};
 pub use webfetcher::WebFetcher as WebFetcherImpl;
@@ -0,0 +1,472 @@
+//! Incremental text and link extraction for large pages
+//!
+//! The extractor consumes an HTML body chunk by chunk and keeps only the
+//! extracted output plus a small amount of state for tags or characters that
+//! straddle chunk boundaries, so the raw document is never buffered in full.
+//! It is used for extraction-only fetches; conversions that need the full DOM
+//! (markdown, JSON, YAML) keep using the buffered path.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Elements whose content is never visible text
+const SKIPPED_ELEMENTS: &[&str] = &["script", "style", "noscript", "template", "title"];
+
+/// Elements that start a new line in extracted text
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// Text and links extracted from a page
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedContent {
+    pub text: String,
+    pub links: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Text,
+    Tag,
+    Comment,
+    /// Content of a skipped element, which ends only at its own closing tag
+    RawText,
+}
+
+/// Streaming HTML text and link extractor
+#[derive(Debug)]
+pub struct StreamingExtractor {
+    state: State,
+    /// Bytes of a UTF-8 character split across chunks
+    pending_bytes: Vec<u8>,
+    /// Text of the current text node, flushed when a tag starts
+    pending_text: String,
+    /// Content of the tag currently being read
+    tag: String,
+    /// Tail of a skipped element's content that may start its closing tag
+    raw_tail: VecDeque<char>,
+    /// Quote character of the attribute value being read inside a tag
+    quote: Option<char>,
+    /// Closing tag that ends the element whose content is being skipped
+    skip_until: Option<String>,
+    /// Inside `<head>`, whose text is metadata rather than page content
+    in_head: bool,
+    output: ExtractedContent,
+}
+
+impl StreamingExtractor {
+    pub fn new() -> Self {
+        Self {
+            state: State::Text,
+            pending_bytes: Vec::new(),
+            pending_text: String::new(),
+            tag: String::new(),
+            raw_tail: VecDeque::new(),
+            quote: None,
+            skip_until: None,
+            in_head: false,
+            output: ExtractedContent::default(),
+        }
+    }
+
+    /// Feed the next chunk of the HTML body
+    pub fn push(&mut self, chunk: &[u8]) {
+        let mut bytes = std::mem::take(&mut self.pending_bytes);
+        bytes.extend_from_slice(chunk);
+
+        let valid = match std::str::from_utf8(&bytes) {
+            Ok(text) => text.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => {
+                // Invalid sequence: decode lossily rather than stalling
+                let text = String::from_utf8_lossy(&bytes).into_owned();
+                self.push_str(&text);
+                return;
+            }
+        };
+
+        let (head, tail) = bytes.split_at(valid);
+        // `head` was validated above
+        let text = std::str::from_utf8(head).unwrap_or_default().to_owned();
+        self.pending_bytes = tail.to_vec();
+        self.push_str(&text);
+    }
+
+    /// Finish extraction and return the collected content
+    pub fn finish(mut self) -> ExtractedContent {
+        if !self.pending_bytes.is_empty() {
+            let text = String::from_utf8_lossy(&self.pending_bytes).into_owned();
+            self.pending_bytes.clear();
+            self.push_str(&text);
+        }
+        self.flush_text();
+        self.output.text = self.output.text.trim().to_string();
+        self.output
+    }
+
+    fn push_str(&mut self, text: &str) {
+        for c in text.chars() {
+            match self.state {
+                State::Text => {
+                    if c == '<' {
+                        self.flush_text();
+                        self.tag.clear();
+                        self.quote = None;
+                        self.state = State::Tag;
+                    } else {
+                        self.pending_text.push(c);
+                    }
+                }
+                State::Tag => match self.quote {
+                    Some(quote) => {
+                        self.tag.push(c);
+                        if c == quote {
+                            self.quote = None;
+                        }
+                    }
+                    None if c == '>' => {
+                        let tag = std::mem::take(&mut self.tag);
+                        self.handle_tag(&tag);
+                        self.state = if self.skip_until.is_some() {
+                            State::RawText
+                        } else {
+                            State::Text
+                        };
+                    }
+                    None => {
+                        // A quote only opens an attribute value right after `=`
+                        if matches!(c, '"' | '\'') && self.tag.trim_end().ends_with('=') {
+                            self.quote = Some(c);
+                        }
+                        self.tag.push(c);
+                        if self.tag == "!--" {
+                            self.state = State::Comment;
+                        }
+                    }
+                },
+                State::Comment => {
+                    self.tag.push(c);
+                    if self.tag.ends_with("-->") {
+                        self.tag.clear();
+                        self.state = State::Text;
+                    }
+                }
+                State::RawText => self.push_raw_text(c),
+            }
+        }
+    }
+
+    /// Scan a skipped element's content for its closing tag, keeping only as many
+    /// trailing characters as the closing tag is long. A `<` that doesn't start
+    /// the closing tag, as in `if (a<b)`, is content like any other character.
+    fn push_raw_text(&mut self, c: char) {
+        let Some(name) = &self.skip_until else {
+            self.state = State::Text;
+            return;
+        };
+        // `</` followed by the element name
+        let end_len = name.len() + 2;
+        self.raw_tail.push_back(c);
+        if self.raw_tail.len() > end_len {
+            self.raw_tail.pop_front();
+        }
+        let is_end = self.raw_tail.len() == end_len
+            && self
+                .raw_tail
+                .iter()
+                .zip("</".chars().chain(name.chars()))
+                .all(|(c, expected)| c.eq_ignore_ascii_case(&expected));
+        if is_end {
+            self.raw_tail.clear();
+            // Read the rest of the closing tag like any other tag
+            self.tag = format!("/{name}");
+            self.quote = None;
+            self.state = State::Tag;
+        }
+    }
+
+    fn handle_tag(&mut self, tag: &str) {
+        let tag = tag.trim();
+        let (closing, body) = match tag.strip_prefix('/') {
+            Some(rest) => (true, rest),
+            None => (false, tag),
+        };
+        let name: String = body
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        if let Some(skip) = &self.skip_until {
+            if closing && &name == skip {
+                self.skip_until = None;
+            }
+            return;
+        }
+
+        match (name.as_str(), closing) {
+            ("head", false) => self.in_head = true,
+            // `</head>` is optional, so the body also ends the head
+            ("head", true) | ("body", false) => self.in_head = false,
+            _ => {}
+        }
+
+        if !closing && SKIPPED_ELEMENTS.contains(&name.as_str()) && !body.ends_with('/') {
+            self.skip_until = Some(name);
+            return;
+        }
+
+        if !closing
+            && name == "a"
+            && let Some(href) = extract_attribute(body, "href")
+            && !href.is_empty()
+            && !href.starts_with('#')
+        {
+            self.output.links.push(href);
+        }
+
+        if BLOCK_ELEMENTS.contains(&name.as_str()) && !self.output.text.ends_with('\n') {
+            self.output.text.push('\n');
+        }
+    }
+
+    fn flush_text(&mut self) {
+        if self.pending_text.is_empty() {
+            return;
+        }
+        if self.in_head {
+            self.pending_text.clear();
+            return;
+        }
+        let decoded = decode_entities(&self.pending_text);
+        self.pending_text.clear();
+
+        let words: Vec<&str> = decoded.split_whitespace().collect();
+        if words.is_empty() {
+            if !decoded.is_empty() && !self.output.text.ends_with(char::is_whitespace) {
+                self.output.text.push(' ');
+            }
+            return;
+        }
+
+        let out = &mut self.output.text;
+        if decoded.starts_with(char::is_whitespace) && !out.ends_with(char::is_whitespace) {
+            out.push(' ');
+        }
+        out.push_str(&words.join(" "));
+        if decoded.ends_with(char::is_whitespace) {
+            out.push(' ');
+        }
+    }
+}
+
+impl Default for StreamingExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Extract an attribute value from the inside of a start tag
+fn extract_attribute(tag: &str, attribute: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut search_from = 0;
+    while let Some(pos) = lower[search_from..].find(attribute) {
+        let start = search_from + pos;
+        search_from = start + attribute.len();
+        let preceded_by_space = lower[..start].ends_with(char::is_whitespace);
+        let rest = tag[search_from..].trim_start();
+        if !preceded_by_space || !rest.starts_with('=') {
+            continue;
+        }
+        let value = rest[1..].trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value.split_whitespace().next().unwrap_or_default(),
+        };
+        return Some(decode_entities(value));
+    }
+    None
+}
+
+/// Decode numeric character references and the most common named ones in a
+/// single pass, so `&amp;lt;` stays `&lt;`. Unknown references are kept as-is.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let reference = rest[1..]
+            .bytes()
+            .take(MAX_REFERENCE_LEN + 1)
+            .position(|b| b == b';')
+            .and_then(|end| decode_reference(&rest[1..=end]).map(|c| (c, end + 2)));
+        match reference {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Longest character reference name decoded, e.g. `#x10FFFF`
+const MAX_REFERENCE_LEN: usize = 8;
+
+/// Character for the reference between `&` and `;`
+fn decode_reference(reference: &str) -> Option<char> {
+    if let Some(number) = reference.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        // NUL and invalid code points decode to the replacement character
+        return Some(
+            char::from_u32(code)
+                .filter(|&c| c != '\0')
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+        );
+    }
+    match reference {
+        "nbsp" => Some(' '),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "amp" => Some('&'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract_in_chunks(html: &str, chunk_size: usize) -> ExtractedContent {
+        let mut extractor = StreamingExtractor::new();
+        for chunk in html.as_bytes().chunks(chunk_size) {
+            extractor.push(chunk);
+        }
+        extractor.finish()
+    }
+
+    #[test]
+    fn test_extracts_text_and_links() {
+        let html = r#"<html><head><title>T</title><style>p { color: red; }</style></head>
+            <body><h1>Hello &amp; welcome</h1><!-- hidden <a href="/x"> -->
+            <p>See <a href="https://example.com/a">this link</a> and
+            <a href='/relative'>that</a>.</p><script>var x = "<p>no</p>";</script>
+            <p>Done</p></body></html>"#;
+
+        let content = extract_in_chunks(html, html.len());
+        assert_eq!(
+            content.links,
+            vec!["https://example.com/a".to_string(), "/relative".to_string()]
+        );
+        assert!(content.text.contains("Hello & welcome"));
+        assert!(content.text.contains("See this link and that."));
+        assert!(content.text.contains("Done"));
+        assert!(!content.text.contains("color"));
+        assert!(!content.text.contains("no"));
+    }
+
+    #[test]
+    fn test_chunk_boundaries_do_not_change_output() {
+        let html = "<p>Caf\u{e9} <a href=\"https://example.com/\u{e9}\">na\u{ef}ve</a></p><div>\u{1F600} end</div>";
+        let expected = extract_in_chunks(html, html.len());
+        for size in 1..8 {
+            assert_eq!(extract_in_chunks(html, size), expected, "chunk size {size}");
+        }
+        assert_eq!(expected.text, "Caf\u{e9} na\u{ef}ve\n\u{1F600} end");
+    }
+
+    #[test]
+    fn test_head_text_is_skipped() {
+        let html = "<html><head><title>Page title</title><meta charset=utf-8>\
+                    </head><body><p>Body</p><svg><title>Icon</title></svg></body></html>";
+        assert_eq!(extract_in_chunks(html, html.len()).text, "Body");
+
+        // Without `</head>`, the body still ends the head
+        let html = "<head>Stray<body><p>Body</p>";
+        assert_eq!(extract_in_chunks(html, 2).text, "Body");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("It&#39;s &#x2019;quoted&#X2019; &amp;lt; &copy; &#0; & x;"),
+            "It's \u{2019}quoted\u{2019} &lt; &copy; \u{FFFD} & x;"
+        );
+    }
+
+    #[test]
+    fn test_extract_attribute() {
+        assert_eq!(
+            extract_attribute(r#"a class="x" href="/a?b=1&amp;c=2""#, "href"),
+            Some("/a?b=1&c=2".to_string())
+        );
+        assert_eq!(
+            extract_attribute("a data-href=\"/no\" href=/yes", "href"),
+            Some("/yes".to_string())
+        );
+        assert_eq!(extract_attribute("a name=\"x\"", "href"), None);
+    }
+
+    #[test]
+    fn test_less_than_in_script() {
+        let html = "<p>Before</p><script>if (a<b) { x = '</p>'; }</script><p>After</p>";
+        let expected = extract_in_chunks(html, html.len());
+        assert_eq!(expected.text, "Before\nAfter");
+        for size in 1..8 {
+            assert_eq!(extract_in_chunks(html, size), expected, "chunk size {size}");
+        }
+    }
+
+    #[test]
+    fn test_greater_than_in_quoted_attribute() {
+        let html = r#"<p><a title="x>y" href="/link">Link</a> text</p>"#;
+        let content = extract_in_chunks(html, html.len());
+        assert_eq!(content.links, vec!["/link".to_string()]);
+        assert_eq!(content.text, "Link text");
+        assert_eq!(extract_in_chunks(html, 3), content);
+    }
+}
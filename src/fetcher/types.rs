@@ -2,10 +2,14 @@ use crate::constants::{
     FETCHER_MODE_BROWSER_HEAD, FETCHER_MODE_BROWSER_HEADLESS, FETCHER_MODE_HEAD,
     FETCHER_MODE_HEADLESS, FETCHER_MODE_PLAIN, FETCHER_MODE_PLAIN_REQUEST,
 };
+use crate::converter::Format;
 use crate::error::TarziError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
 
 /// Different modes for fetching web content
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FetchMode {
     /// Fetch content using plain HTTP request (no JavaScript rendering)
     PlainRequest,
@@ -22,11 +26,146 @@ impl std::str::FromStr for FetchMode {
         match s.to_lowercase().as_str() {
             FETCHER_MODE_PLAIN_REQUEST | FETCHER_MODE_PLAIN => Ok(FetchMode::PlainRequest),
             FETCHER_MODE_HEAD | FETCHER_MODE_BROWSER_HEAD => Ok(FetchMode::BrowserHead),
-            FETCHER_MODE_HEADLESS | FETCHER_MODE_BROWSER_HEADLESS => Ok(FetchMode::BrowserHeadless),
-            _ => Err(TarziError::InvalidMode(s.to_string())),
+            FETCHER_MODE_HEADLESS | FETCHER_MODE_BROWSER_HEADLESS | "browser" => {
+                Ok(FetchMode::BrowserHeadless)
+            }
+            _ => Err(TarziError::InvalidMode(format!(
+                "'{s}' (expected one of: {FETCHER_MODE_PLAIN_REQUEST}, {FETCHER_MODE_BROWSER_HEAD}, \
+                 {FETCHER_MODE_BROWSER_HEADLESS})"
+            ))),
         }
     }
 }
 
+impl FetchMode {
+    /// The mode's name as written in configuration, e.g. `"plain_request"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FetchMode::PlainRequest => FETCHER_MODE_PLAIN_REQUEST,
+            FetchMode::BrowserHead => FETCHER_MODE_BROWSER_HEAD,
+            FetchMode::BrowserHeadless => FETCHER_MODE_BROWSER_HEADLESS,
+        }
+    }
+}
+
+/// A fetch request with optional per-request settings
+///
+/// Built with [`FetchRequest::new`] and the chained setters, then passed to
+/// [`crate::fetcher::WebFetcher::execute`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchRequest {
+    pub url: String,
+    pub mode: FetchMode,
+    pub format: Format,
+    /// Per-request timeout, overriding the fetcher's default
+    pub timeout: Option<Duration>,
+    /// Proxy to route this request through
+    pub proxy: Option<String>,
+    /// Extra HTTP headers (plain request mode only)
+    pub headers: Vec<(String, String)>,
+    /// CSS selector to wait for, up to the timeout, before extracting content
+    /// (browser modes only); defaults to `fetcher.wait_for_selector`. Replaces the
+    /// fixed page load wait, and the page is still extracted if it never appears.
+    pub wait_for: Option<String>,
+    /// How long to let the page settle after navigation before extracting content
+    /// (browser modes only, without `wait_for`); defaults to
+    /// [`crate::constants::PAGE_LOAD_WAIT`]
+    pub page_load_wait: Option<Duration>,
+    /// JavaScript run on the page once it has loaded, before content is extracted,
+    /// e.g. to click "load more" or dismiss a modal (browser modes only)
+    pub script: Option<String>,
+}
+
+impl FetchRequest {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            mode: FetchMode::BrowserHeadless,
+            format: Format::Markdown,
+            timeout: None,
+            proxy: None,
+            headers: Vec::new(),
+            wait_for: None,
+            page_load_wait: None,
+            script: None,
+        }
+    }
+
+    pub fn mode(mut self, mode: FetchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn headers<I, K, V>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.headers
+            .extend(headers.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    pub fn wait_for(mut self, selector: impl Into<String>) -> Self {
+        self.wait_for = Some(selector.into());
+        self
+    }
+
+    pub fn page_load_wait(mut self, wait: Duration) -> Self {
+        self.page_load_wait = Some(wait);
+        self
+    }
+
+    pub fn script(mut self, script: impl Into<String>) -> Self {
+        self.script = Some(script.into());
+        self
+    }
+}
+
+/// A fetched page with its response metadata, returned regardless of its status code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FetchResponse {
+    /// Final URL after redirects
+    pub url: String,
+    pub status: u16,
+    pub content_type: Option<String>,
+    /// Response headers with lowercase names; repeated headers are joined with `, `.
+    /// Empty for browser fetches, where WebDriver does not expose them.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: String,
+    /// Time taken to fetch the page, excluding any conversion
+    #[serde(default)]
+    pub elapsed: Duration,
+}
+
+impl FetchResponse {
+    /// Whether the status is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
 /// Main WebFetcher type alias for backward compatibility
 pub type WebFetcher = crate::fetcher::webfetcher::WebFetcher;
@@ -1,15 +1,42 @@
 use crate::{
     Result,
-    config::Config,
-    constants::{DEFAULT_TIMEOUT, DEFAULT_USER_AGENT, PAGE_LOAD_WAIT},
+    config::{AuthConfig, Config, FetcherConfig, load_ca_certificate},
+    constants::{
+        DEFAULT_RETRY_BACKOFF_MS, DEFAULT_TIMEOUT, DEFAULT_USER_AGENT, FETCHER_MODE_BROWSER_HEAD,
+        FETCHER_MODE_BROWSER_HEADLESS, FETCHER_MODE_PLAIN_REQUEST, PAGE_LOAD_WAIT,
+        RETRYABLE_STATUS_CODES, SCRIPT_SETTLE_WAIT, SELECTOR_POLL_INTERVAL,
+    },
     converter::{Converter, Format},
     error::TarziError,
 };
+use futures::{StreamExt, stream};
 use reqwest::Client;
+use reqwest::cookie::Jar;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thirtyfour::By;
 use tracing::{error, info, warn};
 use url::Url;
 
-use super::{browser::BrowserManager, types::FetchMode};
+use super::{
+    browser::{BrowserManager, is_page_crash_error, is_session_error},
+    cache::{CacheKey, FetchCache},
+    proxy::{ProxyPool, build_http_proxy, strip_credentials},
+    streaming::{ExtractedContent, StreamingExtractor},
+    types::{FetchMode, FetchRequest, FetchResponse},
+};
+
+/// How a browser fetch waits for and prepares a page before extracting it
+#[derive(Debug, Clone, Copy)]
+struct PageOptions<'a> {
+    /// Bounds navigation, the selector wait, the script and content extraction
+    timeout: Duration,
+    page_load_wait: Duration,
+    wait_for: Option<&'a str>,
+    script: Option<&'a str>,
+}
 
 /// Main web content fetcher
 #[derive(Debug)]
@@ -17,81 +44,741 @@ pub struct WebFetcher {
     http_client: Client,
     browser_manager: BrowserManager,
     converter: Converter,
+    auth: Option<AuthConfig>,
+    /// Hosts `auth` is sent to; requests to other hosts carry no credentials
+    auth_hosts: Vec<String>,
+    cache: Option<FetchCache>,
+    /// Headers sent with every plain request; a request's own headers take precedence
+    headers: HashMap<String, String>,
+    /// Cookie store shared by the HTTP client, when `cookie_store` is enabled
+    cookie_jar: Option<Arc<Jar>>,
+    /// Default timeout for navigation and content extraction, overridable per request
+    timeout: Duration,
+    /// CSS selector browser fetches wait for, unless the request sets its own
+    wait_for_selector: Option<String>,
+    /// Retries for transient plain-request failures; see [`is_retryable`]
+    max_retries: u32,
+    retry_backoff: Duration,
+    /// Proxies requests rotate through, overriding the client's own proxy
+    proxy_pool: ProxyPool,
+    /// A client for each proxy of the pool, built once with the pool
+    proxy_clients: HashMap<String, Client>,
+    /// Settings the HTTP client was built from, reused for clients with another proxy
+    client_config: FetcherConfig,
 }
 
 impl WebFetcher {
     pub fn new() -> Self {
-        info!("Initializing WebFetcher");
-        let http_client = Client::builder()
-            .timeout(DEFAULT_TIMEOUT)
-            .user_agent(DEFAULT_USER_AGENT)
+        Self::with_client(Self::default_http_client())
+    }
+
+    pub fn from_config(config: &Config) -> Self {
+        info!("Initializing WebFetcher from config");
+        let cookie_jar = config
+            .fetcher
+            .cookie_store
+            .then(|| Arc::new(Jar::default()));
+        let mut client_builder = Self::http_client_builder(config);
+        if let Some(jar) = &cookie_jar {
+            info!("Cookie store enabled for plain requests");
+            client_builder = client_builder.cookie_provider(jar.clone());
+        }
+        let http_client = client_builder
             .build()
-            .expect("Failed to create HTTP client");
+            .expect("Failed to create HTTP client from config");
 
-        info!("HTTP client created successfully for WebFetcher");
+        Self::from_config_parts(config, http_client, cookie_jar)
+    }
+
+    /// Create a WebFetcher with default settings that reuses an existing HTTP client
+    pub fn with_client(http_client: Client) -> Self {
+        info!("Initializing WebFetcher");
         Self {
             http_client,
             browser_manager: BrowserManager::new(),
             converter: Converter::new(),
+            auth: None,
+            auth_hosts: Vec::new(),
+            cache: None,
+            headers: HashMap::new(),
+            cookie_jar: None,
+            timeout: DEFAULT_TIMEOUT,
+            wait_for_selector: None,
+            max_retries: 0,
+            retry_backoff: Duration::from_millis(DEFAULT_RETRY_BACKOFF_MS),
+            proxy_pool: ProxyPool::default(),
+            proxy_clients: HashMap::new(),
+            client_config: FetcherConfig::default(),
         }
     }
 
-    pub fn from_config(config: &Config) -> Self {
-        info!("Initializing WebFetcher from config");
-        let mut client_builder = Client::builder()
-            .timeout(std::time::Duration::from_secs(config.fetcher.timeout))
-            .user_agent(&config.fetcher.user_agent);
+    /// Create a WebFetcher from config that reuses an existing HTTP client.
+    /// `cookie_store` is not applied, as cookies are kept by the client itself.
+    pub fn from_config_with_client(config: &Config, http_client: Client) -> Self {
+        Self::from_config_parts(config, http_client, None)
+    }
+
+    fn from_config_parts(
+        config: &Config,
+        http_client: Client,
+        cookie_jar: Option<Arc<Jar>>,
+    ) -> Self {
+        let mut fetcher = Self {
+            http_client,
+            browser_manager: BrowserManager::from_config(config),
+            converter: Converter::from_config(config),
+            auth: config.fetcher.auth.clone(),
+            auth_hosts: config.fetcher.auth_hosts.clone(),
+            cache: None,
+            headers: config.fetcher.headers.clone(),
+            cookie_jar,
+            timeout: Duration::from_secs(config.fetcher.timeout),
+            wait_for_selector: config
+                .fetcher
+                .wait_for_selector
+                .clone()
+                .filter(|selector| !selector.is_empty()),
+            max_retries: config.fetcher.max_retries,
+            retry_backoff: Duration::from_millis(config.fetcher.retry_backoff_ms),
+            proxy_pool: ProxyPool::default(),
+            proxy_clients: HashMap::new(),
+            client_config: config.fetcher.clone(),
+        };
+        if fetcher.auth.is_some() && fetcher.auth_hosts.is_empty() {
+            warn!("auth is configured without auth_hosts, so no request will carry it");
+        }
+        fetcher.set_proxy_pool(config.fetcher.proxy_pool.clone());
+        fetcher
+    }
+
+    /// HTTP client with the default timeout and user agent, as used by [`Self::new`]
+    pub(crate) fn default_http_client() -> Client {
+        Client::builder()
+            .timeout(DEFAULT_TIMEOUT)
+            .user_agent(DEFAULT_USER_AGENT)
+            .build()
+            .expect("Failed to create HTTP client")
+    }
+
+    /// Build the HTTP client described by the fetcher configuration.
+    /// `reqwest::Client` is reference counted, so clones share one connection pool.
+    pub fn build_http_client(config: &Config) -> Client {
+        Self::http_client_builder(config)
+            .build()
+            .expect("Failed to create HTTP client from config")
+    }
+
+    fn http_client_builder(config: &Config) -> reqwest::ClientBuilder {
+        let mut client_builder = Self::base_client_builder(&config.fetcher);
 
         // Use environment variables for proxy with fallback to config
         let proxy = crate::config::get_proxy_from_env_or_config(&config.fetcher.proxy);
-        if let Some(proxy) = proxy {
-            if !proxy.is_empty() {
-                if let Ok(proxy_obj) = reqwest::Proxy::http(&proxy) {
+        if let Some(proxy) = proxy
+            && !proxy.is_empty()
+        {
+            match build_http_proxy(&proxy) {
+                Ok(proxy_obj) => {
                     client_builder = client_builder.proxy(proxy_obj);
-                    info!("Using proxy from environment/config: {}", proxy);
-                } else {
-                    warn!("Invalid proxy configuration: {}", proxy);
+                    info!(
+                        "Using proxy from environment/config: {}",
+                        strip_credentials(&proxy)
+                    );
                 }
+                Err(_) => warn!("Invalid proxy configuration: {}", strip_credentials(&proxy)),
             }
         }
 
-        let http_client = client_builder
-            .build()
-            .expect("Failed to create HTTP client from config");
-        Self {
-            http_client,
-            browser_manager: BrowserManager::from_config(config),
-            converter: Converter::new(),
+        client_builder
+    }
+
+    /// Client settings besides the proxy: timeout, user agent, TLS and host overrides
+    fn base_client_builder(fetcher: &FetcherConfig) -> reqwest::ClientBuilder {
+        let mut client_builder = Client::builder()
+            .timeout(Duration::from_secs(fetcher.timeout))
+            .user_agent(&fetcher.user_agent);
+
+        if fetcher.danger_accept_invalid_certs {
+            warn!("TLS certificate verification is disabled for the HTTP client");
+            client_builder = client_builder.danger_accept_invalid_certs(true);
+        }
+
+        if let Some(ca_cert_path) = &fetcher.ca_cert_path {
+            match load_ca_certificate(ca_cert_path) {
+                Ok(cert) => {
+                    client_builder = client_builder.add_root_certificate(cert);
+                    info!("Using custom CA certificate: {}", ca_cert_path);
+                }
+                // Configs loaded from a file are validated; ones built in code may not be
+                Err(e) => error!("{}, skipping it", e),
+            }
+        }
+
+        for (host, addr) in &fetcher.host_overrides {
+            // reqwest ignores this port and connects to the one in the URL
+            client_builder = client_builder.resolve(host, std::net::SocketAddr::new(*addr, 0));
+            info!("Resolving {} to {}", host, addr);
+        }
+
+        client_builder
+    }
+
+    /// Get the underlying HTTP client
+    pub fn http_client(&self) -> &Client {
+        &self.http_client
+    }
+
+    /// Set the HTTP authentication used for plain-request fetches of the hosts
+    /// given to [`Self::set_auth_hosts`]
+    pub fn set_auth(&mut self, auth: Option<AuthConfig>) {
+        self.auth = auth;
+    }
+
+    /// Set the hosts the authentication is sent to, e.g. `api.example.com`
+    pub fn set_auth_hosts(&mut self, hosts: Vec<String>) {
+        self.auth_hosts = hosts;
+    }
+
+    /// Seed the cookie store with a `Set-Cookie` style cookie (e.g.
+    /// `"session=abc; Path=/"`) for `url`, sent on later plain requests it matches.
+    /// Requires `[fetcher] cookie_store = true`.
+    pub fn set_cookie(&mut self, url: &str, cookie: &str) -> Result<()> {
+        let jar = self.cookie_jar.as_ref().ok_or_else(|| {
+            TarziError::Config(
+                "Cookie store is disabled; set cookie_store = true in [fetcher]".to_string(),
+            )
+        })?;
+        jar.add_cookie_str(cookie, &Url::parse(url)?);
+        Ok(())
+    }
+
+    /// Add headers sent with every plain request, replacing earlier values for the
+    /// same names. Headers set on a [`FetchRequest`] take precedence over these.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Rotate through `pool`, one proxy per request, instead of the configured
+    /// `proxy`. A proxy that can't be reached is skipped for a while and the request
+    /// retried through the next one. A request's own proxy takes precedence.
+    pub fn with_proxy_pool(mut self, pool: Vec<String>) -> Self {
+        self.set_proxy_pool(pool);
+        self
+    }
+
+    /// Build a client for each proxy of `pool` and rotate through them. Invalid
+    /// proxy URLs are left out of the pool.
+    fn set_proxy_pool(&mut self, pool: Vec<String>) {
+        let mut valid = Vec::new();
+        let mut clients = HashMap::new();
+        for proxy in ProxyPool::new(pool).proxies() {
+            if let Ok(client) = self.proxy_client(proxy) {
+                valid.push(proxy.clone());
+                clients.insert(proxy.clone(), client);
+            }
+        }
+        self.proxy_pool = ProxyPool::new(valid);
+        self.proxy_clients = clients;
+    }
+
+    /// Share `cache` with this fetcher; fetchers given clones of one cache read and
+    /// fill the same entries
+    pub fn with_shared_cache(mut self, cache: FetchCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Attach or detach the fetch cache
+    pub fn set_cache(&mut self, cache: Option<FetchCache>) {
+        self.cache = cache;
+    }
+
+    /// Get the fetch cache, if one is attached
+    pub fn cache(&self) -> Option<&FetchCache> {
+        self.cache.as_ref()
+    }
+
+    /// Cache key for `request`, or `None` when there is no cache or the response
+    /// may depend on this fetcher's credentials, cookies or headers, which a
+    /// shared cache must not hand to other fetchers
+    fn cache_key(&self, request: &FetchRequest) -> Option<CacheKey> {
+        if self.cache.is_none()
+            || self.auth.is_some()
+            || self.cookie_jar.is_some()
+            || !self.headers.is_empty()
+        {
+            return None;
+        }
+        FetchCache::key(request)
+    }
+
+    /// Build a client that routes requests through `proxy`, with the same settings
+    /// as the fetcher's own client and sharing its cookie store if it has one
+    fn proxy_client(&self, proxy: &str) -> Result<Client> {
+        let proxy_config = build_http_proxy(proxy).inspect_err(|e| {
+            warn!("Invalid proxy URL '{}': {}", strip_credentials(proxy), e);
+        })?;
+        let mut client_builder = Self::base_client_builder(&self.client_config).proxy(proxy_config);
+        if let Some(jar) = &self.cookie_jar {
+            client_builder = client_builder.cookie_provider(jar.clone());
+        }
+        client_builder.build().map_err(|e| {
+            warn!(
+                "Failed to create HTTP client with proxy '{}': {}",
+                strip_credentials(proxy),
+                e
+            );
+            TarziError::Config(format!("Failed to create proxy client: {e}"))
+        })
+    }
+
+    /// Authentication to send to `url`: the configured one if its host is one of
+    /// `auth_hosts`, so credentials never reach other hosts
+    fn auth_for(&self, url: &Url) -> Option<&AuthConfig> {
+        let host = url.host_str()?;
+        self.auth.as_ref().filter(|_| {
+            self.auth_hosts
+                .iter()
+                .any(|auth_host| auth_host.eq_ignore_ascii_case(host))
+        })
+    }
+
+    /// Attach configured authentication to an outgoing plain request to `url`
+    fn apply_auth(&self, request: reqwest::RequestBuilder, url: &Url) -> reqwest::RequestBuilder {
+        match self.auth_for(url) {
+            Some(AuthConfig::Basic { username, password }) => {
+                request.basic_auth(username, password.as_ref())
+            }
+            Some(AuthConfig::Bearer { token }) => request.bearer_auth(token),
+            None => request,
         }
     }
 
     /// Fetch content from URL and convert to specified format
     pub async fn fetch(&mut self, url: &str, mode: FetchMode, format: Format) -> Result<String> {
-        let raw_content = self.fetch_raw(url, mode).await?;
-        let converted_content = self.converter.convert(&raw_content, format).await?;
-        Ok(converted_content)
+        self.execute(FetchRequest::new(url).mode(mode).format(format))
+            .await
+    }
+
+    /// Fetch `url` in a browser mode, running `script` on the loaded page before
+    /// extracting and converting its content, e.g. to click "load more" or dismiss
+    /// a modal. Only the two browser modes can run scripts; plain requests fail
+    /// with [`TarziError::InvalidMode`], and a failing script with
+    /// [`TarziError::Browser`].
+    pub async fn fetch_with_script(
+        &mut self,
+        url: &str,
+        mode: FetchMode,
+        script: &str,
+        format: Format,
+    ) -> Result<String> {
+        self.execute(
+            FetchRequest::new(url)
+                .mode(mode)
+                .script(script)
+                .format(format),
+        )
+        .await
     }
 
     /// Get raw content without conversion (for internal use)
     pub async fn fetch_raw(&mut self, url: &str, mode: FetchMode) -> Result<String> {
-        match mode {
-            FetchMode::PlainRequest => self.fetch_plain_request(url).await,
-            FetchMode::BrowserHead => self.fetch_with_browser(url, false).await,
-            FetchMode::BrowserHeadless => self.fetch_with_browser(url, true).await,
+        self.execute_raw(&FetchRequest::new(url).mode(mode)).await
+    }
+
+    /// Fetch `url` with a plain request and convert it to `format`, like
+    /// [`Self::fetch`] in [`FetchMode::PlainRequest`]. Only needs `&self`, so
+    /// several plain fetches can run concurrently on one fetcher.
+    pub async fn fetch_plain(&self, url: &str, format: Format) -> Result<String> {
+        let request = FetchRequest::new(url)
+            .mode(FetchMode::PlainRequest)
+            .format(format);
+        let key = self.cache_key(&request);
+        let cached = match (&self.cache, &key) {
+            (Some(cache), Some(key)) => cache.get(key),
+            _ => None,
+        };
+        let (content, content_type) = match cached {
+            Some(content) => {
+                info!("Serving {} from the fetch cache", url);
+                (content, None)
+            }
+            None => {
+                let (content, content_type) = self.fetch_plain_request(&request).await?;
+                if let (Some(cache), Some(key)) = (&self.cache, key) {
+                    cache.insert(key, content.clone());
+                }
+                (content, content_type)
+            }
+        };
+        self.converter
+            .convert_response(&content, content_type.as_deref(), format, url)
+            .await
+    }
+
+    /// Fetch each of `urls` and convert it to `format`, returning one result per
+    /// URL in input order so a failing URL doesn't abort the batch. Plain requests
+    /// run up to `concurrency` at once; browser fetches drive one browser session
+    /// and run one at a time.
+    pub async fn fetch_many(
+        &mut self,
+        urls: &[String],
+        mode: FetchMode,
+        format: Format,
+        concurrency: usize,
+    ) -> Vec<Result<String>> {
+        if mode == FetchMode::PlainRequest {
+            // `buffer_unordered` yields fetches as they complete, so results carry
+            // their input position and are put back in order afterwards
+            let fetcher = &*self;
+            let mut results: Vec<(usize, Result<String>)> = stream::iter(urls.iter().enumerate())
+                .map(|(index, url)| async move { (index, fetcher.fetch_plain(url, format).await) })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+            results.sort_by_key(|(index, _)| *index);
+            return results.into_iter().map(|(_, result)| result).collect();
+        }
+
+        let mut results = Vec::with_capacity(urls.len());
+        for url in urls {
+            results.push(self.fetch(url, mode, format).await);
         }
+        results
     }
 
-    /// Fetch raw content using plain HTTP request (no JS rendering)
-    async fn fetch_plain_request(&self, url: &str) -> Result<String> {
-        let url = Url::parse(url)?;
-        let response = self.http_client.get(url).send().await?;
-        let response = response.error_for_status()?;
+    /// Execute a [`FetchRequest`] and convert the content to the requested format.
+    /// Plain requests are converted according to their `Content-Type`, so JSON and
+    /// plain-text responses are not treated as HTML (see [`Converter::convert_response`]).
+    pub async fn execute(&mut self, request: FetchRequest) -> Result<String> {
+        let (raw_content, content_type) = self.execute_typed(&request).await?;
+        let converted_content = self
+            .converter
+            .convert_response(
+                &raw_content,
+                content_type.as_deref(),
+                request.format,
+                &request.url,
+            )
+            .await?;
+        Ok(converted_content)
+    }
+
+    /// Execute a [`FetchRequest`] and return the raw content without conversion.
+    /// With a cache attached, content already fetched for the same request is reused.
+    pub async fn execute_raw(&mut self, request: &FetchRequest) -> Result<String> {
+        Ok(self.execute_typed(request).await?.0)
+    }
+
+    /// Raw content and, for plain requests, its `Content-Type`. Cached content has
+    /// no content type, so its kind is detected from the content itself.
+    async fn execute_typed(&mut self, request: &FetchRequest) -> Result<(String, Option<String>)> {
+        let key = self.cache_key(request);
+        if let (Some(cache), Some(key)) = (&self.cache, &key)
+            && let Some(content) = cache.get(key)
+        {
+            info!("Serving {} from the fetch cache", request.url);
+            return Ok((content, None));
+        }
+
+        let (content, content_type) = self.execute_uncached(request).await?;
+        if let (Some(cache), Some(key)) = (&self.cache, key) {
+            cache.insert(key, content.clone());
+        }
+        Ok((content, content_type))
+    }
+
+    async fn execute_uncached(
+        &mut self,
+        request: &FetchRequest,
+    ) -> Result<(String, Option<String>)> {
+        match (request.mode, &request.proxy) {
+            (FetchMode::PlainRequest, _) if request.script.is_some() => {
+                Err(TarziError::InvalidMode(format!(
+                    "{FETCHER_MODE_PLAIN_REQUEST} cannot run scripts; use \
+                     {FETCHER_MODE_BROWSER_HEAD} or {FETCHER_MODE_BROWSER_HEADLESS}"
+                )))
+            }
+            (FetchMode::PlainRequest, _) => self.fetch_plain_request(request).await,
+            (mode, proxy) => {
+                if !request.headers.is_empty() {
+                    warn!("Custom headers are not supported in browser mode, ignoring");
+                }
+                let headless = matches!(mode, FetchMode::BrowserHeadless);
+                let wait_for = request
+                    .wait_for
+                    .clone()
+                    .or_else(|| self.wait_for_selector.clone());
+                let page = PageOptions {
+                    timeout: request.timeout.unwrap_or(self.timeout),
+                    page_load_wait: request.page_load_wait.unwrap_or(PAGE_LOAD_WAIT),
+                    wait_for: wait_for.as_deref(),
+                    script: request.script.as_deref(),
+                };
+                match proxy.clone().or_else(|| self.proxy_pool.next_proxy()) {
+                    Some(proxy) => self
+                        .fetch_with_proxy_browser(&request.url, &proxy, headless, page)
+                        .await
+                        .map(|content| (content, None)),
+                    // The browser renders any content type as a page, so it is always HTML
+                    None => self
+                        .fetch_with_browser(&request.url, headless, page)
+                        .await
+                        .map(|response| (response.body, None)),
+                }
+            }
+        }
+    }
+
+    /// Fetch raw content and its content type using plain HTTP request (no JS rendering),
+    /// retrying transient failures with exponential backoff up to `max_retries` times
+    async fn fetch_plain_request(
+        &self,
+        request: &FetchRequest,
+    ) -> Result<(String, Option<String>)> {
+        self.with_retries(&request.url, || self.fetch_plain_request_once(request))
+            .await
+    }
+
+    /// Run a plain-request `operation`, retrying it on transient failures (see
+    /// [`is_retryable`]) with exponential backoff up to `max_retries` times
+    async fn with_retries<T, F, Fut>(&self, url: &str, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match operation().await {
+                Err(e) if attempt < self.max_retries && is_retryable(&e) => {
+                    // Wait at least as long as a rate limiting server asked
+                    let backoff = self.retry_backoff * 2u32.saturating_pow(attempt);
+                    let delay = match e {
+                        TarziError::RateLimited {
+                            retry_after: Some(retry_after),
+                        } => backoff.max(retry_after),
+                        _ => backoff,
+                    };
+                    attempt += 1;
+                    warn!(
+                        "Plain request to {} failed ({}), retry {}/{} in {:?}",
+                        url, e, attempt, self.max_retries, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn fetch_plain_request_once(
+        &self,
+        request: &FetchRequest,
+    ) -> Result<(String, Option<String>)> {
+        let response = self.send_checked(request).await?;
+        let content_type = content_type_of(&response);
         let content = response.text().await?;
-        Ok(content)
+        Ok((content, content_type))
+    }
+
+    /// Fetch with a plain request and return the status and body even for 4xx/5xx
+    /// responses, e.g. to inspect an API's error payload or a diagnostic error page.
+    /// The status is only observable over plain HTTP, so `mode` and `format` of the
+    /// request are ignored and the body is returned unconverted.
+    pub async fn fetch_with_meta(&self, request: &FetchRequest) -> Result<FetchResponse> {
+        let started = Instant::now();
+        let response = self.send_plain_request(request).await?;
+        let url = response.url().to_string();
+        let status = response.status().as_u16();
+        let content_type = content_type_of(&response);
+        let mut headers: HashMap<String, String> = HashMap::new();
+        for (name, value) in response.headers() {
+            let value = String::from_utf8_lossy(value.as_bytes());
+            headers
+                .entry(name.as_str().to_string())
+                .and_modify(|joined| {
+                    joined.push_str(", ");
+                    joined.push_str(&value);
+                })
+                .or_insert_with(|| value.into_owned());
+        }
+        let body = response.text().await?;
+
+        Ok(FetchResponse {
+            url,
+            status,
+            content_type,
+            headers,
+            body,
+            elapsed: started.elapsed(),
+        })
     }
 
-    /// Fetch content using browser (with or without headless mode)
-    async fn fetch_with_browser(&mut self, url: &str, headless: bool) -> Result<String> {
+    /// Fetch `url` and return the raw body with the final URL, status and headers.
+    /// Plain requests report everything; browser fetches report the final URL,
+    /// the status where the browser exposes it (200 otherwise) and the document's
+    /// content type, but no headers.
+    pub async fn fetch_response(&mut self, url: &str, mode: FetchMode) -> Result<FetchResponse> {
+        match mode {
+            FetchMode::PlainRequest => self.fetch_with_meta(&FetchRequest::new(url)).await,
+            mode => {
+                let started = Instant::now();
+                let headless = matches!(mode, FetchMode::BrowserHeadless);
+                let wait_for = self.wait_for_selector.clone();
+                let page = PageOptions {
+                    timeout: self.timeout,
+                    page_load_wait: PAGE_LOAD_WAIT,
+                    wait_for: wait_for.as_deref(),
+                    script: None,
+                };
+                let mut response = self.fetch_with_browser(url, headless, page).await?;
+                response.elapsed = started.elapsed();
+                Ok(response)
+            }
+        }
+    }
+
+    /// Fetch `url` and convert it to `format`, keeping the response metadata: the
+    /// final URL after redirects (e.g. to spot a soft redirect to a login page),
+    /// the status, the content type and the time the fetch took. The converted
+    /// content replaces the raw body, with links resolved against the final URL.
+    /// Like [`Self::fetch_response`], error statuses are returned rather than
+    /// failing, so check [`FetchResponse::is_success`].
+    pub async fn fetch_detailed(
+        &mut self,
+        url: &str,
+        mode: FetchMode,
+        format: Format,
+    ) -> Result<FetchResponse> {
+        let mut response = self.fetch_response(url, mode).await?;
+        response.body = self
+            .converter
+            .convert_response(
+                &response.body,
+                response.content_type.as_deref(),
+                format,
+                &response.url,
+            )
+            .await?;
+        Ok(response)
+    }
+
+    /// Send a plain GET request with the request's proxy, auth, headers and timeout.
+    /// The timeout is always set on the request so it takes precedence over the
+    /// client's own, including for the per-request proxy client.
+    async fn send_plain_request(&self, request: &FetchRequest) -> Result<reqwest::Response> {
+        match &request.proxy {
+            Some(proxy) => {
+                let client = self.proxy_client(proxy)?;
+                self.send_with_client(&client, request).await
+            }
+            None if !self.proxy_pool.is_empty() => self.send_through_proxy_pool(request).await,
+            None => self.send_with_client(&self.http_client, request).await,
+        }
+    }
+
+    /// Send a plain request through the next proxy of the pool. Proxies that can't
+    /// be reached are marked dead and the request is sent through the next one,
+    /// trying each proxy at most once.
+    async fn send_through_proxy_pool(&self, request: &FetchRequest) -> Result<reqwest::Response> {
+        let mut last_error = None;
+        for _ in 0..self.proxy_pool.len() {
+            let Some(proxy) = self.proxy_pool.next_proxy() else {
+                break;
+            };
+            let client = self.proxy_clients.get(&proxy).ok_or_else(|| {
+                TarziError::Config(format!("No client for proxy {}", strip_credentials(&proxy)))
+            })?;
+            match self.send_with_client(client, request).await {
+                Err(TarziError::Http(e)) if e.is_connect() => {
+                    warn!(
+                        "Proxy {} is unreachable ({}), trying the next one",
+                        strip_credentials(&proxy),
+                        e
+                    );
+                    self.proxy_pool.mark_dead(&proxy);
+                    last_error = Some(TarziError::Http(e));
+                }
+                result => return result,
+            }
+        }
+        Err(last_error.unwrap_or_else(|| TarziError::Config("The proxy pool is empty".to_string())))
+    }
+
+    async fn send_with_client(
+        &self,
+        client: &Client,
+        request: &FetchRequest,
+    ) -> Result<reqwest::Response> {
+        let url = Url::parse(&request.url)?;
+        if let Some(auth) = self.auth_for(&url) {
+            info!("Using {} auth for plain request", auth.scheme());
+        }
+
+        let mut headers = header_map(&self.headers)?;
+        // Replace the fetcher's values for headers the request sets itself
+        headers.extend(header_map(request.headers.iter().map(|(k, v)| (k, v)))?);
+        let mut builder = self
+            .apply_auth(client.get(url.clone()), &url)
+            .headers(headers);
+        builder = builder.timeout(request.timeout.unwrap_or(self.timeout));
+
+        Ok(builder.send().await?)
+    }
+
+    /// Fetch a page with a plain request and extract its text and links in a single
+    /// streaming pass. The response body is fed to the extractor chunk by chunk,
+    /// so memory use does not grow with the size of the raw page. The request goes
+    /// through the same proxy, auth, headers, timeout and retries as [`Self::fetch`].
+    pub async fn fetch_extract_streaming(&self, url: &str) -> Result<ExtractedContent> {
+        let request = FetchRequest::new(url).mode(FetchMode::PlainRequest);
+        self.with_retries(url, || self.fetch_extract_streaming_once(&request))
+            .await
+    }
+
+    async fn fetch_extract_streaming_once(
+        &self,
+        request: &FetchRequest,
+    ) -> Result<ExtractedContent> {
+        let response = self.send_checked(request).await?;
+        extract_streaming(response).await
+    }
+
+    /// Send a plain request and turn rate limiting and other error statuses into errors
+    async fn send_checked(&self, request: &FetchRequest) -> Result<reqwest::Response> {
+        let response = self.send_plain_request(request).await?;
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(TarziError::rate_limited(response.headers()));
+        }
+        Ok(response.error_for_status()?)
+    }
+
+    /// Fetch content using browser (with or without headless mode), recreating the
+    /// browser session once if it died mid-run
+    async fn fetch_with_browser(
+        &mut self,
+        url: &str,
+        headless: bool,
+        page: PageOptions<'_>,
+    ) -> Result<FetchResponse> {
+        match self.fetch_with_browser_session(url, headless, page).await {
+            Err(e) if is_session_error(&e) => {
+                // The session or driver died: recreate it once before giving up
+                warn!("Browser session failed ({}), retrying on a new session", e);
+                self.browser_manager.recover().await;
+                self.fetch_with_browser_session(url, headless, page).await
+            }
+            result => result,
+        }
+    }
+
+    /// Fetch content with the current browser session, creating one if needed
+    async fn fetch_with_browser_session(
+        &mut self,
+        url: &str,
+        headless: bool,
+        page: PageOptions<'_>,
+    ) -> Result<FetchResponse> {
         info!(
             "Fetching URL with browser (headless: {}): {}",
             headless, url
@@ -99,12 +786,34 @@ impl WebFetcher {
 
         // Get or create browser instance
         info!("Getting or creating browser instance...");
+        let reuse_page = self.browser_manager.reuse_page();
         let browser = self.browser_manager.get_or_create_browser(headless).await?;
         info!("Using existing browser instance for fetching");
 
+        BrowserManager::prepare_page(browser, reuse_page).await?;
+        let result = match WebFetcher::load_page(browser, url, page).await {
+            Err(e) if reuse_page && is_page_crash_error(&e) => {
+                // Only the reused page died; the session is fine, so replace the page
+                warn!("Browser page crashed ({}), retrying on a new page", e);
+                BrowserManager::open_page(browser).await?;
+                WebFetcher::load_page(browser, url, page).await
+            }
+            result => result,
+        };
+        BrowserManager::release_page(browser, reuse_page).await;
+        result
+    }
+
+    /// Navigate the browser's current page to `url` and extract its content
+    async fn load_page(
+        browser: &thirtyfour::WebDriver,
+        url: &str,
+        page: PageOptions<'_>,
+    ) -> Result<FetchResponse> {
+        let timeout = page.timeout;
         // Navigate to the URL
         info!("Navigating to URL: {}", url);
-        let navigation_result = tokio::time::timeout(DEFAULT_TIMEOUT, browser.get(url)).await;
+        let navigation_result = tokio::time::timeout(timeout, browser.get(url)).await;
 
         match navigation_result {
             Ok(Ok(_)) => {
@@ -125,17 +834,15 @@ impl WebFetcher {
                 return Err(TarziError::Browser(error_msg));
             }
             Err(_) => {
-                error!("Timeout while navigating to URL (30 seconds)");
+                let secs = timeout.as_secs();
+                error!("Timeout while navigating to URL ({} seconds)", secs);
                 return Err(TarziError::Browser(format!(
-                    "Timeout while navigating to {url} (30 seconds). The page may be slow to load or the site may be experiencing issues."
+                    "Timeout while navigating to {url} ({secs} seconds). The page may be slow to load or the site may be experiencing issues."
                 )));
             }
         }
 
-        // Wait for the page to load (simplified approach)
-        info!("Waiting for page to load (2 seconds)...");
-        tokio::time::sleep(PAGE_LOAD_WAIT).await;
-        info!("Wait completed");
+        WebFetcher::prepare_content(browser, url, page).await?;
 
         // Get the page content (prefer dynamic DOM via JS execution, fallback to page source)
         info!("Extracting page content (dynamic DOM if available)...");
@@ -146,7 +853,7 @@ impl WebFetcher {
                     "Falling back to page source due to error getting dynamic DOM: {}",
                     e
                 );
-                let content_result = tokio::time::timeout(DEFAULT_TIMEOUT, browser.source()).await;
+                let content_result = tokio::time::timeout(timeout, browser.source()).await;
                 match content_result {
                     Ok(Ok(content)) => content,
                     Ok(Err(e)) => {
@@ -154,7 +861,10 @@ impl WebFetcher {
                         return Err(TarziError::Browser(format!("Failed to get content: {e}")));
                     }
                     Err(_) => {
-                        error!("Timeout while extracting page content (30 seconds)");
+                        error!(
+                            "Timeout while extracting page content ({} seconds)",
+                            timeout.as_secs()
+                        );
                         return Err(TarziError::Browser(
                             "Timeout while extracting page content".to_string(),
                         ));
@@ -167,7 +877,33 @@ impl WebFetcher {
             "Successfully extracted page content ({} characters)",
             content.len()
         );
-        Ok(content)
+        Ok(WebFetcher::page_response(browser, url, content).await)
+    }
+
+    /// Wrap content loaded in the browser with the page's final URL, HTTP status
+    /// (from the Navigation Timing API, where supported) and content type
+    async fn page_response(
+        browser: &thirtyfour::WebDriver,
+        url: &str,
+        body: String,
+    ) -> FetchResponse {
+        let script = "const nav = performance.getEntriesByType('navigation')[0];\
+            return [location.href, (nav && nav.responseStatus) || 0, document.contentType];";
+        let meta = browser
+            .execute(script, Vec::<serde_json::Value>::new())
+            .await
+            .ok()
+            .and_then(|ret| ret.convert::<(String, u16, Option<String>)>().ok());
+        let (url, status, content_type) = meta.unwrap_or_else(|| (url.to_string(), 0, None));
+
+        FetchResponse {
+            url,
+            status: if status == 0 { 200 } else { status },
+            content_type,
+            headers: HashMap::new(),
+            body,
+            elapsed: Duration::ZERO,
+        }
     }
 
     /// Fetch content using proxy
@@ -178,128 +914,106 @@ impl WebFetcher {
         mode: FetchMode,
         format: Format,
     ) -> Result<String> {
-        info!("Fetching URL with proxy: {} (proxy: {})", url, proxy);
-
-        let raw_content = match mode {
-            FetchMode::PlainRequest => {
-                let proxy_client = match reqwest::Proxy::http(proxy) {
-                    Ok(proxy_config) => {
-                        match Client::builder()
-                            .timeout(DEFAULT_TIMEOUT)
-                            .user_agent(DEFAULT_USER_AGENT)
-                            .proxy(proxy_config)
-                            .build()
-                        {
-                            Ok(client) => client,
-                            Err(e) => {
-                                warn!(
-                                    "Failed to create HTTP client with proxy '{}': {}. Falling back to no proxy.",
-                                    proxy, e
-                                );
-                                return Err(TarziError::Config(format!(
-                                    "Failed to create proxy client: {e}"
-                                )));
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        warn!(
-                            "Invalid proxy URL '{}': {}. Falling back to no proxy.",
-                            proxy, e
-                        );
-                        return Err(TarziError::Config(format!("Invalid proxy URL: {e}")));
-                    }
-                };
+        self.execute(
+            FetchRequest::new(url)
+                .mode(mode)
+                .format(format)
+                .proxy(proxy),
+        )
+        .await
+    }
+
+    /// Fetch raw content through a proxy using a dedicated browser instance
+    async fn fetch_with_proxy_browser(
+        &mut self,
+        url: &str,
+        proxy: &str,
+        headless: bool,
+        page: PageOptions<'_>,
+    ) -> Result<String> {
+        let timeout = page.timeout;
+        info!(
+            "Fetching URL with proxy: {} (proxy: {})",
+            url,
+            strip_credentials(proxy)
+        );
 
-                let url = Url::parse(url)?;
-                let response = proxy_client.get(url).send().await?;
-                let response = response.error_for_status()?;
-                response.text().await?
+        // For browser modes with proxy, create a new browser instance with proxy configuration
+        info!(
+            "Creating browser with proxy for fetching: {}",
+            strip_credentials(proxy)
+        );
+        let instance_id = self
+            .browser_manager
+            .create_browser_with_proxy(
+                None,
+                headless,
+                Some("proxy_browser".to_string()),
+                Some(proxy.to_string()),
+            )
+            .await?;
+
+        // Get the browser instance and fetch content
+        let browser = self
+            .browser_manager
+            .get_browser(&instance_id)
+            .ok_or_else(|| {
+                TarziError::Browser("Failed to get proxy browser instance".to_string())
+            })?;
+
+        // Navigate to URL
+        let navigation_result = tokio::time::timeout(timeout, browser.get(url)).await;
+        match navigation_result {
+            Ok(Ok(_)) => info!("Successfully navigated to page with proxy"),
+            Ok(Err(e)) => {
+                error!("Failed to navigate to URL with proxy: {}", e);
+                return Err(TarziError::Browser(format!(
+                    "Failed to navigate with proxy: {e}"
+                )));
             }
-            FetchMode::BrowserHead | FetchMode::BrowserHeadless => {
-                // For browser modes with proxy, create a new browser instance with proxy configuration
-                info!("Creating browser with proxy for fetching: {}", proxy);
-                let headless = matches!(mode, FetchMode::BrowserHeadless);
-                let instance_id = self
-                    .browser_manager
-                    .create_browser_with_proxy(
-                        None,
-                        headless,
-                        Some("proxy_browser".to_string()),
-                        Some(proxy.to_string()),
-                    )
-                    .await?;
-
-                // Get the browser instance and fetch content
-                let browser = self
-                    .browser_manager
-                    .get_browser(&instance_id)
-                    .ok_or_else(|| {
-                        TarziError::Browser("Failed to get proxy browser instance".to_string())
-                    })?;
-
-                // Navigate to URL
-                let navigation_result =
-                    tokio::time::timeout(DEFAULT_TIMEOUT, browser.get(url)).await;
-                match navigation_result {
-                    Ok(Ok(_)) => info!("Successfully navigated to page with proxy"),
+            Err(_) => {
+                error!("Timeout while navigating to URL with proxy");
+                return Err(TarziError::Browser(
+                    "Timeout while navigating with proxy".to_string(),
+                ));
+            }
+        }
+
+        WebFetcher::prepare_content(browser, url, page).await?;
+
+        // Get page content (prefer dynamic DOM via JS execution, fallback to page source)
+        let content = match WebFetcher::get_outer_html_from(browser).await {
+            Ok(html) => html,
+            Err(e) => {
+                warn!(
+                    "Falling back to page source (proxy) due to error getting dynamic DOM: {}",
+                    e
+                );
+                let content_result = tokio::time::timeout(timeout, browser.source()).await;
+                match content_result {
+                    Ok(Ok(content)) => content,
                     Ok(Err(e)) => {
-                        error!("Failed to navigate to URL with proxy: {}", e);
+                        error!("Failed to get page content with proxy: {}", e);
                         return Err(TarziError::Browser(format!(
-                            "Failed to navigate with proxy: {e}"
+                            "Failed to get content with proxy: {e}"
                         )));
                     }
                     Err(_) => {
-                        error!("Timeout while navigating to URL with proxy");
+                        error!("Timeout while extracting page content with proxy");
                         return Err(TarziError::Browser(
-                            "Timeout while navigating with proxy".to_string(),
+                            "Timeout while extracting content with proxy".to_string(),
                         ));
-                    }
-                }
-
-                // Wait for page load
-                tokio::time::sleep(PAGE_LOAD_WAIT).await;
-
-                // Get page content (prefer dynamic DOM via JS execution, fallback to page source)
-                let content = match WebFetcher::get_outer_html_from(browser).await {
-                    Ok(html) => html,
-                    Err(e) => {
-                        warn!(
-                            "Falling back to page source (proxy) due to error getting dynamic DOM: {}",
-                            e
-                        );
-                        let content_result =
-                            tokio::time::timeout(DEFAULT_TIMEOUT, browser.source()).await;
-                        match content_result {
-                            Ok(Ok(content)) => content,
-                            Ok(Err(e)) => {
-                                error!("Failed to get page content with proxy: {}", e);
-                                return Err(TarziError::Browser(format!(
-                                    "Failed to get content with proxy: {e}"
-                                )));
-                            }
-                            Err(_) => {
-                                error!("Timeout while extracting page content with proxy");
-                                return Err(TarziError::Browser(
-                                    "Timeout while extracting content with proxy".to_string(),
-                                ));
-                            }
-                        }
-                    }
-                };
-
-                // Clean up the proxy browser instance
-                if let Err(e) = self.browser_manager.remove_browser(&instance_id).await {
-                    warn!("Failed to cleanup proxy browser instance: {}", e);
+                    }
                 }
-
-                content
             }
         };
 
-        // Convert to specified format
-        let converted_content = self.converter.convert(&raw_content, format).await?;
-        Ok(converted_content)
+        // Clean up the proxy browser instance
+        if let Err(e) = self.browser_manager.remove_browser(&instance_id).await {
+            warn!("Failed to cleanup proxy browser instance: {}", e);
+        }
+
+        Ok(content)
     }
 
     /// Create a new browser instance with a specific user data directory
@@ -370,7 +1084,7 @@ impl WebFetcher {
             "Navigating to URL in browser instance {}: {}",
             instance_id, url
         );
-        let navigation_result = tokio::time::timeout(DEFAULT_TIMEOUT, browser.get(url)).await;
+        let navigation_result = tokio::time::timeout(self.timeout, browser.get(url)).await;
 
         match navigation_result {
             Ok(Ok(_)) => {
@@ -388,8 +1102,9 @@ impl WebFetcher {
             }
             Err(_) => {
                 error!(
-                    "Timeout while navigating to URL in browser instance {} (30 seconds)",
-                    instance_id
+                    "Timeout while navigating to URL in browser instance {} ({} seconds)",
+                    instance_id,
+                    self.timeout.as_secs()
                 );
                 return Err(TarziError::Browser(
                     "Timeout while navigating to URL".to_string(),
@@ -417,7 +1132,7 @@ impl WebFetcher {
                     "Falling back to page source for instance {} due to error getting dynamic DOM: {}",
                     instance_id, e
                 );
-                let content_result = tokio::time::timeout(DEFAULT_TIMEOUT, browser.source()).await;
+                let content_result = tokio::time::timeout(self.timeout, browser.source()).await;
                 match content_result {
                     Ok(Ok(content)) => content,
                     Ok(Err(e)) => {
@@ -429,8 +1144,9 @@ impl WebFetcher {
                     }
                     Err(_) => {
                         error!(
-                            "Timeout while extracting page content from browser instance {} (30 seconds)",
-                            instance_id
+                            "Timeout while extracting page content from browser instance {} ({} seconds)",
+                            instance_id,
+                            self.timeout.as_secs()
                         );
                         return Err(TarziError::Browser(
                             "Timeout while extracting page content".to_string(),
@@ -441,7 +1157,7 @@ impl WebFetcher {
         };
 
         // Convert to specified format
-        let converted_content = self.converter.convert(&content, format).await?;
+        let converted_content = self.converter.convert_page(&content, format, url).await?;
         Ok(converted_content)
     }
 
@@ -472,10 +1188,98 @@ impl WebFetcher {
         self.browser_manager.get_managed_driver_info()
     }
 
+    /// Status of the driver this fetcher started, if any; see [`BrowserManager::driver_status`]
+    pub fn driver_status(&self) -> Option<super::driver::DriverStatus> {
+        self.browser_manager.driver_status()
+    }
+
+    /// Whether search-and-fetch runs share one browser; see [`BrowserManager::reuse_browser`]
+    pub fn reuse_browser(&self) -> bool {
+        self.browser_manager.reuse_browser()
+    }
+
+    /// WebDriver endpoint browser fetches connect to
+    pub fn driver_endpoint(&self) -> String {
+        self.browser_manager.driver_endpoint()
+    }
+
+    /// Whether a WebDriver server answers at [`Self::driver_endpoint`]
+    pub async fn is_driver_reachable(&self) -> bool {
+        self.browser_manager.is_driver_reachable().await
+    }
+
     pub async fn shutdown(&mut self) {
         self.browser_manager.shutdown().await;
     }
 
+    /// Get a freshly navigated page ready for extraction: let it render, waiting
+    /// for the `wait_for` selector when given and otherwise for `page_load_wait`,
+    /// then run the page script, if any, and let its changes settle. A selector
+    /// that never appears is logged and the page is extracted as is.
+    async fn prepare_content(
+        browser: &thirtyfour::WebDriver,
+        url: &str,
+        page: PageOptions<'_>,
+    ) -> Result<()> {
+        match page.wait_for {
+            Some(selector) => {
+                if !WebFetcher::wait_for_selector(browser, selector, page.timeout).await {
+                    warn!(
+                        "Selector '{}' did not appear within {} seconds, extracting the page as is",
+                        selector,
+                        page.timeout.as_secs()
+                    );
+                }
+            }
+            None => {
+                info!("Waiting for page to load ({:?})...", page.page_load_wait);
+                tokio::time::sleep(page.page_load_wait).await;
+                info!("Wait completed");
+            }
+        }
+
+        if let Some(script) = page.script {
+            info!("Running page script on {}", url);
+            match tokio::time::timeout(page.timeout, browser.execute(script, Vec::new())).await {
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => {
+                    return Err(TarziError::Browser(format!(
+                        "Page script failed on {url}: {e}"
+                    )));
+                }
+                Err(_) => {
+                    return Err(TarziError::Browser(format!(
+                        "Timeout while running page script on {url} ({} seconds)",
+                        page.timeout.as_secs()
+                    )));
+                }
+            }
+            tokio::time::sleep(SCRIPT_SETTLE_WAIT).await;
+        }
+        Ok(())
+    }
+
+    /// Poll the page until an element matching the CSS selector appears, returning
+    /// false if it doesn't within `timeout`
+    async fn wait_for_selector(
+        browser: &thirtyfour::WebDriver,
+        selector: &str,
+        timeout: Duration,
+    ) -> bool {
+        info!("Waiting for selector '{}'", selector);
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            if browser.find(By::Css(selector)).await.is_ok() {
+                info!("Selector '{}' is present", selector);
+                return true;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(SELECTOR_POLL_INTERVAL).await;
+        }
+    }
+
     // Attempt to get the fully-hydrated DOM as HTML via JavaScript execution.
     // Falls back to WebDriver page source on error.
     async fn get_outer_html_from(browser: &thirtyfour::WebDriver) -> Result<String> {
@@ -486,12 +1290,10 @@ impl WebFetcher {
                 Vec::<serde_json::Value>::new(),
             )
             .await
+            && let Ok(state) = ret.convert::<String>()
+            && state != "complete"
         {
-            if let Ok(state) = ret.convert::<String>() {
-                if state != "complete" {
-                    tokio::time::sleep(PAGE_LOAD_WAIT).await;
-                }
-            }
+            tokio::time::sleep(PAGE_LOAD_WAIT).await;
         }
 
         // Briefly wait for anchors to populate (dynamic JS apps)
@@ -503,12 +1305,10 @@ impl WebFetcher {
                     Vec::<serde_json::Value>::new(),
                 )
                 .await
+                && let Ok(count) = ret.convert::<i64>()
+                && count >= 20
             {
-                if let Ok(count) = ret.convert::<i64>() {
-                    if count >= 20 {
-                        break;
-                    }
-                }
+                break;
             }
             attempts += 1;
             tokio::time::sleep(std::time::Duration::from_millis(500)).await;
@@ -577,6 +1377,54 @@ impl WebFetcher {
     }
 }
 
+/// Feed a response body to the [`StreamingExtractor`] chunk by chunk
+async fn extract_streaming(mut response: reqwest::Response) -> Result<ExtractedContent> {
+    let mut extractor = StreamingExtractor::new();
+    while let Some(chunk) = response.chunk().await? {
+        extractor.push(&chunk);
+    }
+    Ok(extractor.finish())
+}
+
+/// The response's `Content-Type` header, if present and valid
+fn content_type_of(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Parse name/value pairs into a header map; repeated names keep every value
+fn header_map<'a>(
+    headers: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Result<HeaderMap> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| TarziError::Config(format!("Invalid header name '{name}': {e}")))?;
+        let header_value = HeaderValue::from_str(value)
+            .map_err(|e| TarziError::Config(format!("Invalid value for header '{name}': {e}")))?;
+        map.append(header_name, header_value);
+    }
+    Ok(map)
+}
+
+/// Whether a failed plain request may succeed when retried: connection errors
+/// (refused or reset, including mid-body), rate limiting and the
+/// [`RETRYABLE_STATUS_CODES`]. Timeouts are not retried, as the timeout already
+/// bounds how long to wait.
+fn is_retryable(error: &TarziError) -> bool {
+    match error {
+        TarziError::RateLimited { .. } => true,
+        TarziError::Http(e) => match e.status() {
+            Some(status) => RETRYABLE_STATUS_CODES.contains(&status.as_u16()),
+            None => !e.is_timeout() && (e.is_connect() || e.is_request() || e.is_body()),
+        },
+        _ => false,
+    }
+}
+
 impl Default for WebFetcher {
     fn default() -> Self {
         Self::new()
@@ -602,6 +1450,7 @@ impl Drop for WebFetcher {
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::constants::EXAMPLE_URL;
 
     /// Test creating a new WebFetcher
     #[test]
@@ -637,6 +1486,37 @@ mod tests {
         config.fetcher.timeout = 60; // 60 seconds
         let fetcher = WebFetcher::from_config(&config);
         assert!(!fetcher.browser_manager.has_browsers());
+        assert_eq!(fetcher.timeout, Duration::from_secs(60));
+    }
+
+    /// Test that scripts are rejected in plain request mode, before any request
+    #[tokio::test]
+    async fn test_fetch_with_script_requires_browser_mode() {
+        let mut fetcher = WebFetcher::new();
+        let err = fetcher
+            .fetch_with_script(
+                "http://127.0.0.1:9/",
+                FetchMode::PlainRequest,
+                "document.title = 'x';",
+                Format::Html,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TarziError::InvalidMode(_)));
+    }
+
+    /// Test the default wait selector from config, ignoring an empty one
+    #[test]
+    fn test_webfetcher_wait_for_selector_config() {
+        let mut config = Config::default();
+        assert!(WebFetcher::from_config(&config).wait_for_selector.is_none());
+
+        config.fetcher.wait_for_selector = Some("#app .loaded".to_string());
+        let fetcher = WebFetcher::from_config(&config);
+        assert_eq!(fetcher.wait_for_selector.as_deref(), Some("#app .loaded"));
+
+        config.fetcher.wait_for_selector = Some(String::new());
+        assert!(WebFetcher::from_config(&config).wait_for_selector.is_none());
     }
 
     /// Test WebFetcher with custom user agent
@@ -648,6 +1528,762 @@ mod tests {
         assert!(!fetcher.browser_manager.has_browsers());
     }
 
+    /// Test creating WebFetcher with a shared HTTP client
+    #[test]
+    fn test_webfetcher_with_client() {
+        let config = Config::default();
+        let client = WebFetcher::build_http_client(&config);
+        let fetcher = WebFetcher::with_client(client.clone());
+        assert!(!fetcher.browser_manager.has_browsers());
+
+        let fetcher = WebFetcher::from_config_with_client(&config, client);
+        assert!(!fetcher.browser_manager.has_browsers());
+        assert!(!fetcher.has_managed_driver());
+    }
+
+    /// Test WebFetcher with TLS options
+    #[test]
+    fn test_webfetcher_with_tls_options() {
+        let mut config = Config::default();
+        config.fetcher.danger_accept_invalid_certs = true;
+        let fetcher = WebFetcher::from_config(&config);
+        assert!(!fetcher.browser_manager.has_browsers());
+
+        // Configs are validated when loaded, so an unusable CA file fails there
+        config.fetcher.ca_cert_path = Some("/non/existent/ca.pem".to_string());
+        let error = config.validate().unwrap_err();
+        assert!(matches!(error, TarziError::Config(_)));
+        assert!(error.to_string().contains("/non/existent/ca.pem"));
+    }
+
+    /// Test WebFetcher with authentication config
+    #[test]
+    fn test_webfetcher_with_auth_config() {
+        let mut config = Config::default();
+        config.fetcher.auth = Some(AuthConfig::Basic {
+            username: "alice".to_string(),
+            password: Some("hunter2".to_string()),
+        });
+        config.fetcher.auth_hosts = vec!["example.com".to_string()];
+        let mut fetcher = WebFetcher::from_config(&config);
+        assert_eq!(fetcher.auth, config.fetcher.auth);
+
+        let url = Url::parse(EXAMPLE_URL).unwrap();
+        let request = fetcher
+            .apply_auth(fetcher.http_client.get(url.clone()), &url)
+            .build()
+            .unwrap();
+        let header = request.headers().get(reqwest::header::AUTHORIZATION);
+        assert!(header.unwrap().to_str().unwrap().starts_with("Basic "));
+
+        fetcher.set_auth(Some(AuthConfig::Bearer {
+            token: "secret-token".to_string(),
+        }));
+        let request = fetcher
+            .apply_auth(fetcher.http_client.get(url.clone()), &url)
+            .build()
+            .unwrap();
+        let header = request.headers().get(reqwest::header::AUTHORIZATION);
+        assert_eq!(header.unwrap().to_str().unwrap(), "Bearer secret-token");
+
+        fetcher.set_auth(None);
+        let request = fetcher
+            .apply_auth(fetcher.http_client.get(url.clone()), &url)
+            .build()
+            .unwrap();
+        assert!(
+            request
+                .headers()
+                .get(reqwest::header::AUTHORIZATION)
+                .is_none()
+        );
+    }
+
+    /// Test that credentials are only sent to the configured auth hosts
+    #[tokio::test]
+    async fn test_auth_scoped_to_auth_hosts() {
+        let (url, server) = serve(vec![OK, OK]).await;
+        let port = Url::parse(&url).unwrap().port().unwrap();
+        let mut config = Config::new();
+        config.fetcher.auth = Some(AuthConfig::Bearer {
+            token: "secret-token".to_string(),
+        });
+        config.fetcher.auth_hosts = vec!["api.tarzi.invalid".to_string()];
+        for host in ["api.tarzi.invalid", "results.tarzi.invalid"] {
+            config
+                .fetcher
+                .host_overrides
+                .insert(host.to_string(), [127, 0, 0, 1].into());
+        }
+        let mut fetcher = WebFetcher::from_config(&config);
+
+        for host in ["api.tarzi.invalid", "results.tarzi.invalid"] {
+            fetcher
+                .fetch_raw(&format!("http://{host}:{port}/"), FetchMode::PlainRequest)
+                .await
+                .unwrap();
+        }
+        let requests = server.await.unwrap();
+        assert_eq!(
+            header_value(&requests[0], "authorization"),
+            Some("Bearer secret-token")
+        );
+        assert_eq!(header_value(&requests[1], "authorization"), None);
+    }
+
+    /// Test FetchRequest builder defaults and setters
+    #[test]
+    fn test_fetch_request_builder() {
+        let request = FetchRequest::new(EXAMPLE_URL);
+        assert_eq!(request.url, EXAMPLE_URL);
+        assert_eq!(request.mode, FetchMode::BrowserHeadless);
+        assert_eq!(request.format, Format::Markdown);
+        assert!(request.timeout.is_none());
+        assert!(request.proxy.is_none());
+        assert!(request.headers.is_empty());
+        assert!(request.wait_for.is_none());
+        assert!(request.page_load_wait.is_none());
+
+        let request = FetchRequest::new(EXAMPLE_URL)
+            .mode(FetchMode::PlainRequest)
+            .format(Format::Json)
+            .timeout(Duration::from_secs(5))
+            .proxy("http://proxy.example.com:8080")
+            .header("Accept-Language", "en")
+            .headers([("X-Trace", "1")])
+            .wait_for("#content")
+            .page_load_wait(Duration::from_millis(500));
+        assert_eq!(request.mode, FetchMode::PlainRequest);
+        assert_eq!(request.format, Format::Json);
+        assert_eq!(request.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(
+            request.proxy,
+            Some("http://proxy.example.com:8080".to_string())
+        );
+        assert_eq!(request.headers.len(), 2);
+        assert_eq!(request.wait_for, Some("#content".to_string()));
+        assert_eq!(request.page_load_wait, Some(Duration::from_millis(500)));
+        assert!(request.script.is_none());
+        let request = request.script("window.scrollTo(0, document.body.scrollHeight);");
+        assert_eq!(
+            request.script.as_deref(),
+            Some("window.scrollTo(0, document.body.scrollHeight);")
+        );
+    }
+
+    /// Test executing a FetchRequest with an invalid URL
+    #[tokio::test]
+    async fn test_execute_invalid_url() {
+        let mut fetcher = WebFetcher::new();
+        let request = FetchRequest::new("not-a-valid-url")
+            .mode(FetchMode::PlainRequest)
+            .header("X-Test", "1");
+        assert!(fetcher.execute(request).await.is_err());
+    }
+
+    /// Serve `responses` in order, one per connection, on a local port. Returns the
+    /// server's URL and a handle yielding the raw requests once every response has
+    /// been sent. Each connection stays open until the client closes it, so an
+    /// empty response leaves the request unanswered.
+    async fn serve(responses: Vec<&str>) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let responses: Vec<String> = responses.into_iter().map(str::to_string).collect();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let read = socket.read(&mut buf).await.unwrap_or(0);
+                requests.push(String::from_utf8_lossy(&buf[..read]).into_owned());
+                let _ = socket.write_all(response.as_bytes()).await;
+                while socket.read(&mut buf).await.is_ok_and(|read| read > 0) {}
+            }
+            requests
+        });
+        (format!("http://{addr}/"), server)
+    }
+
+    /// A 200 plain-text response with `body`
+    fn text_response(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    /// Value of header `name` in a raw HTTP request
+    fn header_value<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+        request.lines().find_map(|line| {
+            let (header, value) = line.split_once(':')?;
+            header.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    /// Test that relative links in fetched pages are resolved against the page URL
+    #[tokio::test]
+    async fn test_fetch_absolutizes_links() {
+        let (url, _) = serve(vec![
+            "HTTP/1.1 200 OK\r\n\
+             Content-Type: text/html\r\n\
+             Content-Length: 33\r\n\
+             Connection: close\r\n\r\n\
+             <p><a href=\"docs/x\">Docs</a></p>\n",
+        ])
+        .await;
+        let mut fetcher = WebFetcher::new();
+        let markdown = fetcher
+            .fetch(&url, FetchMode::PlainRequest, Format::Markdown)
+            .await
+            .unwrap();
+        assert_eq!(markdown, format!("[Docs]({url}docs/x)"));
+    }
+
+    /// Test that requests rotate through the proxy pool, skipping unreachable proxies
+    #[tokio::test]
+    async fn test_proxy_pool_rotation() {
+        let responses: Vec<String> = (1..=3)
+            .map(|proxy| text_response(&format!("proxy-{proxy}")))
+            .collect();
+        let proxies = vec![
+            serve(vec![responses[0].as_str(); 2]).await.0,
+            serve(vec![responses[1].as_str()]).await.0,
+            serve(vec![responses[2].as_str()]).await.0,
+        ];
+        let mut fetcher = WebFetcher::new().with_proxy_pool(proxies);
+        let mut used = Vec::new();
+        for _ in 0..4 {
+            used.push(
+                fetcher
+                    .fetch_raw("http://example.test/", FetchMode::PlainRequest)
+                    .await
+                    .unwrap(),
+            );
+        }
+        assert_eq!(used, vec!["proxy-1", "proxy-2", "proxy-3", "proxy-1"]);
+
+        // A proxy refusing connections is marked dead and the next one is used
+        let dead = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let (live, _) = serve(vec![responses[1].as_str(); 2]).await;
+        let mut fetcher = WebFetcher::new().with_proxy_pool(vec![dead, live]);
+        for _ in 0..2 {
+            let content = fetcher
+                .fetch_raw("http://example.test/", FetchMode::PlainRequest)
+                .await
+                .unwrap();
+            assert_eq!(content, "proxy-2");
+        }
+        assert_eq!(fetcher.proxy_pool.available(), 1);
+    }
+
+    /// Test that credentials in a proxy URL are sent as proxy basic auth, both for
+    /// the configured proxy and for a per-request one
+    #[tokio::test]
+    async fn test_proxy_credentials_applied() {
+        let (url, server) = serve(vec![OK, OK]).await;
+        let proxy = url.replace("http://", "http://user:p%40ss@");
+
+        let mut fetcher = WebFetcher::new();
+        fetcher
+            .fetch_with_proxy(
+                "http://example.test/",
+                &proxy,
+                FetchMode::PlainRequest,
+                Format::Html,
+            )
+            .await
+            .unwrap();
+
+        let mut config = Config::new();
+        config.fetcher.proxy = Some(proxy);
+        let mut fetcher = WebFetcher::from_config(&config);
+        fetcher
+            .fetch_raw("http://example.test/", FetchMode::PlainRequest)
+            .await
+            .unwrap();
+
+        // base64("user:p@ss")
+        for request in server.await.unwrap() {
+            assert_eq!(
+                header_value(&request, "proxy-authorization"),
+                Some("Basic dXNlcjpwQHNz")
+            );
+        }
+    }
+
+    /// Test that per-request proxy clients keep the configured client settings
+    #[tokio::test]
+    async fn test_proxy_client_uses_configured_settings() {
+        let (proxy, server) = serve(vec![OK]).await;
+        let mut config = Config::new();
+        config.fetcher.user_agent = "tarzi-test/1.0".to_string();
+        let mut fetcher = WebFetcher::from_config(&config);
+
+        fetcher
+            .fetch_with_proxy(
+                "http://example.test/",
+                &proxy,
+                FetchMode::PlainRequest,
+                Format::Html,
+            )
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+        assert_eq!(
+            header_value(&requests[0], "user-agent"),
+            Some("tarzi-test/1.0")
+        );
+    }
+
+    /// Test that pool clients are built once from the configured client settings,
+    /// leaving invalid proxy URLs out of the pool
+    #[tokio::test]
+    async fn test_proxy_pool_clients() {
+        let (proxy, server) = serve(vec![OK]).await;
+        let mut config = Config::new();
+        config.fetcher.user_agent = "tarzi-test/1.0".to_string();
+        config.fetcher.proxy_pool = vec![proxy.clone(), "http://[invalid".to_string()];
+        let mut fetcher = WebFetcher::from_config(&config);
+        assert_eq!(fetcher.proxy_pool.proxies(), [proxy]);
+        assert_eq!(fetcher.proxy_clients.len(), 1);
+
+        fetcher
+            .fetch_raw("http://example.test/", FetchMode::PlainRequest)
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+        assert_eq!(
+            header_value(&requests[0], "user-agent"),
+            Some("tarzi-test/1.0")
+        );
+    }
+
+    /// Test that a per-request timeout overrides the configured one for plain requests
+    #[tokio::test]
+    async fn test_request_timeout_overrides_config() {
+        // Accept the connection but never answer
+        let (url, _) = serve(vec![""]).await;
+
+        let mut config = Config::new();
+        config.fetcher.timeout = 30;
+        let mut fetcher = WebFetcher::from_config(&config);
+        let request = FetchRequest::new(url)
+            .mode(FetchMode::PlainRequest)
+            .timeout(Duration::from_millis(200));
+
+        let started = std::time::Instant::now();
+        assert!(fetcher.execute(request).await.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\n\
+        Content-Length: 0\r\n\
+        Connection: close\r\n\r\n";
+
+    const OK: &str = "HTTP/1.1 200 OK\r\n\
+        Content-Type: text/html\r\n\
+        Content-Length: 11\r\n\
+        Connection: close\r\n\r\n\
+        <p>ok!</p>\n";
+
+    /// Test that transient 5xx responses are retried until the request succeeds
+    #[tokio::test]
+    async fn test_plain_request_retries_transient_errors() {
+        let (url, server) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let mut config = Config::new();
+        config.fetcher.max_retries = 3;
+        config.fetcher.retry_backoff_ms = 10;
+        let mut fetcher = WebFetcher::from_config(&config);
+
+        let content = fetcher
+            .fetch_raw(&url, FetchMode::PlainRequest)
+            .await
+            .unwrap();
+        assert_eq!(content, "<p>ok!</p>\n");
+        assert_eq!(server.await.unwrap().len(), 3);
+    }
+
+    /// Test that streaming extraction retries transient errors like other plain requests
+    #[tokio::test]
+    async fn test_fetch_extract_streaming_retries() {
+        let (url, server) = serve(vec![UNAVAILABLE, OK]).await;
+        let mut config = Config::new();
+        config.fetcher.max_retries = 1;
+        config.fetcher.retry_backoff_ms = 10;
+        let fetcher = WebFetcher::from_config(&config);
+
+        let content = fetcher.fetch_extract_streaming(&url).await.unwrap();
+        assert_eq!(content.text, "ok!");
+        assert_eq!(server.await.unwrap().len(), 2);
+    }
+
+    /// Test that text fetches keep the buffered conversion, with link URLs, rather
+    /// than the streaming extractor's bare text
+    #[tokio::test]
+    async fn test_fetch_text_is_not_streamed() {
+        let page = "HTTP/1.1 200 OK\r\n\
+                    Content-Type: text/html\r\n\
+                    Content-Length: 33\r\n\
+                    Connection: close\r\n\r\n\
+                    <p><a href=\"docs/x\">Docs</a></p>\n";
+        let (url, _) = serve(vec![page]).await;
+        let mut fetcher = WebFetcher::new();
+
+        let text = fetcher
+            .fetch(&url, FetchMode::PlainRequest, Format::Text)
+            .await
+            .unwrap();
+        assert_eq!(text, format!("Docs ({url}docs/x)"));
+    }
+
+    /// Test that retries stop at max_retries and that 404s are never retried
+    #[tokio::test]
+    async fn test_plain_request_retry_limits() {
+        const NOT_FOUND: &str = "HTTP/1.1 404 Not Found\r\n\
+            Content-Length: 0\r\n\
+            Connection: close\r\n\r\n";
+
+        let mut config = Config::new();
+        config.fetcher.max_retries = 1;
+        config.fetcher.retry_backoff_ms = 10;
+        let mut fetcher = WebFetcher::from_config(&config);
+
+        let (url, server) = serve(vec![UNAVAILABLE, UNAVAILABLE]).await;
+        let error = fetcher
+            .fetch_raw(&url, FetchMode::PlainRequest)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, TarziError::Http(e) if e.status().map(|s| s.as_u16()) == Some(503))
+        );
+        assert_eq!(server.await.unwrap().len(), 2);
+
+        // A retry would be answered with 200, so the error shows none happened and
+        // the server is still waiting to send it
+        let (url, server) = serve(vec![NOT_FOUND, OK]).await;
+        assert!(
+            fetcher
+                .fetch_raw(&url, FetchMode::PlainRequest)
+                .await
+                .is_err()
+        );
+        assert!(!server.is_finished());
+    }
+
+    /// Test that a 429 fails with RateLimited carrying the Retry-After delay
+    #[tokio::test]
+    async fn test_plain_request_rate_limited() {
+        let (url, _) = serve(vec![
+            "HTTP/1.1 429 Too Many Requests\r\n\
+             Retry-After: 5\r\n\
+             Content-Length: 0\r\n\
+             Connection: close\r\n\r\n",
+        ])
+        .await;
+        let mut fetcher = WebFetcher::new();
+        let error = fetcher
+            .fetch_raw(&url, FetchMode::PlainRequest)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                TarziError::RateLimited {
+                    retry_after: Some(delay)
+                } if delay == Duration::from_secs(5)
+            ),
+            "{error:?}"
+        );
+    }
+
+    /// Test that fetch_many returns one result per URL in input order, with failing
+    /// URLs failing alone
+    #[tokio::test]
+    async fn test_fetch_many_mixed_results() {
+        const NOT_FOUND: &str = "HTTP/1.1 404 Not Found\r\n\
+            Content-Length: 0\r\n\
+            Connection: close\r\n\r\n";
+
+        // One server per URL, so each answers its own URL however the fetches interleave
+        let pages: Vec<String> = (1..=3)
+            .map(|page| text_response(&format!("page {page}")))
+            .collect();
+        let urls = vec![
+            serve(vec![pages[0].as_str()]).await.0,
+            serve(vec![NOT_FOUND]).await.0,
+            serve(vec![pages[1].as_str()]).await.0,
+            serve(vec![pages[2].as_str()]).await.0,
+        ];
+        let mut fetcher = WebFetcher::new();
+        let results = fetcher
+            .fetch_many(&urls, FetchMode::PlainRequest, Format::Markdown, 4)
+            .await;
+
+        assert_eq!(results.len(), 4);
+        assert!(results[0].as_ref().unwrap().contains("page 1"));
+        assert!(results[1].is_err());
+        assert!(results[2].as_ref().unwrap().contains("page 2"));
+        assert!(results[3].as_ref().unwrap().contains("page 3"));
+    }
+
+    /// Test that configured headers reach the server and request headers override them
+    #[tokio::test]
+    async fn test_custom_headers() {
+        let (url, server) = serve(vec![OK]).await;
+        let mut config = Config::new();
+        config
+            .fetcher
+            .headers
+            .insert("Accept-Language".to_string(), "de".to_string());
+        let mut fetcher = WebFetcher::from_config(&config).with_headers(HashMap::from([(
+            "X-Team".to_string(),
+            "crawler".to_string(),
+        )]));
+        let request = FetchRequest::new(url)
+            .mode(FetchMode::PlainRequest)
+            .header("Accept-Language", "fr");
+        fetcher.execute(request).await.unwrap();
+
+        let received = server.await.unwrap()[0].to_lowercase();
+        assert!(received.contains("accept-language: fr\r\n"));
+        assert!(!received.contains("accept-language: de"));
+        assert!(received.contains("x-team: crawler\r\n"));
+    }
+
+    /// Test that invalid header names and values are reported as config errors
+    #[tokio::test]
+    async fn test_invalid_headers() {
+        let mut fetcher = WebFetcher::new().with_headers(HashMap::from([(
+            "Bad Header".to_string(),
+            "value".to_string(),
+        )]));
+        let request = FetchRequest::new(EXAMPLE_URL).mode(FetchMode::PlainRequest);
+        let error = fetcher.execute(request).await.unwrap_err();
+        assert!(matches!(error, TarziError::Config(_)));
+
+        let mut fetcher = WebFetcher::new();
+        let request = FetchRequest::new(EXAMPLE_URL)
+            .mode(FetchMode::PlainRequest)
+            .header("X-Test", "line\nbreak");
+        let error = fetcher.execute(request).await.unwrap_err();
+        assert!(matches!(error, TarziError::Config(_)));
+    }
+
+    /// Test that cookies set by a response, or seeded manually, are sent back
+    #[tokio::test]
+    async fn test_cookie_store() {
+        const SET_COOKIE: &str = "HTTP/1.1 200 OK\r\n\
+            Set-Cookie: session=abc123; Path=/\r\n\
+            Content-Length: 0\r\n\
+            Connection: close\r\n\r\n";
+
+        let (url, server) = serve(vec![SET_COOKIE, OK]).await;
+        let mut config = Config::new();
+        config.fetcher.cookie_store = true;
+        let mut fetcher = WebFetcher::from_config(&config);
+        fetcher.set_cookie(&url, "theme=dark; Path=/").unwrap();
+
+        for _ in 0..2 {
+            fetcher
+                .fetch_raw(&url, FetchMode::PlainRequest)
+                .await
+                .unwrap();
+        }
+        let requests = server.await.unwrap();
+        assert_eq!(header_value(&requests[0], "cookie"), Some("theme=dark"));
+        let second = header_value(&requests[1], "cookie").unwrap();
+        assert!(second.contains("session=abc123"));
+        assert!(second.contains("theme=dark"));
+    }
+
+    /// Test that seeding cookies requires the cookie store
+    #[test]
+    fn test_set_cookie_without_cookie_store() {
+        let mut fetcher = WebFetcher::new();
+        let error = fetcher
+            .set_cookie(EXAMPLE_URL, "session=abc123")
+            .unwrap_err();
+        assert!(matches!(error, TarziError::Config(_)));
+    }
+
+    /// Test that fetch_detailed converts the content and reports the final URL
+    #[tokio::test]
+    async fn test_fetch_detailed_follows_redirects() {
+        const REDIRECT: &str = "HTTP/1.1 302 Found\r\n\
+            Location: /login\r\n\
+            Content-Length: 0\r\n\
+            Connection: close\r\n\r\n";
+
+        let (url, _) = serve(vec![REDIRECT, OK]).await;
+        let mut fetcher = WebFetcher::new();
+        let response = fetcher
+            .fetch_detailed(&url, FetchMode::PlainRequest, Format::Markdown)
+            .await
+            .unwrap();
+
+        assert_eq!(response.url, format!("{url}login"));
+        assert_eq!(response.status, 200);
+        assert!(response.is_success());
+        assert_eq!(response.content_type.as_deref(), Some("text/html"));
+        assert_eq!(response.body.trim(), "ok!");
+        assert!(response.elapsed > Duration::ZERO);
+    }
+
+    /// Test that fetchers sharing a cache reuse each other's fetched content
+    #[tokio::test]
+    async fn test_shared_cache() {
+        const RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+            Content-Type: text/html\r\n\
+            Content-Length: 11\r\n\
+            Connection: close\r\n\r\n\
+            <p>hit</p>\n";
+
+        let cache = FetchCache::new();
+        let mut first = WebFetcher::new().with_shared_cache(cache.clone());
+        let mut second = WebFetcher::new().with_shared_cache(cache.clone());
+
+        // The server answers only once, so the second fetch must come from the cache
+        let (url, _) = serve(vec![RESPONSE]).await;
+        let content = first
+            .fetch_raw(&url, FetchMode::PlainRequest)
+            .await
+            .unwrap();
+        assert_eq!(content, "<p>hit</p>\n");
+        let cached = second
+            .fetch_raw(&url, FetchMode::PlainRequest)
+            .await
+            .unwrap();
+        assert_eq!(cached, content);
+        assert_eq!(cache.len(), 1);
+    }
+
+    /// Test that a fetcher sending credentials neither reads nor fills a shared cache
+    #[tokio::test]
+    async fn test_shared_cache_skipped_with_auth() {
+        let cache = FetchCache::new();
+        let mut fetcher = WebFetcher::new().with_shared_cache(cache.clone());
+        fetcher.set_auth(Some(AuthConfig::Bearer {
+            token: "secret".to_string(),
+        }));
+
+        let (url, server) = serve(vec![OK, OK]).await;
+        for _ in 0..2 {
+            fetcher
+                .fetch_raw(&url, FetchMode::PlainRequest)
+                .await
+                .unwrap();
+        }
+        assert_eq!(server.await.unwrap().len(), 2);
+        assert!(cache.is_empty());
+    }
+
+    /// Test that host overrides route a hostname to the configured address
+    #[tokio::test]
+    async fn test_host_overrides() {
+        const RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+            Content-Type: text/html\r\n\
+            Content-Length: 15\r\n\
+            Connection: close\r\n\r\n\
+            <p>staging</p>\n";
+
+        let (url, _) = serve(vec![RESPONSE]).await;
+        let mut config = Config::new();
+        config
+            .fetcher
+            .host_overrides
+            .insert("staging.tarzi.invalid".to_string(), [127, 0, 0, 1].into());
+        let mut fetcher = WebFetcher::from_config(&config);
+
+        let content = fetcher
+            .fetch_raw(
+                &url.replace("127.0.0.1", "staging.tarzi.invalid"),
+                FetchMode::PlainRequest,
+            )
+            .await
+            .unwrap();
+        assert_eq!(content, "<p>staging</p>\n");
+    }
+
+    /// Test that error statuses keep their body with fetch_with_meta but fail plain fetches
+    #[tokio::test]
+    async fn test_fetch_with_meta_error_status() {
+        const RESPONSE: &str = "HTTP/1.1 404 Not Found\r\n\
+            Content-Type: application/json\r\n\
+            Content-Length: 26\r\n\
+            Connection: close\r\n\r\n\
+            {\"error\":\"no such widget\"}";
+
+        let fetcher = WebFetcher::new();
+        let (url, _) = serve(vec![RESPONSE]).await;
+        let response = fetcher
+            .fetch_with_meta(&FetchRequest::new(url.clone()))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 404);
+        assert!(!response.is_success());
+        assert_eq!(response.url, url);
+        assert_eq!(response.content_type.as_deref(), Some("application/json"));
+        assert_eq!(response.body, r#"{"error":"no such widget"}"#);
+
+        let mut fetcher = WebFetcher::new();
+        let (url, _) = serve(vec![RESPONSE]).await;
+        assert!(
+            fetcher
+                .fetch_raw(&url, FetchMode::PlainRequest)
+                .await
+                .is_err()
+        );
+    }
+
+    /// Test that JSON responses are not converted as HTML
+    #[tokio::test]
+    async fn test_execute_json_response() {
+        const RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+            Content-Type: application/json\r\n\
+            Content-Length: 12\r\n\
+            Connection: close\r\n\r\n\
+            {\"a\":[1,2]}\n";
+
+        let mut fetcher = WebFetcher::new();
+        let (url, _) = serve(vec![RESPONSE]).await;
+        let content = fetcher
+            .fetch(&url, FetchMode::PlainRequest, Format::Json)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json, serde_json::json!({"a": [1, 2]}));
+    }
+
+    /// Test that fetch_response returns the response headers of a plain request
+    #[tokio::test]
+    async fn test_fetch_response_headers() {
+        const RESPONSE: &str = "HTTP/1.1 200 OK\r\n\
+            Content-Type: text/html\r\n\
+            Set-Cookie: a=1\r\n\
+            Set-Cookie: b=2\r\n\
+            Content-Length: 9\r\n\
+            Connection: close\r\n\r\n\
+            <p>ok</p>";
+
+        let mut fetcher = WebFetcher::new();
+        let (url, _) = serve(vec![RESPONSE]).await;
+        let response = fetcher
+            .fetch_response(&url, FetchMode::PlainRequest)
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.url, url);
+        assert_eq!(response.body, "<p>ok</p>");
+        assert_eq!(response.headers["content-type"], "text/html");
+        assert_eq!(response.headers["set-cookie"], "a=1, b=2");
+    }
+
     /// Test WebFetcher default implementation
     #[test]
     fn test_webfetcher_default() {
@@ -43,6 +43,12 @@ mod tests {
             FetchMode::from_str("browser_headless").unwrap(),
             FetchMode::BrowserHeadless
         );
+        assert_eq!(
+            FetchMode::from_str("browser").unwrap(),
+            FetchMode::BrowserHeadless
+        );
+        let err = FetchMode::from_str("chrome").unwrap_err().to_string();
+        assert!(err.contains("expected one of: plain_request, browser_head, browser_headless"));
     }
 
     #[test]
@@ -1,15 +1,31 @@
 use clap::{Parser, Subcommand};
+use std::fs::File;
+use std::io::{BufWriter, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::str::FromStr;
-use tarzi::constants::{FORMAT_HTML, FORMAT_JSON, FORMAT_MARKDOWN};
+use tarzi::constants::{
+    CHROMEDRIVER_DEFAULT_PORT, CRAWL_MANIFEST_FILE, DEFAULT_CRAWL_DEPTH, DEFAULT_CRAWL_MAX_PAGES,
+    FETCHER_MODE_BROWSER_HEAD, FETCHER_MODE_BROWSER_HEADLESS, FETCHER_MODE_PLAIN_REQUEST,
+    FORMAT_HTML, FORMAT_JSON, FORMAT_MARKDOWN, GECKODRIVER_DEFAULT_PORT, LOG_FORMAT_JSON,
+    LOG_FORMAT_TEXT,
+};
 use tarzi::{
-    Result,
+    Result, TarziError,
     config::{CliConfigParams, Config},
     converter::{Converter, Format, convert_search_results},
-    fetcher::{FetchMode, WebFetcher},
-    search::SearchEngine,
+    fetcher::{
+        CrawlOptions, Crawler, DriverManager, DriverType, FetchMode, PersistentDriver, WebFetcher,
+        format_driver_list,
+    },
+    search::{SearchEngine, SearchEngineType},
+    utils::JsonStreamWriter,
 };
 use tracing::{debug, info};
 
+/// `--input` value that reads the input from stdin
+const STDIN_INPUT: &str = "-";
+
 #[derive(Parser)]
 #[command(name = "tarzi")]
 #[command(about = "Rust-native lite search for AI applications")]
@@ -17,16 +33,50 @@ use tracing::{debug, info};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Never start a browser: fetch and search with plain HTTP requests (no JavaScript)
+    #[arg(long, global = true, conflicts_with_all = ["headless", "headed"])]
+    no_browser: bool,
+    /// Use a headless browser, overriding the configured fetcher mode
+    #[arg(long, global = true, conflicts_with = "headed")]
+    headless: bool,
+    /// Use a browser with a visible window, overriding the configured fetcher mode
+    #[arg(long, global = true)]
+    headed: bool,
+    /// Load only this config file (.toml, .yaml, .yml, or .json) instead of the
+    /// default tarzi.toml lookup
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Log format on stderr: text, or json for one JSON object per line
+    /// (default: `[general] log_format`, else text)
+    #[arg(long, global = true, value_parser = [LOG_FORMAT_TEXT, LOG_FORMAT_JSON])]
+    log_format: Option<String>,
+}
+
+impl Cli {
+    /// Fetcher mode forced by the browser flags, if any
+    fn fetcher_mode(&self) -> Option<&'static str> {
+        if self.no_browser {
+            Some(FETCHER_MODE_PLAIN_REQUEST)
+        } else if self.headless {
+            Some(FETCHER_MODE_BROWSER_HEADLESS)
+        } else if self.headed {
+            Some(FETCHER_MODE_BROWSER_HEAD)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Convert HTML to various formats
     Convert {
-        /// Input HTML string or file path
+        /// Input HTML string, an http(s) URL to fetch and convert, or `-` to read
+        /// HTML from stdin (the default when stdin is piped). URLs are fetched like
+        /// `fetch` does: with a plain request unless a browser flag is given.
         #[arg(short, long)]
-        input: String,
-        /// Output format: markdown, json, or yaml
+        input: Option<String>,
+        /// Output format: markdown, frontmatter, asciidoc, text, json, or yaml
         #[arg(short, long, default_value = FORMAT_MARKDOWN)]
         format: String,
         /// Output file path (optional)
@@ -41,7 +91,11 @@ enum Commands {
         /// URL to fetch
         #[arg(short, long)]
         url: String,
-        /// Output format: html, markdown, json, or yaml
+        /// Fetch mode: plain_request, browser_head, or browser_headless (default:
+        /// plain_request, or the mode chosen with --no-browser/--headless/--headed)
+        #[arg(short, long, value_parser = parse_fetch_mode)]
+        mode: Option<FetchMode>,
+        /// Output format: html, markdown, frontmatter, asciidoc, text, json, or yaml
         #[arg(short, long, default_value = FORMAT_HTML)]
         format: String,
         /// Output file path (optional)
@@ -59,7 +113,11 @@ enum Commands {
         /// Number of results to return
         #[arg(short, long)]
         limit: Option<usize>,
-        /// Output format: json or yaml
+        /// Search engine, overriding the configured one: bing, duckduckgo, google,
+        /// brave, baidu, sogou_weixin, yandex, serpapi, bing_api, google_cse, or searxng
+        #[arg(short, long, value_parser = parse_engine)]
+        engine: Option<String>,
+        /// Output format: json, ndjson (one result per line), yaml, or csv
         #[arg(short, long, default_value = FORMAT_JSON)]
         format: String,
         /// Output file path (optional)
@@ -77,32 +135,235 @@ enum Commands {
         /// Number of results to return
         #[arg(short, long, default_value = "5")]
         limit: usize,
-        /// Output format: html, markdown, json, or yaml
+        /// Search engine, overriding the configured one: bing, duckduckgo, google,
+        /// brave, baidu, sogou_weixin, yandex, serpapi, bing_api, google_cse, or searxng
+        #[arg(short, long, value_parser = parse_engine)]
+        engine: Option<String>,
+        /// Format of fetched content: html, markdown, frontmatter, asciidoc, text,
+        /// json, or yaml; ndjson writes markdown content as with --jsonl
         #[arg(short, long, default_value = FORMAT_MARKDOWN)]
         format: String,
         /// Output file path (optional)
         #[arg(short, long)]
         output: Option<String>,
+        /// Write one JSON result per line instead of a JSON array
+        #[arg(long)]
+        jsonl: bool,
+        /// Enable verbose logging
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Crawl a site breadth-first from a URL, converting each page
+    Crawl {
+        /// URL to start from
+        #[arg(short, long)]
+        url: String,
+        /// Link depth to follow from the start page
+        #[arg(short, long, default_value_t = DEFAULT_CRAWL_DEPTH)]
+        depth: usize,
+        /// Only follow links on the start page's domain
+        #[arg(long)]
+        same_domain: bool,
+        /// Stop after this many pages
+        #[arg(long, default_value_t = DEFAULT_CRAWL_MAX_PAGES)]
+        max_pages: usize,
+        /// Output format for saved pages: html, markdown, frontmatter, asciidoc, text, json, or yaml
+        #[arg(short, long, default_value = FORMAT_MARKDOWN)]
+        format: String,
+        /// Directory to save pages and the manifest to; without it the manifest is printed
+        #[arg(short, long)]
+        output_dir: Option<String>,
         /// Enable verbose logging
         #[arg(short, long)]
         verbose: bool,
     },
+    /// Check which search engines are usable: API keys and settings configured,
+    /// and a WebDriver reachable for engines searched with a browser
+    Doctor,
+    /// Inspect the WebDriver used for browser fetches
+    #[command(visible_alias = "drivers")]
+    Driver {
+        #[command(subcommand)]
+        command: DriverCommands,
+    },
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize logging as early as possible
-    tracing_subscriber::fmt()
+#[derive(Subcommand)]
+enum DriverCommands {
+    /// Show the chromedriver and geckodriver binaries found in PATH and their versions
+    List,
+    /// Show the driver type, endpoint, port, process and whether it responds
+    Status,
+    /// Start a driver that keeps running, reused by later browser fetches and searches
+    Start {
+        /// Driver to start: chrome or firefox
+        #[arg(long = "type", default_value = "chrome")]
+        driver_type: String,
+        /// Port to listen on (default: the driver's standard port)
+        #[arg(long)]
+        port: Option<u16>,
+    },
+    /// Stop the driver started with `tarzi driver start`
+    Stop,
+}
+
+/// Input for `convert`: the `--input` value, or all of stdin for `-` or when
+/// `--input` is omitted and stdin is not a terminal
+fn read_input(input: Option<String>) -> Result<String> {
+    let read_stdin = || {
+        let mut html = String::new();
+        std::io::stdin().read_to_string(&mut html)?;
+        Ok(html)
+    };
+    match input {
+        Some(input) if input == STDIN_INPUT => read_stdin(),
+        Some(input) => Ok(input),
+        None if !std::io::stdin().is_terminal() => read_stdin(),
+        None => Err(TarziError::Config(
+            "--input is required unless HTML is piped to stdin".to_string(),
+        )),
+    }
+}
+
+/// `--engine` value, checked against the supported engines so a typo is
+/// reported with the valid names before anything runs
+fn parse_engine(engine: &str) -> std::result::Result<String, String> {
+    SearchEngineType::from_str(engine)
+        .map(|_| engine.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// `--mode` value, reported with the valid modes when it is not one of them
+fn parse_fetch_mode(mode: &str) -> std::result::Result<FetchMode, String> {
+    FetchMode::from_str(mode).map_err(|e| e.to_string())
+}
+
+/// Whether `input` is an http(s) URL rather than HTML
+fn is_url(input: &str) -> bool {
+    let input = input.trim_start();
+    ["http://", "https://"].iter().any(|scheme| {
+        input
+            .get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    })
+}
+
+/// Output format from the `--format` flag; a `.pdf` output path selects PDF.
+/// PDF output must go to a file, so it is rejected up front without one.
+fn output_format(format: &str, output: Option<&str>) -> Result<Format> {
+    let is_pdf_path = output.is_some_and(|path| {
+        Path::new(path)
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("pdf"))
+    });
+    let format = if is_pdf_path {
+        Format::Pdf
+    } else {
+        Format::from_str(format)?
+    };
+    if format == Format::Pdf && output.is_none() {
+        return Err(TarziError::InvalidFormat(
+            "PDF output cannot be printed; write it to a file with --output".to_string(),
+        ));
+    }
+    Ok(format)
+}
+
+/// Fetch `url` and convert it to `format`, as bytes so binary formats work too.
+/// PDF is laid out by `converter` from the fetched HTML.
+async fn fetch_bytes(
+    fetcher: &mut WebFetcher,
+    converter: &Converter,
+    url: &str,
+    mode: FetchMode,
+    format: Format,
+) -> Result<Vec<u8>> {
+    if format != Format::Pdf {
+        return Ok(fetcher.fetch(url, mode, format).await?.into_bytes());
+    }
+    let html = fetcher.fetch(url, mode, Format::Html).await?;
+    converter.convert_bytes(&html, format).await
+}
+
+/// Run `work` until it finishes or Ctrl-C is pressed, which fails it with
+/// [`TarziError::Interrupted`]. Either way control returns to the caller, so the
+/// browsers and drivers it started are shut down rather than orphaned.
+async fn until_interrupted<T>(work: impl Future<Output = Result<T>>) -> Result<T> {
+    tokio::select! {
+        result = work => result,
+        _ = tokio::signal::ctrl_c() => {
+            info!("Interrupted, shutting down");
+            Err(TarziError::Interrupted)
+        }
+    }
+}
+
+/// Write converted output to `output`, or print it
+fn write_output(result: &[u8], output: Option<&str>) -> Result<()> {
+    match output {
+        Some(output_path) => {
+            std::fs::write(output_path, result)?;
+            info!("Output written to file: {}", output_path);
+        }
+        None => println!("{}", String::from_utf8_lossy(result)),
+    }
+    Ok(())
+}
+
+/// Install the global log subscriber in `log_format`. Logs go to stderr so stdout
+/// only carries command output.
+fn init_logging(log_format: &str) -> Result<()> {
+    let subscriber = tracing_subscriber::fmt()
         .with_max_level(tracing::Level::INFO)
-        .init();
+        .with_writer(std::io::stderr);
+    match log_format {
+        LOG_FORMAT_TEXT => subscriber.init(),
+        LOG_FORMAT_JSON => subscriber.json().init(),
+        other => {
+            return Err(TarziError::Config(format!(
+                "Invalid log format '{other}' (expected {LOG_FORMAT_TEXT} or {LOG_FORMAT_JSON})"
+            )));
+        }
+    }
+    Ok(())
+}
 
+#[tokio::main]
+async fn main() -> ExitCode {
     let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            // Keep stdout clean for output that is piped or redirected
+            eprintln!("Error: {e}");
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    // Load configuration with proper precedence, or only the file given with --config
+    let mut config = match &cli.config {
+        Some(path) => Config::load_from_path(path)?,
+        None => Config::load()?,
+    };
 
-    // Load configuration with proper precedence
-    let mut config = Config::load()?;
+    // Initialize logging before anything is logged, in the format --log-format or
+    // the config asks for
+    config.apply_cli_params(&CliConfigParams {
+        log_format: cli.log_format.clone(),
+        ..CliConfigParams::new()
+    });
+    init_logging(&config.general.log_format)?;
 
     // Apply CLI parameters to config
     let mut cli_params = CliConfigParams::new();
+    cli_params.fetcher_mode = cli.fetcher_mode().map(str::to_string);
+    // Fetching page content uses plain requests unless a browser flag says otherwise
+    let content_fetch_mode = match &cli_params.fetcher_mode {
+        Some(mode) => FetchMode::from_str(mode)?,
+        None => FetchMode::PlainRequest,
+    };
 
     match cli.command {
         Commands::Convert {
@@ -111,22 +372,34 @@ async fn main() -> Result<()> {
             output,
             verbose: _,
         } => {
-            // Convert HTML input to specified format
-            debug!("Input length: {} characters", input.len());
+            let format = output_format(&format, output.as_deref())?;
+            let input = read_input(input)?;
+            let result = if is_url(&input) {
+                // Fetch the page first, in the same mode as `fetch` without `--mode`
+                config.apply_cli_params(&cli_params);
+                let mode = content_fetch_mode;
+                debug!("Fetching {} for conversion (mode: {:?})", input, mode);
 
-            let converter = Converter::new();
-            let format = Format::from_str(&format)?;
-            let result = converter.convert(&input, format).await?;
-
-            if let Some(output_path) = output {
-                std::fs::write(&output_path, result)?;
-                info!("Output written to file: {}", output_path);
+                let mut fetcher = WebFetcher::from_config(&config);
+                let converter = Converter::from_config(&config);
+                let result =
+                    until_interrupted(fetch_bytes(&mut fetcher, &converter, &input, mode, format))
+                        .await;
+                fetcher.shutdown().await;
+                result?
             } else {
-                println!("{result}");
-            }
+                // Convert HTML input to specified format
+                debug!("Input length: {} characters", input.len());
+                Converter::from_config(&config)
+                    .convert_bytes(&input, format)
+                    .await?
+            };
+
+            write_output(&result, output.as_deref())?;
         }
         Commands::Fetch {
             url,
+            mode,
             format,
             output,
             verbose: _,
@@ -139,20 +412,20 @@ async fn main() -> Result<()> {
             config.apply_cli_params(&cli_params);
 
             let mut fetcher = WebFetcher::from_config(&config);
-            let format = Format::from_str(&format)?;
-
-            let result = fetcher.fetch(&url, FetchMode::PlainRequest, format).await?;
+            let format = output_format(&format, output.as_deref())?;
 
-            if let Some(output_path) = output {
-                std::fs::write(&output_path, result)?;
-                info!("Output written to file: {}", output_path);
-            } else {
-                println!("{result}");
-            }
+            let mode = mode.unwrap_or(content_fetch_mode);
+            let converter = Converter::from_config(&config);
+            let result =
+                until_interrupted(fetch_bytes(&mut fetcher, &converter, &url, mode, format)).await;
+            // Close any browser and driver the fetch started
+            fetcher.shutdown().await;
+            write_output(&result?, output.as_deref())?;
         }
         Commands::Search {
             query,
             limit,
+            engine,
             format,
             output,
             verbose: _,
@@ -163,11 +436,15 @@ async fn main() -> Result<()> {
 
             // Apply CLI parameters to config
             cli_params.search_limit = Some(effective_limit);
+            cli_params.search_engine = engine;
             config.apply_cli_params(&cli_params);
 
             let mut search_engine = SearchEngine::from_config(&config);
 
-            let results = search_engine.search(&query, effective_limit).await?;
+            let results = until_interrupted(search_engine.search(&query, effective_limit)).await;
+            // Clean up browser and driver resources before any error is reported
+            search_engine.shutdown().await;
+            let results = results?;
 
             debug!("Processing results for output format: {}", format);
 
@@ -177,45 +454,193 @@ async fn main() -> Result<()> {
             if let Some(output_path) = output {
                 std::fs::write(&output_path, result)?;
                 info!("Output written to file: {}", output_path);
-            } else {
-                println!("{result}");
+            } else if !result.is_empty() {
+                // NDJSON without results is empty rather than a blank line, and
+                // CSV rows end with their own line break
+                print!("{result}");
+                if !result.ends_with('\n') {
+                    println!();
+                }
             }
-
-            // Explicitly clean up browser and driver resources before exit
-            search_engine.shutdown().await;
         }
         Commands::SearchAndFetch {
             query,
             limit,
+            engine,
             format,
             output,
+            jsonl,
             verbose: _,
         } => {
             // Search and fetch content for each result
             // Apply CLI parameters to config
             cli_params.search_limit = Some(limit);
+            cli_params.search_engine = engine;
             cli_params.fetcher_format = Some(format.clone());
             config.apply_cli_params(&cli_params);
 
             let mut search_engine = SearchEngine::from_config(&config);
-            let format = Format::from_str(&format)?;
+            // NDJSON describes the output, not the content: it means --jsonl
+            let (format, jsonl) = match Format::from_str(&format)? {
+                Format::NdJson => (Format::Markdown, true),
+                format => (format, jsonl),
+            };
 
-            let results_with_content = search_engine
-                .search_with_content(&query, limit, FetchMode::PlainRequest, format)
-                .await?;
-
-            // Convert results to JSON for output
-            let result = serde_json::to_string_pretty(&results_with_content)?;
+            // Write each result as soon as its content is fetched rather than
+            // holding the whole output in memory
+            let writer: Box<dyn Write> = match &output {
+                Some(output_path) => Box::new(BufWriter::new(File::create(output_path)?)),
+                None => Box::new(BufWriter::new(std::io::stdout().lock())),
+            };
+            let mut writer = if jsonl {
+                JsonStreamWriter::lines(writer)
+            } else {
+                JsonStreamWriter::array(writer)
+            };
+            let written = until_interrupted(search_engine.search_with_content_each(
+                &query,
+                limit,
+                content_fetch_mode,
+                format,
+                |result, content| writer.write(&(result, content)),
+            ))
+            .await;
+            // Clean up browser and driver resources, also after Ctrl-C
+            search_engine.shutdown().await;
+            // Close the array even after a failure so partial output stays valid JSON
+            let mut writer = writer.finish()?;
+            if !jsonl && output.is_none() {
+                writeln!(writer)?;
+            }
+            written?;
 
             if let Some(output_path) = output {
-                std::fs::write(&output_path, result)?;
                 info!("Output written to file: {}", output_path);
+            }
+        }
+        Commands::Crawl {
+            url,
+            depth,
+            same_domain,
+            max_pages,
+            format,
+            output_dir,
+            verbose: _,
+        } => {
+            config.apply_cli_params(&cli_params);
+            let options = CrawlOptions {
+                max_depth: depth,
+                max_pages,
+                same_domain,
+                mode: content_fetch_mode,
+                format: Format::from_str(&format)?,
+                ..Default::default()
+            };
+            let mut crawler = Crawler::new(WebFetcher::from_config(&config), options)
+                .with_converter(Converter::from_config(&config));
+            let output_dir = output_dir.map(std::path::PathBuf::from);
+            let manifest = until_interrupted(crawler.crawl(&url, output_dir.as_deref())).await;
+            crawler.shutdown().await;
+            let manifest = manifest?;
+
+            if let Some(dir) = output_dir {
+                info!(
+                    "Crawled {} pages, manifest written to {}",
+                    manifest.pages.len(),
+                    dir.join(CRAWL_MANIFEST_FILE).display()
+                );
             } else {
-                println!("{result}");
+                println!("{}", serde_json::to_string_pretty(&manifest)?);
             }
+        }
+        Commands::Doctor => {
+            config.apply_cli_params(&cli_params);
+            let search_engine = SearchEngine::from_config(&config);
+            let health = search_engine.health_check().await;
 
-            // Explicitly clean up browser and driver resources before exit
-            search_engine.shutdown().await;
+            println!("{:<14} {:<4} STATUS", "ENGINE", "TYPE");
+            for (engine_type, healthy) in health {
+                println!(
+                    "{:<14} {:<4} {}",
+                    engine_type.name(),
+                    if engine_type.is_api() { "api" } else { "web" },
+                    if healthy { "ok" } else { "unavailable" }
+                );
+            }
+        }
+        Commands::Driver {
+            command: DriverCommands::List,
+        } => {
+            print!(
+                "{}",
+                format_driver_list(&DriverManager::new().detect_drivers())
+            );
+        }
+        Commands::Driver {
+            command: DriverCommands::Status,
+        } => {
+            config.apply_cli_params(&cli_params);
+            let fetcher = WebFetcher::from_config(&config);
+            let endpoint = fetcher.driver_endpoint();
+            let managed = fetcher.get_managed_driver_info();
+            let port = url::Url::parse(&endpoint)
+                .ok()
+                .and_then(|url| url.port_or_known_default());
+            let reachable = fetcher.is_driver_reachable().await;
+
+            let external = config
+                .fetcher
+                .web_driver_url
+                .as_ref()
+                .is_some_and(|url| !url.is_empty());
+            let persistent = PersistentDriver::load().filter(|_| !external && managed.is_none());
+            let driver = match (managed, &persistent) {
+                (Some(info), _) => info.config.driver_type.to_string(),
+                _ if external => "external".to_string(),
+                (None, Some(driver)) => format!("{} (persistent)", driver.driver_type),
+                (None, None) => config.fetcher.web_driver.clone(),
+            };
+            let pid = managed
+                .and_then(|info| info.pid)
+                .or(persistent.map(|driver| driver.pid));
+            let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+            println!("driver:    {driver}");
+            println!("endpoint:  {endpoint}");
+            println!("port:      {}", or_dash(port.map(|port| port.to_string())));
+            println!("pid:       {}", or_dash(pid.map(|pid| pid.to_string())));
+            println!(
+                "status:    {}",
+                if reachable {
+                    "reachable"
+                } else {
+                    "not reachable"
+                }
+            );
+        }
+        Commands::Driver {
+            command: DriverCommands::Start { driver_type, port },
+        } => {
+            let driver_type = DriverType::from_str(&driver_type)?;
+            let port = port.unwrap_or(match driver_type {
+                DriverType::Firefox => GECKODRIVER_DEFAULT_PORT,
+                _ => CHROMEDRIVER_DEFAULT_PORT,
+            });
+            let manager =
+                DriverManager::new().with_auto_download(config.fetcher.auto_download_driver);
+            manager.ensure_driver(&driver_type).await?;
+            let driver =
+                manager.start_persistent_driver(DriverManager::create_config(driver_type, port))?;
+            println!(
+                "Started {} at {} (pid {})",
+                driver.driver_type, driver.endpoint, driver.pid
+            );
+        }
+        Commands::Driver {
+            command: DriverCommands::Stop,
+        } => {
+            let driver = DriverManager::stop_persistent_driver()?;
+            println!("Stopped {} (pid {})", driver.driver_type, driver.pid);
         }
     }
 
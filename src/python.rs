@@ -1,23 +1,76 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 #![allow(non_local_definitions)]
 use crate::config::Config;
-use crate::{Converter, FetchMode, Format, SearchEngine, WebFetcher};
+use crate::constants::DEFAULT_FETCH_CONCURRENCY;
+use crate::search::SearchEngineType;
+use crate::{Converter, FetchMode, Format, SearchEngine, TarziError, WebFetcher};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::Mutex;
 use toml;
 
+pyo3::create_exception!(
+    tarzi,
+    RateLimitedError,
+    pyo3::exceptions::PyRuntimeError,
+    "The server answered 429 Too Many Requests; `retry_after` holds the seconds its Retry-After header asked to wait, or None."
+);
+
 /// Python module for tarzi - Rust-native lite search for AI applications
 #[pymodule]
-fn tarzi(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+fn tarzi(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyConverter>()?;
     m.add_class::<PyWebFetcher>()?;
     m.add_class::<PySearchEngine>()?;
     m.add_class::<PySearchResult>()?;
     m.add_class::<PyConfig>()?;
+    m.add("RateLimitedError", py.get_type::<RateLimitedError>())?;
     Ok(())
 }
 
+static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+/// The tokio runtime shared by every call, created on first use. It is
+/// multi-threaded so browser operations that spawn tasks keep running, and it
+/// also drives the futures of the async methods.
+fn runtime() -> PyResult<&'static tokio::runtime::Runtime> {
+    if let Some(rt) = RUNTIME.get() {
+        return Ok(rt);
+    }
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to create async runtime: {e}"
+            ))
+        })?;
+    // A runtime built concurrently by another thread may have won the race
+    let rt = RUNTIME.get_or_init(|| rt);
+    let _ = pyo3_async_runtimes::tokio::init_with_runtime(rt);
+    Ok(rt)
+}
+
+/// Python exception for a failed fetch or search: `RateLimitedError` (a
+/// RuntimeError) with a `retry_after` attribute when rate limited, otherwise a
+/// RuntimeError
+fn to_py_err(context: String, error: TarziError) -> PyErr {
+    let message = format!("{context}: {error}");
+    match error {
+        TarziError::RateLimited { retry_after } => {
+            let err = RateLimitedError::new_err(message);
+            Python::with_gil(|py| {
+                let retry_after = retry_after.map(|delay| delay.as_secs_f64());
+                let _ = err.value(py).setattr("retry_after", retry_after);
+            });
+            err
+        }
+        _ => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(message),
+    }
+}
+
 /// HTML/text content converter
 #[pyclass(name = "Converter")]
 #[derive(Clone)]
@@ -39,7 +92,7 @@ impl PyConverter {
         }
     }
 
-    /// Create a converter from configuration
+    /// Create a converter from configuration, applying its `[converter]` section
     ///
     /// Args:
     ///     config (Config): Configuration object
@@ -47,9 +100,9 @@ impl PyConverter {
     /// Returns:
     ///     Converter: A new converter instance
     #[classmethod]
-    fn from_config(_cls: &Bound<'_, PyType>, _config: &PyConfig) -> PyResult<Self> {
+    fn from_config(_cls: &Bound<'_, PyType>, config: &PyConfig) -> PyResult<Self> {
         Ok(Self {
-            inner: Converter::new(),
+            inner: Converter::from_config(&config.inner),
         })
     }
 
@@ -65,20 +118,16 @@ impl PyConverter {
     /// Raises:
     ///     ValueError: If format is invalid
     ///     RuntimeError: If conversion fails
-    fn convert(&self, input: &str, format: &str) -> PyResult<String> {
+    fn convert(&self, py: Python<'_>, input: &str, format: &str) -> PyResult<String> {
         let format = Format::from_str(format).map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                 "Invalid format '{format}': {e}"
             ))
         })?;
 
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
-            ))
-        })?;
+        let rt = runtime()?;
 
-        rt.block_on(async { self.inner.convert(input, format).await })
+        py.allow_threads(|| rt.block_on(async { self.inner.convert(input, format).await }))
             .map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Conversion failed: {e}"))
             })
@@ -95,19 +144,50 @@ impl PyConverter {
     ///     
     /// Raises:
     ///     RuntimeError: If conversion fails
-    fn convert_with_config(&self, input: &str, config: &PyConfig) -> PyResult<String> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
+    fn convert_with_config(
+        &self,
+        py: Python<'_>,
+        input: &str,
+        config: &PyConfig,
+    ) -> PyResult<String> {
+        let rt = runtime()?;
+        let config = &config.inner;
+
+        py.allow_threads(|| {
+            rt.block_on(async { self.inner.convert_with_config(input, config).await })
+        })
+        .map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
+                "Conversion with config failed: {e}"
             ))
-        })?;
+        })
+    }
 
-        rt.block_on(async { self.inner.convert_with_config(input, &config.inner).await })
+    /// Extract link-preview metadata from an HTML page
+    ///
+    /// Args:
+    ///     html (str): HTML content
+    ///
+    /// Returns:
+    ///     dict: "title", "description", "image", "site_name" and "published_time"
+    ///     (None when missing) and "json_ld", a list of the page's JSON-LD objects
+    ///
+    /// Raises:
+    ///     RuntimeError: If extraction fails
+    fn extract_metadata(&self, py: Python<'_>, html: &str) -> PyResult<PyObject> {
+        let metadata = self
+            .inner
+            .extract_metadata(html)
+            .and_then(|metadata| Ok(serde_json::to_string(&metadata)?))
             .map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Conversion with config failed: {e}"
+                    "Metadata extraction failed: {e}"
                 ))
-            })
+            })?;
+        Ok(py
+            .import("json")?
+            .call_method1("loads", (metadata,))?
+            .unbind())
     }
 
     fn __repr__(&self) -> String {
@@ -122,7 +202,8 @@ impl PyConverter {
 /// Web page fetcher with multiple modes
 #[pyclass(name = "WebFetcher")]
 pub struct PyWebFetcher {
-    inner: WebFetcher,
+    /// Shared with the futures of the async methods, which outlive the call
+    inner: Arc<Mutex<WebFetcher>>,
 }
 
 #[allow(non_local_definitions)]
@@ -135,7 +216,7 @@ impl PyWebFetcher {
     #[new]
     fn new() -> Self {
         Self {
-            inner: WebFetcher::new(),
+            inner: Arc::new(Mutex::new(WebFetcher::new())),
         }
     }
 
@@ -149,7 +230,7 @@ impl PyWebFetcher {
     #[classmethod]
     fn from_config(_cls: &Bound<'_, PyType>, config: &PyConfig) -> PyResult<Self> {
         Ok(Self {
-            inner: WebFetcher::from_config(&config.inner),
+            inner: Arc::new(Mutex::new(WebFetcher::from_config(&config.inner))),
         })
     }
 
@@ -165,8 +246,9 @@ impl PyWebFetcher {
     ///     
     /// Raises:
     ///     ValueError: If mode or format is invalid
+    ///     RateLimitedError: If the server answers 429 Too Many Requests
     ///     RuntimeError: If fetching fails
-    fn fetch(&mut self, url: &str, mode: &str, format: &str) -> PyResult<String> {
+    fn fetch(&mut self, py: Python<'_>, url: &str, mode: &str, format: &str) -> PyResult<String> {
         let mode = FetchMode::from_str(mode).map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                 "Invalid fetch mode '{mode}': {e}"
@@ -178,18 +260,54 @@ impl PyWebFetcher {
             ))
         })?;
 
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
+        let rt = runtime()?;
+
+        py.allow_threads(|| {
+            rt.block_on(async { self.inner.lock().await.fetch(url, mode, format).await })
+        })
+        .map_err(|e| to_py_err(format!("Failed to fetch '{url}'"), e))
+    }
+
+    /// Fetch a web page and convert it to the specified format without blocking
+    /// the event loop
+    ///
+    /// Args:
+    ///     url (str): URL to fetch
+    ///     mode (str): Fetch mode ("plain_request", "browser_head", "browser_headless")
+    ///     format (str): Output format ("html", "markdown", "json", "yaml")
+    ///
+    /// Returns:
+    ///     Awaitable[str]: Fetched and converted content
+    ///
+    /// Raises:
+    ///     ValueError: If mode or format is invalid
+    ///     RateLimitedError: If the server answers 429 Too Many Requests
+    ///     RuntimeError: If fetching fails
+    fn fetch_async<'py>(
+        &self,
+        py: Python<'py>,
+        url: String,
+        mode: &str,
+        format: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mode = FetchMode::from_str(mode).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid fetch mode '{mode}': {e}"
+            ))
+        })?;
+        let format = Format::from_str(format).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid format '{format}': {e}"
             ))
         })?;
 
-        rt.block_on(async { self.inner.fetch(url, mode, format).await })
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Failed to fetch '{url}': {e}"
-                ))
-            })
+        runtime()?;
+        let inner = Arc::clone(&self.inner);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // Release the fetcher before mapping the error, which takes the GIL
+            let result = inner.lock().await.fetch(&url, mode, format).await;
+            result.map_err(|e| to_py_err(format!("Failed to fetch '{url}'"), e))
+        })
     }
 
     /// Fetch raw HTML content from a web page
@@ -203,26 +321,21 @@ impl PyWebFetcher {
     ///     
     /// Raises:
     ///     ValueError: If mode is invalid
+    ///     RateLimitedError: If the server answers 429 Too Many Requests
     ///     RuntimeError: If fetching fails
-    fn fetch_raw(&mut self, url: &str, mode: &str) -> PyResult<String> {
+    fn fetch_raw(&mut self, py: Python<'_>, url: &str, mode: &str) -> PyResult<String> {
         let mode = FetchMode::from_str(mode).map_err(|e| {
             PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                 "Invalid fetch mode '{mode}': {e}"
             ))
         })?;
 
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
-            ))
-        })?;
+        let rt = runtime()?;
 
-        rt.block_on(async { self.inner.fetch_raw(url, mode).await })
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Failed to fetch raw content from '{url}': {e}"
-                ))
-            })
+        py.allow_threads(|| {
+            rt.block_on(async { self.inner.lock().await.fetch_raw(url, mode).await })
+        })
+        .map_err(|e| to_py_err(format!("Failed to fetch raw content from '{url}'"), e))
     }
 
     /// Fetch a web page through a proxy
@@ -238,9 +351,11 @@ impl PyWebFetcher {
     ///     
     /// Raises:
     ///     ValueError: If mode or format is invalid
+    ///     RateLimitedError: If the server answers 429 Too Many Requests
     ///     RuntimeError: If fetching fails
     fn fetch_with_proxy(
         &mut self,
+        py: Python<'_>,
         url: &str,
         proxy: &str,
         mode: &str,
@@ -257,18 +372,92 @@ impl PyWebFetcher {
             ))
         })?;
 
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
+        let rt = runtime()?;
+
+        py.allow_threads(|| {
+            rt.block_on(async {
+                self.inner
+                    .lock()
+                    .await
+                    .fetch_with_proxy(url, proxy, mode, format)
+                    .await
+            })
+        })
+        .map_err(|e| to_py_err(format!("Failed to fetch '{url}' via proxy '{proxy}'"), e))
+    }
+
+    /// Fetch several web pages, running up to `concurrency` plain requests at once
+    ///
+    /// Args:
+    ///     urls (list[str]): URLs to fetch
+    ///     mode (str): Fetch mode ("plain_request", "browser_head", "browser_headless")
+    ///     format (str): Output format ("html", "markdown", "json", "yaml")
+    ///     concurrency (int): Plain requests run at once (default: 4); browser
+    ///         fetches run one at a time
+    ///
+    /// Returns:
+    ///     list[str | RuntimeError]: One entry per URL, in order: the converted
+    ///         content, or the exception for a URL that failed
+    ///
+    /// Raises:
+    ///     ValueError: If mode or format is invalid
+    #[pyo3(signature = (urls, mode, format, concurrency = DEFAULT_FETCH_CONCURRENCY))]
+    fn fetch_many(
+        &mut self,
+        py: Python<'_>,
+        urls: Vec<String>,
+        mode: &str,
+        format: &str,
+        concurrency: usize,
+    ) -> PyResult<Vec<PyObject>> {
+        let mode = FetchMode::from_str(mode).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid fetch mode '{mode}': {e}"
+            ))
+        })?;
+        let format = Format::from_str(format).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid format '{format}': {e}"
             ))
         })?;
 
-        rt.block_on(async { self.inner.fetch_with_proxy(url, proxy, mode, format).await })
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Failed to fetch '{url}' via proxy '{proxy}': {e}"
-                ))
+        let rt = runtime()?;
+
+        let results = py.allow_threads(|| {
+            rt.block_on(async {
+                self.inner
+                    .lock()
+                    .await
+                    .fetch_many(&urls, mode, format, concurrency)
+                    .await
+            })
+        });
+        urls.iter()
+            .zip(results)
+            .map(|(url, result)| match result {
+                Ok(content) => Ok(content.into_pyobject(py)?.into_any().unbind()),
+                Err(e) => Ok(to_py_err(format!("Failed to fetch '{url}'"), e)
+                    .into_value(py)
+                    .into_any()),
             })
+            .collect()
+    }
+
+    /// Shutdown browser and driver resources
+    ///
+    /// Closes browser sessions opened by browser-mode fetches and stops any WebDriver
+    /// process the fetcher started. Call it when the fetcher is no longer needed.
+    ///
+    /// Returns:
+    ///     None
+    ///
+    /// Raises:
+    ///     RuntimeError: If shutdown fails
+    fn shutdown(&mut self, py: Python<'_>) -> PyResult<()> {
+        let rt = runtime()?;
+
+        py.allow_threads(|| rt.block_on(async { self.inner.lock().await.shutdown().await }));
+        Ok(())
     }
 
     fn __repr__(&self) -> String {
@@ -283,7 +472,19 @@ impl PyWebFetcher {
 /// Search engine with multiple providers and modes
 #[pyclass(name = "SearchEngine")]
 pub struct PySearchEngine {
-    inner: SearchEngine,
+    /// Shared with the futures of the async methods, which outlive the call
+    inner: Arc<Mutex<SearchEngine>>,
+    /// Copied out of the engine so reading it never waits for a running search
+    default_limit: usize,
+}
+
+impl PySearchEngine {
+    fn from_engine(engine: SearchEngine) -> Self {
+        Self {
+            default_limit: engine.default_limit(),
+            inner: Arc::new(Mutex::new(engine)),
+        }
+    }
 }
 
 #[allow(non_local_definitions)]
@@ -297,9 +498,7 @@ impl PySearchEngine {
     fn new() -> Self {
         // Use configuration loading with precedence to ensure proper defaults
         let config = crate::config::Config::load().unwrap_or_default();
-        Self {
-            inner: SearchEngine::from_config(&config),
-        }
+        Self::from_engine(SearchEngine::from_config(&config))
     }
 
     /// Create a search engine from configuration
@@ -311,46 +510,80 @@ impl PySearchEngine {
     ///     SearchEngine: A new search engine instance
     #[classmethod]
     fn from_config(_cls: &Bound<'_, PyType>, config: &PyConfig) -> PyResult<Self> {
-        Ok(Self {
-            inner: SearchEngine::from_config(&config.inner),
-        })
+        Ok(Self::from_engine(SearchEngine::from_config(&config.inner)))
+    }
+
+    /// Number of results a search returns when no limit is given
+    #[getter]
+    fn default_limit(&self) -> usize {
+        self.default_limit
     }
 
     /// Search for web pages
     ///
     /// Args:
     ///     query (str): Search query
-    ///     limit (int): Maximum number of results
+    ///     limit (Optional[int]): Maximum number of results (default: the
+    ///         configured `search_limit`)
     ///     
     /// Returns:
     ///     List[SearchResult]: List of search results
     ///     
     /// Raises:
+    ///     RateLimitedError: If the search API answers 429 Too Many Requests
     ///     RuntimeError: If search fails
-    fn search(&mut self, query: &str, limit: usize) -> PyResult<Vec<PySearchResult>> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
-            ))
-        })?;
+    #[pyo3(signature = (query, limit = None))]
+    fn search(
+        &mut self,
+        py: Python<'_>,
+        query: &str,
+        limit: Option<usize>,
+    ) -> PyResult<Vec<PySearchResult>> {
+        let rt = runtime()?;
+        let limit = limit.unwrap_or(self.default_limit);
 
-        rt.block_on(async { self.inner.search(query, limit).await })
-            .map(|results| {
-                results
-                    .into_iter()
-                    .map(|r| PySearchResult {
-                        title: r.title,
-                        url: r.url,
-                        snippet: r.snippet,
-                        rank: r.rank,
-                    })
-                    .collect()
-            })
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Search failed for query '{query}': {e}"
-                ))
-            })
+        py.allow_threads(|| {
+            rt.block_on(async { self.inner.lock().await.search(query, limit).await })
+        })
+        .map(|results| results.into_iter().map(PySearchResult::from).collect())
+        .map_err(|e| to_py_err(format!("Search failed for query '{query}'"), e))
+    }
+
+    /// Search for web pages without blocking the event loop
+    ///
+    /// Args:
+    ///     query (str): Search query
+    ///     limit (Optional[int]): Maximum number of results (default: the
+    ///         configured `search_limit`)
+    ///
+    /// Returns:
+    ///     Awaitable[List[SearchResult]]: List of search results
+    ///
+    /// Raises:
+    ///     RateLimitedError: If the search API answers 429 Too Many Requests
+    ///     RuntimeError: If search fails
+    #[pyo3(signature = (query, limit = None))]
+    fn search_async<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        limit: Option<usize>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        runtime()?;
+        let limit = limit.unwrap_or(self.default_limit);
+        let inner = Arc::clone(&self.inner);
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // Release the engine before mapping the error, which takes the GIL
+            let result = inner.lock().await.search(&query, limit).await;
+            result
+                .map(|results| {
+                    results
+                        .into_iter()
+                        .map(PySearchResult::from)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(|e| to_py_err(format!("Search failed for query '{query}'"), e))
+        })
     }
 
     /// Search for web pages and fetch their content
@@ -366,9 +599,11 @@ impl PySearchEngine {
     ///     
     /// Raises:
     ///     ValueError: If fetch_mode, or format is invalid
+    ///     RateLimitedError: If the search API answers 429 Too Many Requests
     ///     RuntimeError: If search or fetch fails
     fn search_with_content(
         &mut self,
+        py: Python<'_>,
         query: &str,
         limit: usize,
         fetch_mode: &str,
@@ -385,38 +620,24 @@ impl PySearchEngine {
             ))
         })?;
 
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
-            ))
-        })?;
+        let rt = runtime()?;
 
-        rt.block_on(async {
-            self.inner
-                .search_with_content(query, limit, fetch_mode, format)
-                .await
+        py.allow_threads(|| {
+            rt.block_on(async {
+                self.inner
+                    .lock()
+                    .await
+                    .search_with_content(query, limit, fetch_mode, format)
+                    .await
+            })
         })
         .map(|results| {
             results
                 .into_iter()
-                .map(|(r, content)| {
-                    (
-                        PySearchResult {
-                            title: r.title,
-                            url: r.url,
-                            snippet: r.snippet,
-                            rank: r.rank,
-                        },
-                        content,
-                    )
-                })
+                .map(|(result, content)| (PySearchResult::from(result), content))
                 .collect()
         })
-        .map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Search and fetch failed for query '{query}': {e}"
-            ))
-        })
+        .map_err(|e| to_py_err(format!("Search and fetch failed for query '{query}'"), e))
     }
 
     /// Search using a proxy
@@ -431,36 +652,28 @@ impl PySearchEngine {
     ///     
     /// Raises:
     ///     ValueError: If mode is invalid
+    ///     RateLimitedError: If the search API answers 429 Too Many Requests
     ///     RuntimeError: If search fails
     fn search_with_proxy(
         &mut self,
+        py: Python<'_>,
         query: &str,
         limit: usize,
         proxy: &str,
     ) -> PyResult<Vec<PySearchResult>> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
-            ))
-        })?;
-
-        rt.block_on(async { self.inner.search_with_proxy(query, limit, proxy).await })
-            .map(|results| {
-                results
-                    .into_iter()
-                    .map(|r| PySearchResult {
-                        title: r.title,
-                        url: r.url,
-                        snippet: r.snippet,
-                        rank: r.rank,
-                    })
-                    .collect()
-            })
-            .map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Search with proxy failed for query '{query}': {e}"
-                ))
+        let rt = runtime()?;
+
+        py.allow_threads(|| {
+            rt.block_on(async {
+                self.inner
+                    .lock()
+                    .await
+                    .search_with_proxy(query, limit, proxy)
+                    .await
             })
+        })
+        .map(|results| results.into_iter().map(PySearchResult::from).collect())
+        .map_err(|e| to_py_err(format!("Search with proxy failed for query '{query}'"), e))
     }
 
     /// Shutdown browser and driver resources
@@ -473,14 +686,10 @@ impl PySearchEngine {
     ///     
     /// Raises:
     ///     RuntimeError: If shutdown fails
-    fn shutdown(&mut self) -> PyResult<()> {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to create async runtime: {e}"
-            ))
-        })?;
+    fn shutdown(&mut self, py: Python<'_>) -> PyResult<()> {
+        let rt = runtime()?;
 
-        rt.block_on(async { self.inner.shutdown().await });
+        py.allow_threads(|| rt.block_on(async { self.inner.lock().await.shutdown().await }));
         Ok(())
     }
 
@@ -509,10 +718,53 @@ pub struct PySearchResult {
     /// Search result rank (1-based)
     #[pyo3(get)]
     pub rank: usize,
+    /// Publication date, ISO-8601 when it could be parsed
+    #[pyo3(get)]
+    pub date: Option<String>,
+}
+
+impl From<crate::SearchResult> for PySearchResult {
+    fn from(result: crate::SearchResult) -> Self {
+        Self {
+            title: result.title,
+            url: result.url,
+            snippet: result.snippet,
+            rank: result.rank,
+            date: result.date,
+        }
+    }
+}
+
+impl PySearchResult {
+    fn to_search_result(&self) -> crate::SearchResult {
+        crate::SearchResult {
+            title: self.title.clone(),
+            url: self.url.clone(),
+            snippet: self.snippet.clone(),
+            rank: self.rank,
+            date: self.date.clone(),
+        }
+    }
 }
 
 #[pymethods]
 impl PySearchResult {
+    /// Registrable domain of the result URL, e.g. "bbc.co.uk"
+    ///
+    /// Returns:
+    ///     Optional[str]: The domain, or None if the URL cannot be parsed
+    fn domain(&self) -> Option<String> {
+        self.to_search_result().domain()
+    }
+
+    /// URL of the result site's favicon ("/favicon.ico" on the same host)
+    ///
+    /// Returns:
+    ///     Optional[str]: The favicon URL, or None if the URL cannot be parsed
+    fn favicon_url(&self) -> Option<String> {
+        self.to_search_result().favicon_url()
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "SearchResult(title='{}', url='{}', snippet='{}', rank={})",
@@ -549,7 +801,10 @@ impl PyConfig {
         }
     }
 
-    /// Load configuration from a TOML file
+    /// Load configuration from a file, like the CLI's `--config`: `.toml`,
+    /// `.yaml`/`.yml` or `.json` by extension. API keys set in the environment
+    /// (`TARZI_SERPAPI_KEY`, `TARZI_BING_API_KEY`, `TARZI_GOOGLE_CSE_KEY`) override
+    /// the file's.
     ///
     /// Args:
     ///     path (str): Path to configuration file
@@ -558,23 +813,12 @@ impl PyConfig {
     ///     Config: Configuration loaded from file
     ///     
     /// Raises:
-    ///     RuntimeError: If file cannot be read or parsed
+    ///     RuntimeError: If file cannot be read or parsed, has an unsupported
+    ///         extension, or its CA certificate cannot be loaded
     #[classmethod]
     fn from_file(_cls: &Bound<'_, PyType>, path: &str) -> PyResult<Self> {
-        use std::fs;
-
-        let content = fs::read_to_string(path).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to read config file '{path}': {e}"
-            ))
-        })?;
-
-        let config: Config = toml::from_str(&content).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to parse config file '{path}': {e}"
-            ))
-        })?;
-
+        let config = Config::load_from_path(std::path::Path::new(path))
+            .map_err(|e| to_py_err(format!("Failed to load config file '{path}'"), e))?;
         Ok(Self { inner: config })
     }
 
@@ -598,6 +842,140 @@ impl PyConfig {
         Ok(Self { inner: config })
     }
 
+    /// Serialize the configuration to TOML
+    ///
+    /// Returns:
+    ///     str: The configuration as a TOML document
+    ///
+    /// Raises:
+    ///     RuntimeError: If the configuration cannot be serialized
+    fn to_toml_string(&self) -> PyResult<String> {
+        toml::to_string_pretty(&self.inner).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to serialize config: {e}"
+            ))
+        })
+    }
+
+    /// Search engine, e.g. "duckduckgo" (`search.engine`). The engine also decides
+    /// whether results come from a search API or a scraped page, so there is no
+    /// separate `search_mode`.
+    #[getter]
+    fn search_engine(&self) -> String {
+        self.inner.search.engine.clone()
+    }
+
+    #[setter]
+    fn set_search_engine(&mut self, engine: &str) -> PyResult<()> {
+        SearchEngineType::from_str(engine).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid search engine '{engine}': {e}"
+            ))
+        })?;
+        self.inner.search.engine = engine.to_string();
+        Ok(())
+    }
+
+    /// Maximum number of search results (`search.limit`)
+    #[getter]
+    fn search_limit(&self) -> usize {
+        self.inner.search.limit
+    }
+
+    #[setter]
+    fn set_search_limit(&mut self, limit: usize) {
+        self.inner.search.limit = limit;
+    }
+
+    /// Fetch mode, e.g. "plain_request" (`fetcher.mode`)
+    #[getter]
+    fn fetcher_mode(&self) -> String {
+        self.inner.fetcher.mode.clone()
+    }
+
+    #[setter]
+    fn set_fetcher_mode(&mut self, mode: &str) -> PyResult<()> {
+        FetchMode::from_str(mode).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid fetch mode '{mode}': {e}"
+            ))
+        })?;
+        self.inner.fetcher.mode = mode.to_string();
+        Ok(())
+    }
+
+    /// Output format, e.g. "markdown" (`fetcher.format`)
+    #[getter]
+    fn fetcher_format(&self) -> String {
+        self.inner.fetcher.format.clone()
+    }
+
+    #[setter]
+    fn set_fetcher_format(&mut self, format: &str) -> PyResult<()> {
+        Format::from_str(format).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid format '{format}': {e}"
+            ))
+        })?;
+        self.inner.fetcher.format = format.to_string();
+        Ok(())
+    }
+
+    /// User-Agent header of plain requests (`fetcher.user_agent`)
+    #[getter]
+    fn user_agent(&self) -> String {
+        self.inner.fetcher.user_agent.clone()
+    }
+
+    #[setter]
+    fn set_user_agent(&mut self, user_agent: String) {
+        self.inner.fetcher.user_agent = user_agent;
+    }
+
+    /// Proxy URL, or None (`fetcher.proxy`)
+    #[getter]
+    fn proxy(&self) -> Option<String> {
+        self.inner.fetcher.proxy.clone()
+    }
+
+    #[setter]
+    fn set_proxy(&mut self, proxy: Option<String>) {
+        self.inner.fetcher.proxy = proxy;
+    }
+
+    /// SerpApi key, or None (`search.serpapi_key`)
+    #[getter]
+    fn serpapi_key(&self) -> Option<String> {
+        self.inner.search.serpapi_key.clone()
+    }
+
+    #[setter]
+    fn set_serpapi_key(&mut self, key: Option<String>) {
+        self.inner.search.serpapi_key = key;
+    }
+
+    /// Bing Web Search API subscription key, or None (`search.bing_api_key`)
+    #[getter]
+    fn bing_api_key(&self) -> Option<String> {
+        self.inner.search.bing_api_key.clone()
+    }
+
+    #[setter]
+    fn set_bing_api_key(&mut self, key: Option<String>) {
+        self.inner.search.bing_api_key = key;
+    }
+
+    /// Google Custom Search API key, or None (`search.google_cse_key`)
+    #[getter]
+    fn google_cse_key(&self) -> Option<String> {
+        self.inner.search.google_cse_key.clone()
+    }
+
+    #[setter]
+    fn set_google_cse_key(&mut self, key: Option<String>) {
+        self.inner.search.google_cse_key = key;
+    }
+
     fn __repr__(&self) -> String {
         "Config()".to_string()
     }
@@ -623,35 +1001,39 @@ mod tests {
 
     #[test]
     fn test_py_converter_convert_html() {
+        setup_python();
         let converter = PyConverter::new();
         let html = "<h1>Test</h1>";
-        let result = converter.convert(html, "html").unwrap();
+        let result = Python::with_gil(|py| converter.convert(py, html, "html")).unwrap();
         assert_eq!(result, html);
     }
 
     #[test]
     fn test_py_converter_convert_markdown() {
+        setup_python();
         let converter = PyConverter::new();
         let html = "<h1>Test</h1>";
-        let result = converter.convert(html, "markdown").unwrap();
+        let result = Python::with_gil(|py| converter.convert(py, html, "markdown")).unwrap();
         // The HTML to markdown conversion produces "# Test\n"
         assert!(result.contains("# Test") || result.contains("Test"));
     }
 
     #[test]
     fn test_py_converter_convert_json() {
+        setup_python();
         let converter = PyConverter::new();
         let html = "<h1>Test</h1><p>Content</p>";
-        let result = converter.convert(html, "json").unwrap();
+        let result = Python::with_gil(|py| converter.convert(py, html, "json")).unwrap();
         assert!(result.contains("Test"));
         assert!(result.contains("Content"));
     }
 
     #[test]
     fn test_py_converter_convert_yaml() {
+        setup_python();
         let converter = PyConverter::new();
         let html = "<h1>Test</h1><p>Content</p>";
-        let result = converter.convert(html, "yaml").unwrap();
+        let result = Python::with_gil(|py| converter.convert(py, html, "yaml")).unwrap();
         assert!(result.contains("Test"));
         assert!(result.contains("Content"));
     }
@@ -661,7 +1043,7 @@ mod tests {
         setup_python();
         let converter = PyConverter::new();
         let html = "<h1>Test</h1>";
-        let result = converter.convert(html, "invalid");
+        let result = Python::with_gil(|py| converter.convert(py, html, "invalid"));
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid format"));
     }
@@ -677,7 +1059,7 @@ mod tests {
         let config = PyConfig::new();
         // Just test that it can be created without panicking
         let _fetcher = PyWebFetcher {
-            inner: WebFetcher::from_config(&config.inner),
+            inner: Arc::new(Mutex::new(WebFetcher::from_config(&config.inner))),
         };
     }
 
@@ -691,9 +1073,8 @@ mod tests {
     fn test_py_searchengine_from_config() {
         let config = PyConfig::new();
         // Just test that it can be created without panicking
-        let _engine = PySearchEngine {
-            inner: SearchEngine::from_config(&config.inner),
-        };
+        let engine = PySearchEngine::from_engine(SearchEngine::from_config(&config.inner));
+        assert_eq!(engine.default_limit, config.inner.search.limit);
     }
 
     #[test]
@@ -703,11 +1084,17 @@ mod tests {
             url: "https://example.com".to_string(),
             snippet: "Test snippet".to_string(),
             rank: 1,
+            date: None,
         };
         assert_eq!(result.title, "Test Title");
         assert_eq!(result.url, "https://example.com");
         assert_eq!(result.snippet, "Test snippet");
         assert_eq!(result.rank, 1);
+        assert_eq!(result.domain().as_deref(), Some("example.com"));
+        assert_eq!(
+            result.favicon_url().as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
     }
 
     #[test]
@@ -717,6 +1104,7 @@ mod tests {
             url: "https://example.com".to_string(),
             snippet: "Test snippet".to_string(),
             rank: 1,
+            date: None,
         };
         let repr = result.__repr__();
         assert!(repr.contains("Test Title"));
@@ -732,6 +1120,7 @@ mod tests {
             url: "https://example.com".to_string(),
             snippet: "Test snippet".to_string(),
             rank: 1,
+            date: None,
         };
         let str_repr = result.__str__();
         assert!(str_repr.contains("[1]"));
@@ -747,6 +1136,7 @@ mod tests {
             url: "https://example.com".to_string(),
             snippet: "Test snippet".to_string(),
             rank: 1,
+            date: None,
         };
         let cloned = result.clone();
         assert_eq!(result.title, cloned.title);
@@ -1,35 +1,94 @@
 use super::parser::ParserFactory;
-use super::types::{SearchEngineType, SearchResult};
+use super::types::{
+    AutoSwitchStrategy, DomainFilter, EngineInfo, SafeSearch, SearchEngineType, SearchQuery,
+    SearchResponse, SearchResult,
+};
 use crate::config::Config;
 use crate::{
     Result,
     error::TarziError,
     fetcher::{FetchMode, WebFetcher},
 };
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
-use crate::constants::DEFAULT_QUERY_PATTERN;
+use super::providers::{ApiKeyPool, ProviderConfig, ProviderVariant, fetch_api, fetch_open_api};
+use crate::constants::{
+    COUNTRY_SETTING, DEFAULT_FETCH_CONCURRENCY, DEFAULT_MAX_SEARCH_LIMIT, DEFAULT_QUERY_PATTERN,
+    DEFAULT_SEARCH_LIMIT, DEFAULT_SERPAPI_ENGINE, FETCH_MODE_SETTING, GOOGLE_CSE_CX_SETTING,
+    LANGUAGE_SETTING, MAX_SEARCH_PAGES, RRF_K, SAFE_SEARCH_SETTING, SEARCH_ENGINE_SEARXNG,
+    SEARXNG_ENGINES_SETTING, SERPAPI_ENGINE_SETTING,
+};
+use futures::{StreamExt, stream};
 use tracing::{info, warn};
 
 pub struct SearchEngine {
     fetcher: WebFetcher,
+    /// Settings the fetchers of per-engine providers are built from
+    config: Config,
     engine_type: SearchEngineType,
     query_pattern: String,
     user_agent: String,
     parser_factory: ParserFactory,
     fetch_mode: FetchMode,
+    serpapi_keys: ApiKeyPool,
+    serpapi_engine: String,
+    bing_api_keys: ApiKeyPool,
+    google_cse_keys: ApiKeyPool,
+    google_cse_cx: Option<String>,
+    searxng_base_url: Option<String>,
+    searxng_engines: Vec<String>,
+    /// Base URLs replacing engines' default domains, keyed by engine name
+    provider_urls: HashMap<String, String>,
+    /// When to retry a search with the next of `fallback_engines`
+    autoswitch: AutoSwitchStrategy,
+    fallback_engines: Vec<SearchEngineType>,
+    /// Country and language results are localized for, where the engine supports it
+    country: Option<String>,
+    language: Option<String>,
+    safe_search: SafeSearch,
+    default_limit: usize,
+    max_limit: usize,
+    domain_filter: DomainFilter,
+    /// Snippets are cut after this many characters; 0 keeps them whole
+    max_snippet_len: usize,
+    /// Result pages fetched at once by [`Self::search_with_content`] in plain request mode
+    fetch_concurrency: usize,
 }
 
 impl SearchEngine {
     pub fn new() -> Self {
-        // Initialize SearchEngine with default configuration
+        Self::with_client(WebFetcher::default_http_client())
+    }
+
+    /// Create a SearchEngine with default settings that reuses an existing HTTP client
+    pub fn with_client(http_client: reqwest::Client) -> Self {
         Self {
-            fetcher: WebFetcher::new(),
+            fetcher: WebFetcher::with_client(http_client),
+            config: Config::new(),
             engine_type: SearchEngineType::Bing,
             query_pattern: SearchEngineType::Bing.get_query_pattern(),
             user_agent: crate::constants::DEFAULT_USER_AGENT.to_string(),
             parser_factory: ParserFactory::new(),
             fetch_mode: FetchMode::BrowserHeadless, // Default mode
+            serpapi_keys: ApiKeyPool::default(),
+            serpapi_engine: DEFAULT_SERPAPI_ENGINE.to_string(),
+            bing_api_keys: ApiKeyPool::default(),
+            google_cse_keys: ApiKeyPool::default(),
+            google_cse_cx: None,
+            searxng_base_url: None,
+            searxng_engines: Vec::new(),
+            provider_urls: HashMap::new(),
+            autoswitch: AutoSwitchStrategy::default(),
+            fallback_engines: Vec::new(),
+            country: None,
+            language: None,
+            safe_search: SafeSearch::default(),
+            default_limit: DEFAULT_SEARCH_LIMIT,
+            max_limit: DEFAULT_MAX_SEARCH_LIMIT,
+            domain_filter: DomainFilter::default(),
+            max_snippet_len: 0,
+            fetch_concurrency: DEFAULT_FETCH_CONCURRENCY,
         }
     }
 
@@ -46,58 +105,621 @@ impl SearchEngine {
         &self.user_agent
     }
 
+    /// Get the HTTP client shared with the underlying fetcher
+    pub fn http_client(&self) -> &reqwest::Client {
+        self.fetcher.http_client()
+    }
+
+    /// Share `cache` with this engine's fetcher, e.g. across engines serving a server
+    pub fn with_shared_cache(mut self, cache: crate::fetcher::FetchCache) -> Self {
+        self.fetcher.set_cache(Some(cache));
+        self
+    }
+
+    /// Number of results used by queries built with [`Self::query`]
+    pub fn default_limit(&self) -> usize {
+        self.default_limit
+    }
+
+    /// Set the number of results used by queries built with [`Self::query`]
+    pub fn set_default_limit(&mut self, limit: usize) {
+        self.default_limit = limit;
+    }
+
+    /// Upper bound applied to the limit of every search
+    pub fn max_limit(&self) -> usize {
+        self.max_limit
+    }
+
+    /// Domain allow/deny lists applied to every search
+    pub fn domain_filter(&self) -> &DomainFilter {
+        &self.domain_filter
+    }
+
+    /// Set the domain allow/deny lists applied to every search
+    pub fn set_domain_filter(&mut self, filter: DomainFilter) {
+        self.domain_filter = filter;
+    }
+
+    /// Every supported engine with its capabilities and whether the API key it
+    /// needs is configured, e.g. to offer only usable engines in a UI
+    pub fn available_engines(&self) -> Vec<EngineInfo> {
+        SearchEngineType::ALL
+            .into_iter()
+            .map(|engine_type| EngineInfo {
+                engine_type,
+                supports_web: !engine_type.is_api(),
+                supports_api: engine_type.is_api(),
+                requires_api_key: engine_type.requires_api_key(),
+                has_key_configured: self
+                    .api_keys(engine_type)
+                    .is_none_or(|keys| !keys.is_empty())
+                    && (engine_type != SearchEngineType::GoogleCse || self.google_cse_cx.is_some()),
+            })
+            .collect()
+    }
+
+    /// Probe the provider of every supported engine: API providers must have their
+    /// keys and settings configured, web providers additionally need a reachable
+    /// WebDriver unless pages are fetched with plain requests
+    pub async fn health_check(&self) -> Vec<(SearchEngineType, bool)> {
+        let webdriver_available =
+            self.fetch_mode == FetchMode::PlainRequest || self.fetcher.is_driver_reachable().await;
+        SearchEngineType::ALL
+            .into_iter()
+            .map(|engine_type| {
+                let healthy = ProviderVariant::from_engine_type(
+                    engine_type,
+                    self.provider_config(engine_type),
+                )
+                .is_ok_and(|provider| provider.is_healthy(webdriver_available));
+                (engine_type, healthy)
+            })
+            .collect()
+    }
+
+    /// Settings for a standalone provider of `engine_type`, sharing this engine's HTTP client
+    fn provider_config(&self, engine_type: SearchEngineType) -> ProviderConfig {
+        let base_url = if engine_type == SearchEngineType::SearxNG {
+            self.searxng_base_url.clone()
+        } else {
+            self.provider_urls.get(engine_type.name()).cloned()
+        };
+        let mut extra = HashMap::new();
+        if let Some(cx) = &self.google_cse_cx {
+            extra.insert(GOOGLE_CSE_CX_SETTING.to_string(), cx.clone());
+        }
+        if !self.searxng_engines.is_empty() {
+            extra.insert(
+                SEARXNG_ENGINES_SETTING.to_string(),
+                self.searxng_engines.join(","),
+            );
+        }
+        if let Some(country) = &self.country {
+            extra.insert(COUNTRY_SETTING.to_string(), country.clone());
+        }
+        if let Some(language) = &self.language {
+            extra.insert(LANGUAGE_SETTING.to_string(), language.clone());
+        }
+        extra.insert(
+            SAFE_SEARCH_SETTING.to_string(),
+            self.safe_search.as_str().to_string(),
+        );
+        extra.insert(
+            SERPAPI_ENGINE_SETTING.to_string(),
+            self.serpapi_engine.clone(),
+        );
+        extra.insert(
+            FETCH_MODE_SETTING.to_string(),
+            self.fetch_mode.as_str().to_string(),
+        );
+        ProviderConfig {
+            fetcher: Box::new(search_fetcher(&self.config, self.http_client().clone())),
+            api_keys: self
+                .api_keys(engine_type)
+                .map(|keys| keys.keys().to_vec())
+                .unwrap_or_default(),
+            base_url,
+            extra,
+        }
+    }
+
+    /// Keys configured for an API engine, or `None` for engines without one
+    fn api_keys(&self, engine_type: SearchEngineType) -> Option<&ApiKeyPool> {
+        match engine_type {
+            SearchEngineType::SerpApi => Some(&self.serpapi_keys),
+            SearchEngineType::BingApi => Some(&self.bing_api_keys),
+            SearchEngineType::GoogleCse => Some(&self.google_cse_keys),
+            _ => None,
+        }
+    }
+
+    /// Start building a query that uses this engine's default limit
+    pub fn query(&self, query: impl Into<String>) -> SearchQuery {
+        SearchQuery::new(query).limit(self.default_limit)
+    }
+
     // Custom parser registration removed - custom engines are no longer supported
 
     pub fn from_config(config: &Config) -> Self {
-        let fetcher = crate::fetcher::WebFetcher::from_config(config);
+        let http_client = WebFetcher::build_http_client(config);
+        Self::from_config_with_client(config, http_client)
+    }
+
+    /// Create a SearchEngine from config that reuses an existing HTTP client
+    pub fn from_config_with_client(config: &Config, http_client: reqwest::Client) -> Self {
+        let fetcher = search_fetcher(config, http_client);
 
         // Parse the search engine type from config
         let engine_type =
             SearchEngineType::from_str(&config.search.engine).unwrap_or(SearchEngineType::Bing);
 
+        // SearxNG has no public default instance; its address is configured
+        let searxng_base_url = config
+            .search
+            .searxng_base_url
+            .as_ref()
+            .or_else(|| config.search.provider_urls.get(SEARCH_ENGINE_SEARXNG))
+            .filter(|base_url| !base_url.is_empty())
+            .cloned();
+
         // Use custom query pattern if provided, otherwise use the default for the engine type
         let query_pattern = if config.search.query_pattern != DEFAULT_QUERY_PATTERN {
             // If a custom query pattern is explicitly set in config, use it
             config.search.query_pattern.clone()
         } else {
-            // Otherwise use the engine-specific pattern
-            engine_type.get_query_pattern()
+            Self::engine_query_pattern(
+                engine_type,
+                searxng_base_url.as_deref(),
+                &config.search.provider_urls,
+            )
         };
 
+        let autoswitch =
+            AutoSwitchStrategy::from_str(&config.search.autoswitch).unwrap_or_else(|e| {
+                warn!("{e}, disabling autoswitch");
+                AutoSwitchStrategy::None
+            });
+        let fallback_engines = config
+            .search
+            .fallback_engines
+            .iter()
+            .filter_map(|name| match SearchEngineType::from_str(name) {
+                Ok(engine_type) => Some(engine_type),
+                Err(e) => {
+                    warn!("Ignoring fallback engine: {e}");
+                    None
+                }
+            })
+            .collect();
+
+        let safe_search = SafeSearch::from_str(&config.search.safe_search).unwrap_or_else(|e| {
+            warn!("{e}, using moderate safe search");
+            SafeSearch::Moderate
+        });
+
         // Parse fetch mode from config
         let fetch_mode =
             FetchMode::from_str(&config.fetcher.mode).unwrap_or(FetchMode::BrowserHeadless);
 
         Self {
             fetcher,
+            config: config.clone(),
             engine_type,
             query_pattern,
             user_agent: config.fetcher.user_agent.clone(),
             parser_factory: ParserFactory::new(),
             fetch_mode,
+            serpapi_keys: ApiKeyPool::new(
+                config
+                    .search
+                    .serpapi_key
+                    .iter()
+                    .chain(&config.search.serpapi_keys)
+                    .cloned(),
+            ),
+            serpapi_engine: config.search.serpapi_engine.clone(),
+            bing_api_keys: ApiKeyPool::new(config.search.bing_api_key.clone()),
+            google_cse_keys: ApiKeyPool::new(config.search.google_cse_key.clone()),
+            google_cse_cx: config
+                .search
+                .google_cse_cx
+                .clone()
+                .filter(|cx| !cx.is_empty()),
+            searxng_base_url,
+            searxng_engines: config.search.searxng_engines.clone(),
+            provider_urls: config.search.provider_urls.clone(),
+            autoswitch,
+            fallback_engines,
+            country: config.search.country.clone(),
+            language: config.search.language.clone(),
+            safe_search,
+            default_limit: config.search.limit,
+            max_limit: config.search.max_limit,
+            domain_filter: DomainFilter::new(
+                &config.search.include_domains,
+                &config.search.exclude_domains,
+            ),
+            max_snippet_len: config.search.max_snippet_len,
+            fetch_concurrency: config.fetcher.concurrency,
+        }
+    }
+
+    /// Query pattern for `engine_type`, on a configured base URL where there is one
+    fn engine_query_pattern(
+        engine_type: SearchEngineType,
+        searxng_base_url: Option<&str>,
+        provider_urls: &HashMap<String, String>,
+    ) -> String {
+        if engine_type == SearchEngineType::SearxNG
+            && let Some(base_url) = searxng_base_url
+        {
+            engine_type.query_pattern_with_base(base_url)
+        } else if let Some(base_url) = provider_urls.get(engine_type.name()) {
+            // A configured mirror or regional domain replaces the engine's default one
+            engine_type.query_pattern_with_base(base_url)
+        } else {
+            // Otherwise use the engine-specific pattern
+            engine_type.get_query_pattern()
         }
     }
 
     pub async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        self.search_query(SearchQuery::new(query).limit(limit))
+            .await
+    }
+
+    /// Search using a pre-built [`SearchQuery`] carrying optional parameters
+    pub async fn search_query(&mut self, query: SearchQuery) -> Result<Vec<SearchResult>> {
+        Ok(self.search_response(query).await?.results)
+    }
+
+    /// Search for results from a single site, e.g. `docs.rust-lang.org`, using the
+    /// engine's site operator
+    pub async fn search_site(
+        &mut self,
+        query: &str,
+        site: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_query(SearchQuery::new(query).site(site).limit(limit))
+            .await
+    }
+
+    /// Search and also extract the engine's related queries from the results page.
+    ///
+    /// The limit is capped at `search.max_limit`; results beyond one page are
+    /// collected by requesting further pages where the engine supports it.
+    /// Results removed by the domain filter don't count toward the limit: further
+    /// pages are requested to make up for them, so fewer results are returned only
+    /// when the engine can't paginate, runs out of results, or `MAX_SEARCH_PAGES`
+    /// pages have been fetched.
+    ///
+    /// When the search fails or comes back empty, `search.fallback_engines` are
+    /// tried in order as allowed by the `search.autoswitch` strategy.
+    pub async fn search_response(&mut self, query: SearchQuery) -> Result<SearchResponse> {
+        let primary_engine = self.engine_type;
+        let primary_pattern = self.query_pattern.clone();
+        let fallbacks: Vec<SearchEngineType> = if self.autoswitch == AutoSwitchStrategy::None {
+            Vec::new()
+        } else {
+            self.fallback_engines
+                .iter()
+                .copied()
+                .filter(|engine_type| *engine_type != primary_engine)
+                .collect()
+        };
+
+        let mut outcome = self.search_engine_response(&query).await;
+        for engine_type in fallbacks {
+            let reason = match &outcome {
+                Ok(response)
+                    if response.results.is_empty() && self.autoswitch.switches_on_empty() =>
+                {
+                    "returned no results".to_string()
+                }
+                Err(e) if self.autoswitch.switches_on_error() => format!("failed: {e}"),
+                _ => break,
+            };
+            warn!(
+                "{:?} {}, switching to {:?}",
+                self.engine_type, reason, engine_type
+            );
+            self.engine_type = engine_type;
+            self.query_pattern = Self::engine_query_pattern(
+                engine_type,
+                self.searxng_base_url.as_deref(),
+                &self.provider_urls,
+            );
+            outcome = self.search_engine_response(&query).await;
+        }
+
+        if let Ok(response) = &outcome {
+            info!(
+                "{} results from {:?}",
+                response.results.len(),
+                self.engine_type
+            );
+        }
+        self.engine_type = primary_engine;
+        self.query_pattern = primary_pattern;
+        outcome
+    }
+
+    /// Run a search against the current engine only
+    async fn search_engine_response(&mut self, query: &SearchQuery) -> Result<SearchResponse> {
+        let limit = if query.limit > self.max_limit {
+            warn!(
+                "Requested {} results, clamping to the configured maximum of {}",
+                query.limit, self.max_limit
+            );
+            self.max_limit
+        } else {
+            query.limit
+        };
+        let page_size = self.engine_type.max_results_per_page();
+        let paginates = self.engine_type.page_param(0).is_some();
+        let filtering = !self.domain_filter.is_empty();
+
+        // Start from the page containing `offset` when the engine can jump to it. With
+        // a domain filter the offset counts filtered results, so start from the top.
+        let mut page_start = if paginates && !filtering {
+            query.offset / page_size * page_size
+        } else {
+            0
+        };
+        let first_start = page_start;
+        let skip = query.offset - page_start;
+        let needed = skip.saturating_add(limit);
+
+        let mut results: Vec<SearchResult> = Vec::new();
+        let mut related_queries = Vec::new();
+        let mut seen_urls = HashSet::new();
+        for page_number in 1.. {
+            let content = self.fetch_search_page(query, page_start).await?;
+            if related_queries.is_empty() {
+                related_queries = self
+                    .parser_factory
+                    .get_parser(&self.engine_type)
+                    .parse_related_queries(&content);
+            }
+
+            // Filtered-out results don't count toward the limit, so keep whole pages
+            let page_limit = if filtering {
+                usize::MAX
+            } else {
+                needed - results.len()
+            };
+            let page = self.extract_search_results_from_html(&content, page_limit)?;
+            let mut new_results = false;
+            for result in page {
+                if !seen_urls.insert(result.url.clone()) {
+                    continue;
+                }
+                new_results = true;
+                if self.domain_filter.allows(&result.url) {
+                    results.push(result);
+                }
+            }
+
+            // Stop when satisfied, on a page without new results, or without pagination
+            if results.len() >= needed
+                || !new_results
+                || !paginates
+                || page_number >= MAX_SEARCH_PAGES
+            {
+                break;
+            }
+            page_start += page_size;
+        }
+
+        let results = results
+            .into_iter()
+            .enumerate()
+            .skip(skip)
+            .take(limit)
+            .map(|(i, mut result)| {
+                result.truncate_snippet(self.max_snippet_len);
+                SearchResult {
+                    rank: first_start + i + 1,
+                    ..result
+                }
+            })
+            .collect();
+
+        Ok(SearchResponse {
+            results,
+            related_queries,
+        })
+    }
+
+    /// Search several engines at once and fuse their results into one list with
+    /// reciprocal rank fusion: a page scores `1 / (RRF_K + rank)` for every engine
+    /// that returned it, so pages found by several engines rise to the top. Pages
+    /// are recognized across engines by [`SearchResult::normalized_url`].
+    ///
+    /// Engines are queried concurrently through their providers. An engine that
+    /// fails is logged and left out; the call only fails when every engine does.
+    pub async fn search_multi(
+        &mut self,
+        query: &str,
+        engines: &[SearchEngineType],
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let limit = limit.min(self.max_limit);
+        let this = &*self;
+        let searches = engines.iter().map(|&engine_type| async move {
+            let mut provider =
+                ProviderVariant::from_engine_type(engine_type, this.provider_config(engine_type))?;
+            let results = provider.search(query, limit).await;
+            provider.shutdown().await;
+            results
+        });
+        let outcomes = futures::future::join_all(searches).await;
+
+        let mut ranked_lists = Vec::new();
+        let mut last_error = None;
+        for (engine_type, outcome) in engines.iter().zip(outcomes) {
+            match outcome {
+                Ok(mut results) => {
+                    info!("{} results from {:?}", results.len(), engine_type);
+                    results.retain(|result| self.domain_filter.allows(&result.url));
+                    for result in &mut results {
+                        result.truncate_snippet(self.max_snippet_len);
+                    }
+                    ranked_lists.push(results);
+                }
+                Err(e) => {
+                    warn!("{:?} failed, leaving it out: {}", engine_type, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        if ranked_lists.is_empty()
+            && let Some(e) = last_error
+        {
+            return Err(e);
+        }
+        Ok(reciprocal_rank_fusion(ranked_lists, limit))
+    }
+
+    /// Fetch the unparsed search results page for a query, for debugging parsers
+    /// or capturing fixtures. Feed the content back through [`Self::parse_raw`].
+    pub async fn search_raw(&mut self, query: &str) -> Result<String> {
+        self.fetch_search_page(&SearchQuery::new(query), 0).await
+    }
+
+    /// Parse previously captured search results page content with the parser for `engine_type`
+    pub fn parse_raw(
+        content: &str,
+        engine_type: SearchEngineType,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        ParserFactory::new()
+            .get_parser(&engine_type)
+            .parse(content, limit)
+    }
+
+    /// Fetch the results page starting at the zero-based result index `page_start`
+    async fn fetch_search_page(
+        &mut self,
+        query: &SearchQuery,
+        page_start: usize,
+    ) -> Result<String> {
+        let search_url = self.build_search_url(query, page_start);
+
+        if let Some(keys) = self.api_keys(self.engine_type) {
+            if self.engine_type == SearchEngineType::GoogleCse && self.google_cse_cx.is_none() {
+                return Err(TarziError::Config(
+                    "search.google_cse_cx is required for the google_cse engine".to_string(),
+                ));
+            }
+            info!("API search: {}", search_url);
+            return fetch_api(
+                self.fetcher.http_client(),
+                &search_url,
+                self.engine_type,
+                keys,
+            )
+            .await;
+        }
+        if self.engine_type.is_api() {
+            if self.engine_type == SearchEngineType::SearxNG && self.searxng_base_url.is_none() {
+                return Err(TarziError::Config(
+                    "search.searxng_base_url is required for the searxng engine".to_string(),
+                ));
+            }
+            info!("API search: {}", search_url);
+            return fetch_open_api(self.fetcher.http_client(), &search_url, self.engine_type).await;
+        }
+
+        // Use configured fetch mode for search. Failures keep their kind, so callers
+        // can still tell network errors, timeouts and rate limiting apart.
+        self.fetch_with_retry(&search_url, self.fetch_mode)
+            .await
+            .inspect_err(|e| warn!("Fetching search page {} failed: {}", search_url, e))
+    }
+
+    /// Build the search URL for a query from the configured query pattern, for the
+    /// results page starting at the zero-based result index `page_start`
+    fn build_search_url(&self, query: &SearchQuery, page_start: usize) -> String {
+        let site_query = query.site.as_deref().and_then(|site| {
+            let site_query = self.engine_type.site_query(&query.query, site);
+            if site_query.is_none() {
+                warn!(
+                    "Site restriction is not supported by {:?}, ignoring",
+                    self.engine_type
+                );
+            }
+            site_query
+        });
+        let query_text = site_query.as_deref().unwrap_or(&query.query);
+
         // Use the query pattern from config to build the search URL
-        let search_url = self
+        let mut search_url = self
             .query_pattern
-            .replace("{query}", &urlencoding::encode(query));
-
-        // Use configured fetch mode for search
-        let search_page_content = match self.fetch_with_retry(&search_url, self.fetch_mode).await {
-            Ok(content) => content,
-            Err(browser_error) => {
-                return Err(TarziError::Search(format!(
-                    "Browser mode failed: {browser_error}"
-                )));
+            .replace("{engine}", &urlencoding::encode(&self.serpapi_engine))
+            .replace(
+                "{cx}",
+                &urlencoding::encode(self.google_cse_cx.as_deref().unwrap_or_default()),
+            )
+            .replace("{query}", &urlencoding::encode(query_text));
+
+        // A query's language takes precedence over the configured one
+        let region_params = self.engine_type.region_params(
+            self.country.as_deref(),
+            query.language.as_deref().or(self.language.as_deref()),
+        );
+        if query.language.is_some() && self.engine_type.language_param().is_none() {
+            warn!(
+                "Language selection is not supported by {:?}, ignoring",
+                self.engine_type
+            );
+        }
+        let mut params: Vec<(&str, &str)> = region_params
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect();
+        if let Some(param) = self.engine_type.safe_search_param(self.safe_search) {
+            params.push(param);
+        }
+        if let Some(time_range) = query.time_range {
+            match self.engine_type.time_range_param(time_range) {
+                Some(param) => params.push(param),
+                None => warn!(
+                    "Time range filtering is not supported by {:?}, ignoring",
+                    self.engine_type
+                ),
             }
+        }
+        let page_param = if page_start > 0 {
+            self.engine_type.page_param(page_start)
+        } else {
+            None
         };
+        if let Some((key, value)) = &page_param {
+            params.push((key, value.as_str()));
+        }
+        let searxng_engines = self.searxng_engines.join(",");
+        if self.engine_type == SearchEngineType::SearxNG && !searxng_engines.is_empty() {
+            params.push(("engines", &searxng_engines));
+        }
+        params.extend(
+            query
+                .extra_params
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str())),
+        );
 
-        // Extract search results from the HTML content using web parser
-        let results = self.extract_search_results_from_html(&search_page_content, limit)?;
+        for (key, value) in params {
+            let separator = if search_url.contains('?') { '&' } else { '?' };
+            search_url.push(separator);
+            search_url.push_str(&urlencoding::encode(key));
+            search_url.push('=');
+            search_url.push_str(&urlencoding::encode(value));
+        }
 
-        Ok(results)
+        search_url
     }
 
     async fn fetch_with_retry(&mut self, url: &str, fetch_mode: FetchMode) -> Result<String> {
@@ -114,7 +736,8 @@ impl SearchEngine {
                 }
                 Err(e) => {
                     let error_str = e.to_string();
-                    let is_network_error = error_str.contains("nssFailure")
+                    let is_network_error = matches!(e, TarziError::Timeout(_))
+                        || error_str.contains("nssFailure")
                         || error_str.contains("network")
                         || error_str.contains("timeout")
                         || error_str.contains("connection");
@@ -152,7 +775,9 @@ impl SearchEngine {
         Ok(results)
     }
 
-    /// Search and fetch content for each result
+    /// Search and fetch content for each result, in search order. Results whose
+    /// content can't be fetched are skipped. In plain request mode up to
+    /// `fetcher.concurrency` pages are fetched at once; browser fetches are serial.
     pub async fn search_with_content(
         &mut self,
         query: &str,
@@ -160,6 +785,29 @@ impl SearchEngine {
         fetch_mode: FetchMode,
         format: crate::converter::Format,
     ) -> Result<Vec<(SearchResult, String)>> {
+        let mut results_with_content = Vec::new();
+        self.search_with_content_each(query, limit, fetch_mode, format, |result, content| {
+            results_with_content.push((result, content));
+            Ok(())
+        })
+        .await?;
+        Ok(results_with_content)
+    }
+
+    /// Like [`Self::search_with_content`], but hands each result to `on_result` as
+    /// soon as its content is fetched instead of collecting them, so callers can
+    /// write large outputs incrementally. Returns the number of results handed over.
+    pub async fn search_with_content_each<F>(
+        &mut self,
+        query: &str,
+        limit: usize,
+        fetch_mode: FetchMode,
+        format: crate::converter::Format,
+        mut on_result: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(SearchResult, String) -> Result<()>,
+    {
         // For web search, use the provided fetch_mode or default to browser_headless
         let effective_fetch_mode = if matches!(fetch_mode, FetchMode::PlainRequest) {
             FetchMode::PlainRequest
@@ -169,18 +817,44 @@ impl SearchEngine {
 
         // First, perform the search
         let search_results = self.search(query, limit).await?;
+        let mut count = 0;
 
-        // Then, fetch content for each result using the effective fetch mode
-        let mut results_with_content = Vec::new();
+        if effective_fetch_mode == FetchMode::PlainRequest {
+            // Plain requests share the fetcher, so several run at once; `buffered`
+            // still hands the results over in search order as they complete
+            let fetcher = &self.fetcher;
+            let mut fetches = stream::iter(search_results)
+                .map(|result| async move {
+                    let content = fetcher.fetch_plain(&result.url, format).await;
+                    (result, content)
+                })
+                .buffered(self.fetch_concurrency.max(1));
+            while let Some((result, content)) = fetches.next().await {
+                match content {
+                    Ok(content) => {
+                        on_result(result, content)?;
+                        count += 1;
+                    }
+                    Err(e) => warn!("Failed to fetch content for {}: {}", result.url, e),
+                }
+            }
+            return Ok(count);
+        }
 
-        for result in search_results.clone() {
+        // Browser fetches drive a single browser session, one result at a time
+        let mut handed_over = Ok(());
+        for result in search_results {
             match self
                 .fetcher
                 .fetch(&result.url, effective_fetch_mode, format)
                 .await
             {
                 Ok(content) => {
-                    results_with_content.push((result, content));
+                    handed_over = on_result(result, content);
+                    if handed_over.is_err() {
+                        break;
+                    }
+                    count += 1;
                 }
                 Err(e) => {
                     warn!("Failed to fetch content for {}: {}", result.url, e);
@@ -189,7 +863,11 @@ impl SearchEngine {
             }
         }
 
-        Ok(results_with_content)
+        // With `reuse_browser` the browser and driver only live for this run
+        if self.fetcher.reuse_browser() {
+            self.fetcher.shutdown().await;
+        }
+        handed_over.map(|()| count)
     }
 
     pub async fn search_with_proxy(
@@ -230,6 +908,53 @@ impl Default for SearchEngine {
     }
 }
 
+/// Fetcher for search pages and their results. Both live on third-party hosts,
+/// so the configured fetcher credentials are never sent with them.
+fn search_fetcher(config: &Config, http_client: reqwest::Client) -> WebFetcher {
+    let mut fetcher = WebFetcher::from_config_with_client(config, http_client);
+    fetcher.set_auth(None);
+    fetcher
+}
+
+/// Merge ranked result lists, one per engine, into a single list ordered by
+/// reciprocal rank fusion score and truncated to `limit`. Pages returned by
+/// several engines are kept once, as first returned; ties keep the order in
+/// which pages were first seen.
+fn reciprocal_rank_fusion(ranked_lists: Vec<Vec<SearchResult>>, limit: usize) -> Vec<SearchResult> {
+    let mut fused: Vec<(f64, SearchResult)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for results in ranked_lists {
+        let mut seen = HashSet::new();
+        for (i, result) in results.into_iter().enumerate() {
+            let key = result.normalized_url();
+            // An engine repeating a page only counts its best rank
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+            let score = 1.0 / (RRF_K + (i + 1) as f64);
+            match positions.get(&key) {
+                Some(&position) => fused[position].0 += score,
+                None => {
+                    positions.insert(key, fused.len());
+                    fused.push((score, result));
+                }
+            }
+        }
+    }
+
+    // A stable sort keeps first-seen order between equal scores
+    fused.sort_by(|a, b| b.0.total_cmp(&a.0));
+    fused
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(|(i, (_, result))| SearchResult {
+            rank: i + 1,
+            ..result
+        })
+        .collect()
+}
+
 impl Drop for SearchEngine {
     fn drop(&mut self) {
         info!("SearchEngine dropping - cleanup will be handled by WebFetcher");
@@ -240,6 +965,7 @@ impl Drop for SearchEngine {
 mod tests {
     use super::*;
     use crate::constants::*;
+    use crate::search::test_server::{Request, Response, serve};
 
     #[test]
     fn test_search_engine_default() {
@@ -289,6 +1015,771 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_engine_with_client() {
+        let config = crate::config::Config::new();
+        let client = WebFetcher::build_http_client(&config);
+
+        let engine = SearchEngine::with_client(client.clone());
+        assert_eq!(engine.engine_type(), &SearchEngineType::Bing);
+
+        let engine = SearchEngine::from_config_with_client(&config, client);
+        assert_eq!(engine.engine_type(), &SearchEngineType::Bing);
+        assert_eq!(engine.user_agent(), config.fetcher.user_agent);
+    }
+
+    #[test]
+    fn test_build_search_url() {
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_GOOGLE.to_string();
+        let engine = SearchEngine::from_config(&config);
+
+        let query = SearchQuery::new("rust lang");
+        assert_eq!(
+            engine.build_search_url(&query, 0),
+            "https://www.google.com/search?q=rust%20lang"
+        );
+
+        let query = SearchQuery::new("rust")
+            .language("en")
+            .time_range(crate::search::TimeRange::Day)
+            .param("num", "20");
+        assert_eq!(
+            engine.build_search_url(&query, 0),
+            "https://www.google.com/search?q=rust&hl=en&tbs=qdr%3Ad&num=20"
+        );
+
+        // Unsupported parameters are dropped rather than sent to the engine
+        config.search.engine = SEARCH_ENGINE_BAIDU.to_string();
+        let engine = SearchEngine::from_config(&config);
+        let query = SearchQuery::new("rust").language("zh");
+        assert_eq!(
+            engine.build_search_url(&query, 0),
+            "https://www.baidu.com/s?wd=rust"
+        );
+
+        // SerpApi substitutes the engine it should query; the key is added at request time
+        config.search.engine = SEARCH_ENGINE_SERPAPI.to_string();
+        config.search.serpapi_engine = "bing".to_string();
+        config.search.serpapi_key = Some("secret".to_string());
+        let engine = SearchEngine::from_config(&config);
+        let url = engine.build_search_url(&SearchQuery::new("rust lang"), 0);
+        assert_eq!(
+            url,
+            "https://serpapi.com/search.json?engine=bing&q=rust%20lang"
+        );
+        assert!(!url.contains("secret"));
+
+        // The Bing API key is sent in a header, never in the URL
+        config.search.engine = SEARCH_ENGINE_BING_API.to_string();
+        config.search.bing_api_key = Some("azure-key".to_string());
+        let engine = SearchEngine::from_config(&config);
+        let query = SearchQuery::new("rust").language("en");
+        assert_eq!(
+            engine.build_search_url(&query, 10),
+            "https://api.bing.microsoft.com/v7.0/search?q=rust&setLang=en&offset=10"
+        );
+
+        // Google CSE takes the search engine ID in the URL and the key as a parameter
+        // added per request; its `start` is one-based
+        config.search.engine = SEARCH_ENGINE_GOOGLE_CSE.to_string();
+        config.search.google_cse_key = Some("cse-key".to_string());
+        config.search.google_cse_cx = Some("abc:123".to_string());
+        let engine = SearchEngine::from_config(&config);
+        let query = SearchQuery::new("rust").time_range(crate::search::TimeRange::Week);
+        assert_eq!(
+            engine.build_search_url(&query, 10),
+            "https://www.googleapis.com/customsearch/v1?cx=abc%3A123&q=rust&dateRestrict=w1&start=11"
+        );
+    }
+
+    #[test]
+    fn test_region_params() {
+        let mut config = crate::config::Config::new();
+        config.search.country = Some("de".to_string());
+        config.search.language = Some("de".to_string());
+        let url = |engine: &str| {
+            let mut config = config.clone();
+            config.search.engine = engine.to_string();
+            SearchEngine::from_config(&config).build_search_url(&SearchQuery::new("rust"), 0)
+        };
+
+        assert_eq!(
+            url(SEARCH_ENGINE_GOOGLE),
+            "https://www.google.com/search?q=rust&gl=de&hl=de"
+        );
+        assert_eq!(
+            url(SEARCH_ENGINE_BING),
+            "https://www.bing.com/search?q=rust&mkt=de-DE"
+        );
+        assert_eq!(
+            url(SEARCH_ENGINE_DUCKDUCKGO),
+            "https://duckduckgo.com/?q=rust&kl=de-de"
+        );
+        // Engines without region parameters ignore them
+        assert_eq!(url(SEARCH_ENGINE_BAIDU), "https://www.baidu.com/s?wd=rust");
+
+        // A query's language takes precedence over the configured one
+        config.search.engine = SEARCH_ENGINE_GOOGLE.to_string();
+        let engine = SearchEngine::from_config(&config);
+        assert_eq!(
+            engine.build_search_url(&SearchQuery::new("rust").language("en"), 0),
+            "https://www.google.com/search?q=rust&gl=de&hl=en"
+        );
+
+        // Providers built from the engine's config get the same parameters
+        config.search.engine = SEARCH_ENGINE_BING.to_string();
+        config.search.language = None;
+        let engine = SearchEngine::from_config(&config);
+        let ProviderVariant::Bing(provider) = ProviderVariant::from_engine_type(
+            SearchEngineType::Bing,
+            engine.provider_config(SearchEngineType::Bing),
+        )
+        .unwrap() else {
+            panic!("expected a Bing provider");
+        };
+        assert_eq!(
+            provider.search_url("rust"),
+            "https://www.bing.com/search?q=rust&cc=DE"
+        );
+    }
+
+    #[test]
+    fn test_safe_search_params() {
+        let mut config = crate::config::Config::new();
+        config.search.safe_search = "strict".to_string();
+        let url = |config: &Config, engine: &str| {
+            let mut config = config.clone();
+            config.search.engine = engine.to_string();
+            SearchEngine::from_config(&config).build_search_url(&SearchQuery::new("rust"), 0)
+        };
+
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_GOOGLE),
+            "https://www.google.com/search?q=rust&safe=active"
+        );
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_BING),
+            "https://www.bing.com/search?q=rust&adlt=strict"
+        );
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_DUCKDUCKGO),
+            "https://duckduckgo.com/?q=rust&kp=1"
+        );
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_BRAVE),
+            "https://search.brave.com/search?q=rust&source=web&safesearch=strict"
+        );
+
+        config.search.safe_search = "off".to_string();
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_GOOGLE),
+            "https://www.google.com/search?q=rust&safe=off"
+        );
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_BING),
+            "https://www.bing.com/search?q=rust&adlt=off"
+        );
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_DUCKDUCKGO),
+            "https://duckduckgo.com/?q=rust&kp=-2"
+        );
+
+        // Moderate is the engines' default and needs no parameter
+        config.search.safe_search = "moderate".to_string();
+        assert_eq!(
+            url(&config, SEARCH_ENGINE_BING),
+            "https://www.bing.com/search?q=rust"
+        );
+
+        // Providers built from the engine's config use the same level
+        config.search.safe_search = "strict".to_string();
+        config.search.engine = SEARCH_ENGINE_DUCKDUCKGO.to_string();
+        let engine = SearchEngine::from_config(&config);
+        let ProviderVariant::DuckDuckGo(provider) = ProviderVariant::from_engine_type(
+            SearchEngineType::DuckDuckGo,
+            engine.provider_config(SearchEngineType::DuckDuckGo),
+        )
+        .unwrap() else {
+            panic!("expected a DuckDuckGo provider");
+        };
+        assert_eq!(
+            provider.search_url("rust"),
+            "https://duckduckgo.com/?q=rust&kp=1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_searxng_search_url() {
+        let mut config = Config::new();
+        config.search.engine = SEARCH_ENGINE_SEARXNG.to_string();
+        let mut engine = SearchEngine::from_config(&config);
+        let err = engine.search("rust", 5).await.unwrap_err();
+        assert!(err.to_string().contains("search.searxng_base_url"), "{err}");
+
+        config.search.searxng_base_url = Some("https://searx.example.com/".to_string());
+        config.search.searxng_engines = vec!["google".to_string(), "wikipedia".to_string()];
+        let engine = SearchEngine::from_config(&config);
+        let query = SearchQuery::new("rust").language("en");
+        assert_eq!(
+            engine.build_search_url(&query, 10),
+            "https://searx.example.com/search?q=rust&format=json&language=en&pageno=2&engines=google%2Cwikipedia"
+        );
+        let info = engine
+            .available_engines()
+            .into_iter()
+            .find(|info| info.engine_type == SearchEngineType::SearxNG)
+            .unwrap();
+        assert!(info.supports_api && !info.requires_api_key && info.has_key_configured);
+    }
+
+    #[tokio::test]
+    async fn test_google_cse_requires_cx() {
+        let mut config = Config::new();
+        config.search.engine = SEARCH_ENGINE_GOOGLE_CSE.to_string();
+        config.search.google_cse_key = Some("cse-key".to_string());
+        let mut engine = SearchEngine::from_config(&config);
+        let err = engine.search("rust", 5).await.unwrap_err();
+        assert!(err.to_string().contains("search.google_cse_cx"), "{err}");
+    }
+
+    #[test]
+    fn test_build_search_url_site() {
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_GOOGLE.to_string();
+        let engine = SearchEngine::from_config(&config);
+        let query = SearchQuery::new("iterators").site("https://docs.rust-lang.org/");
+        assert_eq!(
+            engine.build_search_url(&query, 0),
+            "https://www.google.com/search?q=iterators%20site%3Adocs.rust-lang.org"
+        );
+
+        config.search.engine = SEARCH_ENGINE_BING_API.to_string();
+        let engine = SearchEngine::from_config(&config);
+        assert_eq!(
+            engine.build_search_url(&query, 0),
+            "https://api.bing.microsoft.com/v7.0/search?q=iterators%20site%3Adocs.rust-lang.org"
+        );
+
+        // Sogou WeChat search has no site operator, so the restriction is dropped
+        config.search.engine = SEARCH_ENGINE_SOUGOU_WEIXIN.to_string();
+        let engine = SearchEngine::from_config(&config);
+        assert!(
+            engine
+                .build_search_url(&query, 0)
+                .ends_with("query=iterators")
+        );
+    }
+
+    #[test]
+    fn test_available_engines() {
+        let mut config = crate::config::Config::new();
+        let engines = SearchEngine::from_config(&config).available_engines();
+        assert_eq!(engines.len(), SearchEngineType::ALL.len());
+
+        let bing = &engines[0];
+        assert_eq!(bing.engine_type, SearchEngineType::Bing);
+        assert!(bing.supports_web && !bing.supports_api);
+        assert!(!bing.requires_api_key && bing.has_key_configured);
+
+        let serpapi = |engines: &[EngineInfo]| {
+            engines
+                .iter()
+                .find(|info| info.engine_type == SearchEngineType::SerpApi)
+                .cloned()
+                .unwrap()
+        };
+        let info = serpapi(&engines);
+        assert!(info.supports_api && info.requires_api_key);
+        assert!(!info.has_key_configured);
+
+        config.search.serpapi_keys = vec!["first".to_string(), "second".to_string()];
+        let engines = SearchEngine::from_config(&config).available_engines();
+        assert!(serpapi(&engines).has_key_configured);
+
+        config.search.serpapi_key = Some("secret".to_string());
+        let engines = SearchEngine::from_config(&config).available_engines();
+        assert!(serpapi(&engines).has_key_configured);
+
+        let bing_api = |engines: &[EngineInfo]| {
+            engines.iter().any(|info| {
+                info.engine_type == SearchEngineType::BingApi && info.has_key_configured
+            })
+        };
+        assert!(!bing_api(&engines));
+        config.search.bing_api_key = Some("azure-key".to_string());
+        let engines = SearchEngine::from_config(&config).available_engines();
+        assert!(bing_api(&engines));
+
+        // Google CSE needs both the key and the search engine ID
+        let google_cse = |config: &Config| {
+            SearchEngine::from_config(config)
+                .available_engines()
+                .iter()
+                .any(|info| {
+                    info.engine_type == SearchEngineType::GoogleCse && info.has_key_configured
+                })
+        };
+        config.search.google_cse_key = Some("cse-key".to_string());
+        assert!(!google_cse(&config));
+        config.search.google_cse_cx = Some("cse-id".to_string());
+        assert!(google_cse(&config));
+    }
+
+    #[test]
+    fn test_provider_url_override() {
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_GOOGLE.to_string();
+        config
+            .search
+            .provider_urls
+            .insert("google".to_string(), "https://www.google.de".to_string());
+        let engine = SearchEngine::from_config(&config);
+        assert_eq!(
+            engine.build_search_url(&SearchQuery::new("rust"), 0),
+            "https://www.google.de/search?q=rust"
+        );
+
+        // Engines without an override keep their default domain
+        config.search.engine = SEARCH_ENGINE_BING.to_string();
+        let engine = SearchEngine::from_config(&config);
+        assert_eq!(
+            engine.query_pattern(),
+            SearchEngineType::Bing.get_query_pattern()
+        );
+    }
+
+    #[test]
+    fn test_build_search_url_pages() {
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_BING.to_string();
+        let engine = SearchEngine::from_config(&config);
+
+        let query = SearchQuery::new("rust");
+        assert_eq!(
+            engine.build_search_url(&query, 10),
+            "https://www.bing.com/search?q=rust&first=11"
+        );
+
+        // The page parameter comes before caller-supplied extras
+        let query = SearchQuery::new("rust").param("cc", "us");
+        assert_eq!(
+            engine.build_search_url(&query, 20),
+            "https://www.bing.com/search?q=rust&first=21&cc=us"
+        );
+    }
+
+    #[test]
+    fn test_domain_filter_from_config() {
+        let mut config = crate::config::Config::new();
+        config.search.exclude_domains = vec!["Pinterest.com".to_string()];
+        let mut engine = SearchEngine::from_config(&config);
+        assert_eq!(engine.domain_filter().exclude, vec!["pinterest.com"]);
+        assert!(!engine.domain_filter().allows("https://www.pinterest.com/"));
+
+        engine.set_domain_filter(DomainFilter::default());
+        assert!(engine.domain_filter().is_empty());
+    }
+
+    #[test]
+    fn test_default_and_max_limit() {
+        let mut config = crate::config::Config::new();
+        config.search.limit = 7;
+        config.search.max_limit = 50;
+        let mut engine = SearchEngine::from_config(&config);
+
+        assert_eq!(engine.default_limit(), 7);
+        assert_eq!(engine.max_limit(), 50);
+        assert_eq!(engine.query("rust").limit, 7);
+
+        engine.set_default_limit(25);
+        assert_eq!(engine.query("rust").limit, 25);
+    }
+
+    /// Bing API results page with one result per host
+    fn bing_api_page(hosts: &[&str]) -> String {
+        let items: Vec<String> = hosts
+            .iter()
+            .map(|host| format!(r#"{{"name": "{host}", "url": "https://{host}/", "snippet": ""}}"#))
+            .collect();
+        format!(r#"{{"webPages": {{"value": [{}]}}}}"#, items.join(","))
+    }
+
+    #[tokio::test]
+    async fn test_domain_filter_fetches_further_pages() {
+        use std::sync::{Arc, Mutex};
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let base_url = serve(move |request: &Request| {
+            recorded.lock().unwrap().push(request.path.clone());
+            // The third page repeats the second, i.e. the engine ran out of results
+            if request.path.contains("offset=") {
+                bing_api_page(&["two.example.com", "www.pinterest.com", "example.org"])
+            } else {
+                bing_api_page(&["www.pinterest.com", "one.example.com", "pinterest.com"])
+            }
+        })
+        .await;
+
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_BING_API.to_string();
+        config.search.bing_api_key = Some("key".to_string());
+        config.search.query_pattern = format!("{base_url}/v7.0/search?q={{query}}");
+        config.search.exclude_domains = vec!["pinterest.com".to_string()];
+        let mut engine = SearchEngine::from_config(&config);
+
+        // Page one yields a single allowed result; the second page fills the limit
+        let results = engine.search("rust", 2).await.unwrap();
+        let urls: Vec<&str> = results.iter().map(|r| r.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            ["https://one.example.com/", "https://two.example.com/"]
+        );
+        assert_eq!(results[1].rank, 2);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+
+        // Once pages stop yielding new results, fewer than `limit` are returned
+        requests.lock().unwrap().clear();
+        let results = engine.search("rust", 5).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2].url, "https://example.org/");
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[1].contains("offset=10"));
+        assert!(requests[2].contains("offset=20"));
+    }
+
+    /// Provider returning fixed results, one per URL
+    struct MockProvider {
+        urls: Vec<&'static str>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::search::providers::SearchProvider for MockProvider {
+        type Config = Vec<&'static str>;
+
+        fn new(urls: Self::Config) -> Self {
+            Self { urls }
+        }
+
+        async fn search(&mut self, _query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+            Ok(self
+                .urls
+                .iter()
+                .take(limit)
+                .enumerate()
+                .map(|(i, url)| SearchResult {
+                    title: url.to_string(),
+                    url: url.to_string(),
+                    snippet: String::new(),
+                    rank: i + 1,
+                    date: None,
+                })
+                .collect())
+        }
+
+        fn is_healthy(&self) -> bool {
+            true
+        }
+
+        fn get_engine_type(&self) -> SearchEngineType {
+            SearchEngineType::Bing
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reciprocal_rank_fusion() {
+        use crate::search::providers::SearchProvider;
+
+        let mut first = MockProvider::new(vec![
+            "https://a.example/",
+            "https://b.example/",
+            "https://www.c.example/",
+        ]);
+        let mut second = MockProvider::new(vec![
+            "http://c.example",
+            "https://d.example/",
+            "https://b.example/#top",
+        ]);
+        let lists = vec![
+            first.search("rust", 10).await.unwrap(),
+            second.search("rust", 10).await.unwrap(),
+        ];
+
+        // c: 1/61 + 1/63, b: 1/62 + 1/63, a: 1/61, d: 1/62
+        let fused = reciprocal_rank_fusion(lists.clone(), 10);
+        let urls: Vec<&str> = fused.iter().map(|r| r.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://www.c.example/",
+                "https://b.example/",
+                "https://a.example/",
+                "https://d.example/"
+            ]
+        );
+        let ranks: Vec<usize> = fused.iter().map(|r| r.rank).collect();
+        assert_eq!(ranks, [1, 2, 3, 4]);
+
+        let fused = reciprocal_rank_fusion(lists, 2);
+        assert_eq!(fused.len(), 2);
+        assert_eq!(fused[1].url, "https://b.example/");
+    }
+
+    #[test]
+    fn test_provider_config_from_engine_settings() {
+        let mut config = crate::config::Config::new();
+        config.search.serpapi_key = Some("first".to_string());
+        config.search.serpapi_keys = vec!["second".to_string()];
+        config.search.serpapi_engine = "bing".to_string();
+        config.fetcher.mode = FETCHER_MODE_PLAIN_REQUEST.to_string();
+        let engine = SearchEngine::from_config(&config);
+
+        let provider_config = engine.provider_config(SearchEngineType::SerpApi);
+        assert_eq!(provider_config.api_keys, ["first", "second"]);
+        assert_eq!(provider_config.extra[SERPAPI_ENGINE_SETTING], "bing");
+        assert_eq!(
+            provider_config.extra[FETCH_MODE_SETTING],
+            FETCHER_MODE_PLAIN_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_multi_skips_failing_engines() {
+        let base_url = serve(|_: &Request| {
+            r#"{"results": [{"url": "https://one.example/", "title": "One", "content": ""}, {"url": "https://two.example/", "title": "Two", "content": ""}]}"#
+        })
+        .await;
+
+        let mut config = crate::config::Config::new();
+        config.search.searxng_base_url = Some(base_url);
+        let mut engine = SearchEngine::from_config(&config);
+
+        // SerpApi has no key configured and fails; SearxNG's results are kept
+        let engines = [SearchEngineType::SerpApi, SearchEngineType::SearxNG];
+        let results = engine.search_multi("rust", &engines, 1).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].url, "https://one.example/");
+        assert_eq!(results[0].rank, 1);
+
+        let err = engine
+            .search_multi("rust", &[SearchEngineType::SerpApi], 5)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("search.serpapi_key"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let mut config = crate::config::Config::new();
+        config.fetcher.mode = FETCHER_MODE_PLAIN_REQUEST.to_string();
+        config.search.bing_api_key = Some("key".to_string());
+        config.search.google_cse_key = Some("key".to_string());
+        let engine = SearchEngine::from_config(&config);
+
+        let health: HashMap<SearchEngineType, bool> =
+            engine.health_check().await.into_iter().collect();
+        assert_eq!(health.len(), SearchEngineType::ALL.len());
+        // Plain requests need no WebDriver
+        assert!(health[&SearchEngineType::Google]);
+        assert!(health[&SearchEngineType::BingApi]);
+        assert!(!health[&SearchEngineType::SerpApi]);
+        // Google CSE also needs the search engine ID, SearxNG an instance
+        assert!(!health[&SearchEngineType::GoogleCse]);
+        assert!(!health[&SearchEngineType::SearxNG]);
+    }
+
+    /// With `reuse_browser`, every result of a run is fetched by the same browser
+    /// (it sends back the cookie the first page set), which is shut down with its
+    /// driver when the run ends. Skipped unless a driver and browser are installed.
+    #[tokio::test]
+    async fn test_reuse_browser_across_results() {
+        if which::which("chromedriver").is_err() && which::which("geckodriver").is_err() {
+            println!("No driver installed - skipping test");
+            return;
+        }
+
+        let base_url = serve(|request: &Request| {
+            if request.path.contains("format=json") {
+                let host = request.header("host").unwrap_or_default();
+                let results: Vec<String> = (1..=3)
+                    .map(|i| {
+                        format!(
+                            r#"{{"url": "http://{host}/page{i}", "title": "Page {i}", "content": ""}}"#
+                        )
+                    })
+                    .collect();
+                Response::ok(format!(r#"{{"results": [{}]}}"#, results.join(",")))
+            } else if request
+                .header("cookie")
+                .is_some_and(|cookie| cookie.contains("visited=yes"))
+            {
+                Response::ok("<p>returning browser</p>")
+            } else {
+                Response::ok("<p>new browser</p>").header("Set-Cookie", "visited=yes; Path=/")
+            }
+        })
+        .await;
+
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_SEARXNG.to_string();
+        config.search.searxng_base_url = Some(base_url);
+        config.fetcher.reuse_browser = true;
+        let mut engine = SearchEngine::from_config(&config);
+
+        let results = engine
+            .search_with_content(
+                "rust",
+                3,
+                FetchMode::BrowserHeadless,
+                crate::converter::Format::Markdown,
+            )
+            .await
+            .unwrap();
+        if results.is_empty() {
+            println!("No browser could be started - skipping test");
+            return;
+        }
+        assert_eq!(results.len(), 3);
+        assert!(results[0].1.contains("new browser"), "{}", results[0].1);
+        for (_, content) in &results[1..] {
+            assert!(content.contains("returning browser"), "{content}");
+        }
+        assert!(engine.fetcher.get_browser_ids().is_empty());
+        assert!(!engine.fetcher.has_managed_driver());
+    }
+
+    #[tokio::test]
+    async fn test_autoswitch_falls_back_on_empty() {
+        // Bing API finds nothing, the SearxNG instance on the same server does
+        let base_url = serve(|request: &Request| {
+            if request.path.contains("format=json") {
+                r#"{"results": [{"url": "https://searx.example.com/", "title": "Searx", "content": ""}]}"#.to_string()
+            } else {
+                bing_api_page(&[])
+            }
+        })
+        .await;
+
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_BING_API.to_string();
+        config.search.bing_api_key = Some("key".to_string());
+        config
+            .search
+            .provider_urls
+            .insert(SEARCH_ENGINE_BING_API.to_string(), base_url.clone());
+        config.search.searxng_base_url = Some(base_url.clone());
+        config.search.fallback_engines = vec![SEARCH_ENGINE_SEARXNG.to_string()];
+
+        config.search.autoswitch = "on_empty".to_string();
+        let mut engine = SearchEngine::from_config(&config);
+        let results = engine.search("rust", 5).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].url, "https://searx.example.com/");
+        // The primary engine is used again for the next search
+        assert_eq!(engine.engine_type(), &SearchEngineType::BingApi);
+        assert!(
+            engine
+                .query_pattern()
+                .starts_with(&format!("{base_url}/v7.0/"))
+        );
+
+        // Empty results are final unless the strategy switches on them
+        for strategy in ["none", "on_error"] {
+            config.search.autoswitch = strategy.to_string();
+            let mut engine = SearchEngine::from_config(&config);
+            assert!(engine.search("rust", 5).await.unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_with_content_fetches_concurrently() {
+        const PAGE_DELAY: std::time::Duration = std::time::Duration::from_millis(400);
+        let base_url = serve(|request: &Request| {
+            if let Some(page) = request.path.strip_prefix("/page/") {
+                // Later pages answer first, so completion order differs from search order
+                let page: u64 = page.parse().unwrap();
+                return Response::ok(format!("content of page {page}"))
+                    .delay(PAGE_DELAY - std::time::Duration::from_millis(50 * page));
+            }
+            let host = request.header("host").unwrap_or_default();
+            let items: Vec<String> = (1..=4)
+                .map(|i| format!(r#"{{"name": "Page {i}", "url": "http://{host}/page/{i}"}}"#))
+                .collect();
+            Response::ok(format!(
+                r#"{{"webPages": {{"value": [{}]}}}}"#,
+                items.join(",")
+            ))
+        })
+        .await;
+
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_BING_API.to_string();
+        config.search.bing_api_key = Some("key".to_string());
+        config.search.query_pattern = format!("{base_url}/v7.0/search?q={{query}}");
+        let mut engine = SearchEngine::from_config(&config);
+
+        let started = std::time::Instant::now();
+        let results = engine
+            .search_with_content(
+                "rust",
+                4,
+                FetchMode::PlainRequest,
+                crate::converter::Format::Html,
+            )
+            .await
+            .unwrap();
+        let elapsed = started.elapsed();
+
+        let contents: Vec<&str> = results.iter().map(|(_, c)| c.as_str()).collect();
+        assert_eq!(
+            contents,
+            [
+                "content of page 1",
+                "content of page 2",
+                "content of page 3",
+                "content of page 4"
+            ]
+        );
+        assert_eq!(results[3].0.title, "Page 4");
+        // Serially the four pages would take well over a second and a half
+        assert!(elapsed < PAGE_DELAY * 2, "took {elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_serpapi_search_requires_key() {
+        let mut config = crate::config::Config::new();
+        config.search.engine = SEARCH_ENGINE_SERPAPI.to_string();
+        let mut engine = SearchEngine::from_config(&config);
+
+        let err = engine.search("rust", 5).await.unwrap_err();
+        assert!(matches!(err, TarziError::Config(_)));
+    }
+
+    #[test]
+    fn test_parse_raw() {
+        let html = r#"
+            <html><body>
+                <li class="b_algo">
+                    <h2><a href="https://example.com/a">Result A</a></h2>
+                    <div class="b_caption"><p>Snippet A</p></div>
+                </li>
+                <li class="b_algo">
+                    <h2><a href="https://example.com/b">Result B</a></h2>
+                    <div class="b_caption"><p>Snippet B</p></div>
+                </li>
+            </body></html>
+        "#;
+
+        let results = SearchEngine::parse_raw(html, SearchEngineType::Bing, 5).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].url, "https://example.com/a");
+
+        let results = SearchEngine::parse_raw(html, SearchEngineType::Bing, 1).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
     #[test]
     fn test_search_engine_fallback_to_bing() {
         let mut config = crate::config::Config::new();
@@ -8,9 +8,14 @@
 pub mod engine;
 pub mod parser;
 pub mod providers;
+#[cfg(test)]
+mod test_server;
 pub mod types;
 
 // Re-export main types and functions
 pub use engine::SearchEngine;
 pub use parser::ParserFactory;
-pub use types::{SearchEngineType, SearchResult};
+pub use types::{
+    AutoSwitchStrategy, DomainFilter, EngineInfo, SafeSearch, SearchEngineType, SearchQuery,
+    SearchResponse, SearchResult, SearchResults, TimeRange,
+};
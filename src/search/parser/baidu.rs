@@ -64,6 +64,7 @@ impl BaseParser for BaiduParser {
                     url,
                     snippet,
                     rank: results.len() + 1,
+                    date: None,
                 });
             }
         }
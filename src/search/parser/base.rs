@@ -17,6 +17,12 @@ pub trait BaseParser: Send + Sync {
 
     /// Parse search results from HTML content
     fn parse(&self, html: &str, limit: usize) -> Result<Vec<SearchResult>>;
+
+    /// Extract related queries ("related searches", "people also ask") from HTML content.
+    /// Engines without such blocks return an empty list.
+    fn parse_related_queries(&self, _html: &str) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 /// Common base implementation for all parsers
@@ -63,6 +69,111 @@ pub mod helpers {
         current.as_str().unwrap_or("").to_string()
     }
 
+    /// Text of the first of `fields` present as a string (or number), so a renamed
+    /// field can be listed next to its old name
+    pub fn first_json_text(json: &Value, fields: &[&str]) -> Option<String> {
+        fields.iter().find_map(|field| match &json[*field] {
+            Value::String(text) => Some(text.clone()),
+            Value::Number(number) => Some(number.to_string()),
+            _ => None,
+        })
+    }
+
+    /// Normalize a result date as displayed by an engine ("Jul 25, 2024",
+    /// "25 July 2024") to an ISO-8601 date; ISO dates and timestamps are kept as
+    /// they are. Other text mentioning a number, such as "3 days ago", is returned
+    /// as displayed, and anything else gives `None`.
+    pub fn parse_result_date(text: &str) -> Option<String> {
+        let text = text.trim_matches(|c: char| {
+            c.is_whitespace() || matches!(c, '—' | '–' | '-' | '·' | '|' | ',')
+        });
+        if is_iso_date(text) {
+            return Some(text.to_string());
+        }
+        let words: Vec<&str> = text
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '.')
+            .filter(|word| !word.is_empty())
+            .collect();
+        let parts = match words.as_slice() {
+            [month, day, year] if month_number(month).is_some() => Some((*year, *month, *day)),
+            [day, month, year] => Some((*year, *month, *day)),
+            _ => None,
+        };
+        if let Some((year, month, day)) = parts
+            && let Some(month) = month_number(month)
+            && let (Ok(year), Ok(day)) = (year.parse::<u32>(), day.parse::<u32>())
+            && (1000..=9999).contains(&year)
+            && (1..=31).contains(&day)
+        {
+            return Some(format!("{year:04}-{month:02}-{day:02}"));
+        }
+        text.chars()
+            .any(|c| c.is_ascii_digit())
+            .then(|| text.to_string())
+    }
+
+    /// Date of a JSON result from the first of `fields` present, normalized with
+    /// [`parse_result_date`]
+    pub fn json_result_date(json: &Value, fields: &[&str]) -> Option<String> {
+        first_json_text(json, fields).and_then(|text| parse_result_date(&text))
+    }
+
+    /// Drop a date the engine prefixes to a snippet ("Jul 25, 2024 — ...")
+    pub fn strip_date_prefix(snippet: &str, date_text: &str) -> String {
+        match snippet.trim_start().strip_prefix(date_text.trim()) {
+            Some(rest) if !date_text.trim().is_empty() => rest
+                .trim_start_matches(|c: char| {
+                    c.is_whitespace() || matches!(c, '—' | '–' | '-' | '·' | '|')
+                })
+                .to_string(),
+            _ => snippet.to_string(),
+        }
+    }
+
+    fn is_iso_date(text: &str) -> bool {
+        let bytes = text.as_bytes();
+        bytes.len() >= 10
+            && bytes[..10].iter().enumerate().all(|(i, b)| match i {
+                4 | 7 => *b == b'-',
+                _ => b.is_ascii_digit(),
+            })
+    }
+
+    fn month_number(word: &str) -> Option<u32> {
+        const MONTHS: [&str; 12] = [
+            "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+        ];
+        if word.len() < 3 || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let prefix = word[..3].to_ascii_lowercase();
+        MONTHS
+            .iter()
+            .position(|month| *month == prefix)
+            .map(|index| index as u32 + 1)
+    }
+
+    /// Decode an API response as untyped JSON. If strict decoding fails, retry once
+    /// after dropping a byte order mark or an anti-XSSI prefix such as `)]}'`.
+    pub fn parse_json_tolerant(content: &str) -> Result<Value> {
+        match serde_json::from_str(content) {
+            Ok(json) => Ok(json),
+            Err(e) => {
+                let trimmed = content.trim_start_matches('\u{feff}');
+                let start = trimmed.find(['{', '[']).unwrap_or(0);
+                match serde_json::from_str(&trimmed[start..]) {
+                    Ok(json) => {
+                        tracing::warn!(
+                            "Decoded JSON response after skipping a {start}-byte prefix"
+                        );
+                        Ok(json)
+                    }
+                    Err(_) => Err(e.into()),
+                }
+            }
+        }
+    }
+
     /// Extract array from JSON safely
     pub fn extract_json_array(json: &Value, field: &str) -> Option<Vec<Value>> {
         json[field].as_array().cloned()
@@ -77,6 +188,50 @@ pub mod helpers {
         current.as_array().cloned()
     }
 
+    /// Normalize whitespace in query texts and drop empty entries and duplicates,
+    /// preserving the original order
+    pub fn unique_queries(texts: impl IntoIterator<Item = String>) -> Vec<String> {
+        let mut queries: Vec<String> = Vec::new();
+        for text in texts {
+            let query = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !query.is_empty() && !queries.contains(&query) {
+                queries.push(query);
+            }
+        }
+        queries
+    }
+
+    /// Create a SearchResult from an API result object, trying each listed field name
+    /// in turn. Returns `None` (with a warning) when the title or URL is absent; a
+    /// missing snippet only logs a warning, so provider schema changes degrade
+    /// results instead of failing the parse.
+    pub fn create_search_result_tolerant(
+        json: &Value,
+        parser: &str,
+        title_fields: &[&str],
+        url_fields: &[&str],
+        snippet_fields: &[&str],
+        rank: usize,
+    ) -> Option<SearchResult> {
+        let field = |fields: &[&str]| {
+            let text = first_json_text(json, fields).filter(|text| !text.is_empty());
+            if text.is_none() {
+                tracing::warn!("{parser}: result has none of the expected fields {fields:?}");
+            }
+            text
+        };
+        let title = field(title_fields);
+        let url = field(url_fields);
+        let snippet = field(snippet_fields).unwrap_or_default();
+        Some(SearchResult {
+            title: title?,
+            url: url?,
+            snippet,
+            rank,
+            date: None,
+        })
+    }
+
     /// Create a SearchResult from JSON fields
     pub fn create_search_result_from_json(
         json: &Value,
@@ -90,6 +245,7 @@ pub mod helpers {
             url: extract_json_text(json, url_field),
             snippet: extract_json_text(json, snippet_field),
             rank,
+            date: None,
         }
     }
 
@@ -106,6 +262,7 @@ pub mod helpers {
             url: extract_nested_json_text(json, url_path),
             snippet: extract_nested_json_text(json, snippet_path),
             rank,
+            date: None,
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::base::{BaseParser, BaseParserImpl};
+use super::base::{BaseParser, BaseParserImpl, helpers};
 use crate::Result;
 use crate::search::types::{SearchEngineType, SearchResult};
 use select::document::Document;
@@ -54,17 +54,34 @@ impl BaseParser for BingParser {
                 .next()
                 .map(|n| n.text().trim().to_string())
                 .unwrap_or_default();
+            // News-style results prefix the snippet with "Jul 25, 2024 · "
+            let date_text = node.find(Class("news_dt")).next().map(|n| n.text());
+            let date = date_text.as_deref().and_then(helpers::parse_result_date);
+            let snippet = match (&date, &date_text) {
+                (Some(_), Some(text)) => helpers::strip_date_prefix(&snippet, text),
+                _ => snippet,
+            };
             if !title.is_empty() {
                 results.push(SearchResult {
                     title,
                     url,
                     snippet,
                     rank: results.len() + 1, // Use results.len() + 1 for proper ranking
+                    date,
                 });
             }
         }
         Ok(results)
     }
+
+    fn parse_related_queries(&self, html: &str) -> Vec<String> {
+        let document = Document::from(html);
+        helpers::unique_queries(
+            document
+                .find(Descendant(Class("b_rs"), Name("a")))
+                .map(|n| n.text()),
+        )
+    }
 }
 
 impl Default for BingParser {
@@ -108,6 +125,48 @@ mod tests {
         assert_eq!(results[0].rank, 1);
     }
 
+    #[test]
+    fn test_bing_parser_result_date() {
+        let parser = BingParser::new();
+        let html = r#"
+        <li class="b_algo">
+            <h2><a href="https://example1.com">Dated result</a></h2>
+            <div class="b_caption"><p><span class="news_dt">25 Jul 2024</span> · Rust 1.80 is out.</p></div>
+        </li>
+        <li class="b_algo">
+            <h2><a href="https://example2.com">Undated result</a></h2>
+            <div class="b_caption"><p>No date here.</p></div>
+        </li>
+        "#;
+        let results = parser.parse(html, 5).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].date.as_deref(), Some("2024-07-25"));
+        assert_eq!(results[0].snippet, "Rust 1.80 is out.");
+        assert_eq!(results[1].date, None);
+    }
+
+    #[test]
+    fn test_bing_parser_related_queries() {
+        let parser = BingParser::new();
+        let html = r#"
+        <ol id="b_results">
+            <li class="b_algo"><h2><a href="https://example.com">Example</a></h2></li>
+            <li class="b_ans"><div class="b_rs"><h2>Related searches</h2><ul>
+                <li><a href="/search?q=rust+book">rust <strong>book</strong></a></li>
+                <li><a href="/search?q=rust+async">rust async</a></li>
+                <li><a href="/search?q=rust+book">rust book</a></li>
+            </ul></div></li>
+        </ol>
+        "#;
+
+        assert_eq!(
+            parser.parse_related_queries(html),
+            vec!["rust book".to_string(), "rust async".to_string()]
+        );
+        assert!(parser.parse_related_queries("<html></html>").is_empty());
+    }
+
     #[test]
     fn test_bing_parser_empty_html() {
         let parser = BingParser::new();
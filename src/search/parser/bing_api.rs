@@ -0,0 +1,173 @@
+use super::base::{BaseParser, BaseParserImpl, helpers};
+use crate::Result;
+use crate::error::TarziError;
+use crate::search::types::{SearchEngineType, SearchResult};
+use serde_json::Value;
+
+/// Bing Web Search API parser (JSON-based)
+pub struct BingApiParser {
+    base: BaseParserImpl,
+}
+
+impl BingApiParser {
+    pub fn new() -> Self {
+        Self {
+            base: BaseParserImpl::new("BingApiParser".to_string(), SearchEngineType::BingApi),
+        }
+    }
+
+    /// Error message of an error response: `{"error": {...}}` from the Azure gateway
+    /// (e.g. an invalid key) or `{"_type": "ErrorResponse", "errors": [...]}` from
+    /// the API itself
+    fn error_message(json: &Value) -> Option<String> {
+        let error = if json["error"].is_object() {
+            &json["error"]
+        } else if json["_type"] == "ErrorResponse" {
+            &json["errors"][0]
+        } else {
+            return None;
+        };
+        Some(
+            helpers::first_json_text(error, &["message", "code"])
+                .unwrap_or_else(|| "unknown error".to_string()),
+        )
+    }
+}
+
+impl BaseParser for BingApiParser {
+    fn name(&self) -> &str {
+        self.base.name()
+    }
+
+    fn engine_type(&self) -> SearchEngineType {
+        self.base.engine_type()
+    }
+
+    fn parse(&self, content: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let json = helpers::parse_json_tolerant(content)?;
+        if let Some(error) = Self::error_message(&json) {
+            return Err(TarziError::Search(format!("Bing API error: {error}")));
+        }
+
+        let Some(items) = helpers::extract_nested_json_array(&json, &["webPages", "value"]) else {
+            tracing::warn!("{}: response has no webPages.value", self.name());
+            return Ok(Vec::new());
+        };
+        let results = items
+            .iter()
+            .filter_map(|item| {
+                let result = helpers::create_search_result_tolerant(
+                    item,
+                    self.name(),
+                    &["name"],
+                    &["url"],
+                    &["snippet"],
+                    0,
+                )?;
+                Some(SearchResult {
+                    date: helpers::json_result_date(item, &["datePublished"]),
+                    ..result
+                })
+            })
+            .take(limit)
+            .enumerate()
+            .map(|(i, result)| SearchResult {
+                rank: i + 1,
+                ..result
+            })
+            .collect();
+        Ok(results)
+    }
+
+    fn parse_related_queries(&self, content: &str) -> Vec<String> {
+        let Ok(json) = helpers::parse_json_tolerant(content) else {
+            return Vec::new();
+        };
+        let related = helpers::extract_nested_json_array(&json, &["relatedSearches", "value"])
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| helpers::extract_json_text(&item, "text"));
+        helpers::unique_queries(related)
+    }
+}
+
+impl Default for BingApiParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bing_api_parser() {
+        let parser = BingApiParser::new();
+        let json = r#"{
+            "_type": "SearchResponse",
+            "queryContext": {"originalQuery": "rust"},
+            "webPages": {
+                "totalEstimatedMatches": 1230000,
+                "value": [
+                    {"id": "0", "name": "Rust", "url": "https://www.rust-lang.org/", "snippet": "A language", "datePublished": "2024-07-25T00:00:00.0000000"},
+                    {"id": "1", "name": "", "url": "https://example.com/untitled"},
+                    {"id": "2", "name": "The Book", "url": "https://doc.rust-lang.org/book/"},
+                    {"id": "3", "name": "Crates", "url": "https://crates.io/", "snippet": "Registry"}
+                ]
+            },
+            "relatedSearches": {"value": [{"text": "rust book"}, {"text": "rust book"}, {"text": "rust vs go"}]}
+        }"#;
+
+        let results = parser.parse(json, 2).unwrap();
+        assert_eq!(parser.name(), "BingApiParser");
+        assert!(parser.supports(&SearchEngineType::BingApi));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "Rust");
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert_eq!(results[0].snippet, "A language");
+        assert_eq!(results[0].rank, 1);
+        assert_eq!(
+            results[0].date.as_deref(),
+            Some("2024-07-25T00:00:00.0000000")
+        );
+        assert_eq!(results[1].title, "The Book");
+        assert_eq!(results[1].snippet, "");
+        assert_eq!(results[1].rank, 2);
+        assert_eq!(results[1].date, None);
+
+        assert_eq!(
+            parser.parse_related_queries(json),
+            vec!["rust book".to_string(), "rust vs go".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_bing_api_parser_errors() {
+        let parser = BingApiParser::new();
+
+        let err = parser
+            .parse(
+                r#"{"error": {"code": "401", "message": "Access denied due to invalid subscription key."}}"#,
+                10,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid subscription key"));
+
+        let err = parser
+            .parse(
+                r#"{"_type": "ErrorResponse", "errors": [{"code": "InvalidRequest", "message": "Parameter has invalid value."}]}"#,
+                10,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Parameter has invalid value."));
+
+        assert!(parser.parse("<html>not json</html>", 10).is_err());
+        assert!(
+            parser
+                .parse(r#"{"_type": "SearchResponse"}"#, 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -171,6 +171,7 @@ impl DuckDuckGoParser {
             url,
             snippet,
             rank: 0, // Will be set by caller
+            date: None,
         })
     }
 }
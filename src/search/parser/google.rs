@@ -1,8 +1,8 @@
-use super::base::{BaseParser, BaseParserImpl};
+use super::base::{BaseParser, BaseParserImpl, helpers};
 use crate::Result;
 use crate::search::types::{SearchEngineType, SearchResult};
 use select::document::Document;
-use select::predicate::{Class, Name, Predicate};
+use select::predicate::{Attr, Class, Name, Predicate};
 use std::collections::HashSet;
 
 /// Google web parser (HTML-based)
@@ -60,6 +60,12 @@ impl BaseParser for GoogleParser {
 
                 // Try multiple snippet extraction strategies
                 let snippet = self.extract_snippet(&result_element);
+                let date_text = self.extract_date_text(&result_element);
+                let date = date_text.as_deref().and_then(helpers::parse_result_date);
+                let snippet = match (&date, &date_text) {
+                    (Some(_), Some(text)) => helpers::strip_date_prefix(&snippet, text),
+                    _ => snippet,
+                };
 
                 seen_urls.insert(url.clone());
                 results.push(SearchResult {
@@ -67,12 +73,29 @@ impl BaseParser for GoogleParser {
                     url,
                     snippet,
                     rank: results.len() + 1,
+                    date,
                 });
             }
         }
 
         Ok(results)
     }
+
+    fn parse_related_queries(&self, html: &str) -> Vec<String> {
+        let document = Document::from(html);
+
+        // "People also ask" questions, then the "related searches" block
+        let questions = document.find(Class("related-question-pair")).map(|n| {
+            n.attr("data-q")
+                .map(str::to_string)
+                .unwrap_or_else(|| n.text())
+        });
+        let related = document
+            .find(Attr("id", "bres").descendant(Name("a")))
+            .map(|n| n.text());
+
+        helpers::unique_queries(questions.chain(related))
+    }
 }
 
 impl GoogleParser {
@@ -138,6 +161,16 @@ impl GoogleParser {
         (String::new(), String::new())
     }
 
+    /// Date Google shows at the start of a snippet ("Jul 25, 2024 — ...")
+    fn extract_date_text(&self, element: &select::node::Node) -> Option<String> {
+        ["LEwnzc", "MUxGbd"].iter().find_map(|class_name| {
+            element
+                .find(Name("span").and(Class(*class_name)))
+                .map(|span| span.text().trim().to_string())
+                .find(|text| !text.is_empty())
+        })
+    }
+
     fn extract_snippet(&self, element: &select::node::Node) -> String {
         // Try multiple strategies for snippet extraction
 
@@ -256,6 +289,54 @@ mod tests {
         assert_eq!(results[1].rank, 2);
     }
 
+    #[test]
+    fn test_google_parser_result_date() {
+        let parser = GoogleParser::new();
+        let html = r#"
+        <div class="tF2Cxc">
+            <div class="yuRUbf"><a href="https://example1.com">Dated result</a></div>
+            <div class="VwiC3b"><span class="MUxGbd wuQ4Ob">Jul 25, 2024 — </span>Rust 1.80 is out.</div>
+        </div>
+        <div class="tF2Cxc">
+            <div class="yuRUbf"><a href="https://example2.com">Undated result</a></div>
+            <div class="VwiC3b">No date here.</div>
+        </div>
+        "#;
+        let results = parser.parse(html, 5).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].date.as_deref(), Some("2024-07-25"));
+        assert_eq!(results[0].snippet, "Rust 1.80 is out.");
+        assert_eq!(results[1].date, None);
+        assert_eq!(results[1].snippet, "No date here.");
+    }
+
+    #[test]
+    fn test_google_parser_related_queries() {
+        let parser = GoogleParser::new();
+        let html = r#"
+        <div class="related-question-pair" data-q="What is Rust used for?">
+            <span>What is Rust used for?</span>
+        </div>
+        <div class="related-question-pair"><span>Is Rust  hard to learn?</span></div>
+        <div id="bres">
+            <a href="/search?q=rust+tutorial"><div>rust tutorial</div></a>
+            <a href="/search?q=rust+vs+go">rust vs go</a>
+        </div>
+        "#;
+
+        assert_eq!(
+            parser.parse_related_queries(html),
+            vec![
+                "What is Rust used for?".to_string(),
+                "Is Rust hard to learn?".to_string(),
+                "rust tutorial".to_string(),
+                "rust vs go".to_string(),
+            ]
+        );
+        assert!(parser.parse_related_queries("<html></html>").is_empty());
+    }
+
     #[test]
     fn test_google_parser_empty_and_limit() {
         let parser = GoogleParser::new();
@@ -0,0 +1,164 @@
+use super::base::{BaseParser, BaseParserImpl, helpers};
+use crate::Result;
+use crate::error::TarziError;
+use crate::search::types::{SearchEngineType, SearchResult};
+use serde_json::Value;
+
+/// Google Custom Search JSON API parser
+pub struct GoogleCseApiParser {
+    base: BaseParserImpl,
+}
+
+impl GoogleCseApiParser {
+    pub fn new() -> Self {
+        Self {
+            base: BaseParserImpl::new(
+                "GoogleCseApiParser".to_string(),
+                SearchEngineType::GoogleCse,
+            ),
+        }
+    }
+
+    /// Error message of an error response, `{"error": {"code": 403, "message": ...}}`
+    fn error_message(json: &Value) -> Option<String> {
+        let error = json.get("error")?;
+        Some(
+            helpers::first_json_text(error, &["message", "status", "code"])
+                .unwrap_or_else(|| "unknown error".to_string()),
+        )
+    }
+}
+
+impl BaseParser for GoogleCseApiParser {
+    fn name(&self) -> &str {
+        self.base.name()
+    }
+
+    fn engine_type(&self) -> SearchEngineType {
+        self.base.engine_type()
+    }
+
+    fn parse(&self, content: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let json = helpers::parse_json_tolerant(content)?;
+        if let Some(error) = Self::error_message(&json) {
+            return Err(TarziError::Search(format!("Google CSE error: {error}")));
+        }
+
+        // A query without matches has no `items` at all
+        let Some(items) = helpers::extract_json_array(&json, "items") else {
+            return Ok(Vec::new());
+        };
+        let results = items
+            .iter()
+            .filter_map(|item| {
+                let result = helpers::create_search_result_tolerant(
+                    item,
+                    self.name(),
+                    &["title", "htmlTitle"],
+                    &["link"],
+                    &["snippet", "htmlSnippet"],
+                    0,
+                )?;
+                Some(SearchResult {
+                    date: helpers::json_result_date(
+                        &item["pagemap"]["metatags"][0],
+                        &["article:published_time"],
+                    ),
+                    ..result
+                })
+            })
+            .take(limit)
+            .enumerate()
+            .map(|(i, result)| SearchResult {
+                rank: i + 1,
+                ..result
+            })
+            .collect();
+        Ok(results)
+    }
+
+    fn parse_related_queries(&self, content: &str) -> Vec<String> {
+        let Ok(json) = helpers::parse_json_tolerant(content) else {
+            return Vec::new();
+        };
+        // The API offers no related searches, only a spelling correction
+        helpers::unique_queries(helpers::first_json_text(
+            &json["spelling"],
+            &["correctedQuery"],
+        ))
+    }
+}
+
+impl Default for GoogleCseApiParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_google_cse_parser() {
+        let parser = GoogleCseApiParser::new();
+        let json = r#"{
+            "kind": "customsearch#search",
+            "queries": {"request": [{"searchTerms": "rust", "startIndex": 1}]},
+            "spelling": {"correctedQuery": "rust lang"},
+            "items": [
+                {
+                    "kind": "customsearch#result",
+                    "title": "Rust",
+                    "link": "https://www.rust-lang.org/",
+                    "snippet": "A language",
+                    "pagemap": {"metatags": [{"article:published_time": "2024-07-25T10:00:00Z"}]}
+                },
+                {"kind": "customsearch#result", "title": "", "link": "https://example.com/untitled"},
+                {"kind": "customsearch#result", "title": "The Book", "link": "https://doc.rust-lang.org/book/"},
+                {"kind": "customsearch#result", "title": "Crates", "link": "https://crates.io/", "snippet": "Registry"}
+            ]
+        }"#;
+
+        let results = parser.parse(json, 2).unwrap();
+        assert_eq!(parser.name(), "GoogleCseApiParser");
+        assert!(parser.supports(&SearchEngineType::GoogleCse));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "Rust");
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert_eq!(results[0].snippet, "A language");
+        assert_eq!(results[0].rank, 1);
+        assert_eq!(results[0].date.as_deref(), Some("2024-07-25T10:00:00Z"));
+        assert_eq!(results[1].title, "The Book");
+        assert_eq!(results[1].snippet, "");
+        assert_eq!(results[1].rank, 2);
+        assert_eq!(results[1].date, None);
+
+        assert_eq!(
+            parser.parse_related_queries(json),
+            vec!["rust lang".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_google_cse_parser_errors() {
+        let parser = GoogleCseApiParser::new();
+
+        let err = parser
+            .parse(
+                r#"{"error": {"code": 400, "message": "API key not valid. Please pass a valid API key.", "status": "INVALID_ARGUMENT"}}"#,
+                10,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("API key not valid"));
+
+        assert!(parser.parse("<html>not json</html>", 10).is_err());
+        // No matches: the response has no items
+        assert!(
+            parser
+                .parse(r#"{"kind": "customsearch#search", "searchInformation": {"totalResults": "0"}}"#, 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -6,10 +6,15 @@
 pub mod baidu;
 pub mod base;
 pub mod bing;
+pub mod bing_api;
 pub mod brave;
 pub mod duckduckgo;
 pub mod google;
+pub mod google_cse;
+pub mod searxng;
+pub mod serpapi;
 pub mod sogou_weixin;
+pub mod yandex;
 
 use crate::search::types::SearchEngineType;
 
@@ -17,10 +22,15 @@ use crate::search::types::SearchEngineType;
 pub use baidu::BaiduParser;
 pub use base::BaseParser;
 pub use bing::BingParser;
+pub use bing_api::BingApiParser;
 pub use brave::BraveParser;
 pub use duckduckgo::DuckDuckGoParser;
 pub use google::GoogleParser;
+pub use google_cse::GoogleCseApiParser;
+pub use searxng::SearxngParser;
+pub use serpapi::SerpApiParser;
 pub use sogou_weixin::SogouWeixinParser;
+pub use yandex::YandexParser;
 
 /// Factory for creating parsers based on search engine type
 pub struct ParserFactory;
@@ -40,6 +50,12 @@ impl ParserFactory {
             SearchEngineType::BraveSearch => Box::new(BraveParser::new()),
             SearchEngineType::Baidu => Box::new(BaiduParser::new()),
             SearchEngineType::SougouWeixin => Box::new(SogouWeixinParser::new()),
+            SearchEngineType::Yandex => Box::new(YandexParser::new()),
+            // API parsers (JSON-based)
+            SearchEngineType::SerpApi => Box::new(SerpApiParser::new()),
+            SearchEngineType::BingApi => Box::new(BingApiParser::new()),
+            SearchEngineType::GoogleCse => Box::new(GoogleCseApiParser::new()),
+            SearchEngineType::SearxNG => Box::new(SearxngParser::new()),
         }
     }
 }
@@ -73,6 +89,18 @@ mod tests {
 
         let baidu_parser = factory.get_parser(&SearchEngineType::Baidu);
         assert_eq!(baidu_parser.name(), "BaiduParser");
+
+        let yandex_parser = factory.get_parser(&SearchEngineType::Yandex);
+        assert_eq!(yandex_parser.name(), "YandexParser");
+
+        let bing_api_parser = factory.get_parser(&SearchEngineType::BingApi);
+        assert_eq!(bing_api_parser.name(), "BingApiParser");
+
+        let google_cse_parser = factory.get_parser(&SearchEngineType::GoogleCse);
+        assert_eq!(google_cse_parser.name(), "GoogleCseApiParser");
+
+        let searxng_parser = factory.get_parser(&SearchEngineType::SearxNG);
+        assert_eq!(searxng_parser.name(), "SearxngParser");
     }
 
     #[test]
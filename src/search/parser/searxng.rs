@@ -0,0 +1,155 @@
+use super::base::{BaseParser, BaseParserImpl, helpers};
+use crate::Result;
+use crate::search::types::{SearchEngineType, SearchResult};
+
+/// SearxNG JSON API parser (`/search?format=json`)
+pub struct SearxngParser {
+    base: BaseParserImpl,
+}
+
+impl SearxngParser {
+    pub fn new() -> Self {
+        Self {
+            base: BaseParserImpl::new("SearxngParser".to_string(), SearchEngineType::SearxNG),
+        }
+    }
+}
+
+impl BaseParser for SearxngParser {
+    fn name(&self) -> &str {
+        self.base.name()
+    }
+
+    fn engine_type(&self) -> SearchEngineType {
+        self.base.engine_type()
+    }
+
+    fn parse(&self, content: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let json = helpers::parse_json_tolerant(content)?;
+        // Engines that failed are listed as [name, reason] pairs; the others still answered
+        for engine in helpers::extract_json_array(&json, "unresponsive_engines").unwrap_or_default()
+        {
+            tracing::warn!(
+                "{}: SearxNG engine did not respond: {}",
+                self.name(),
+                engine
+            );
+        }
+
+        let Some(items) = helpers::extract_json_array(&json, "results") else {
+            tracing::warn!("{}: response has no results", self.name());
+            return Ok(Vec::new());
+        };
+        let results = items
+            .iter()
+            .filter_map(|item| {
+                let result = helpers::create_search_result_tolerant(
+                    item,
+                    self.name(),
+                    &["title"],
+                    &["url"],
+                    &["content"],
+                    0,
+                )?;
+                Some(SearchResult {
+                    date: helpers::json_result_date(item, &["publishedDate"]),
+                    ..result
+                })
+            })
+            .take(limit)
+            .enumerate()
+            .map(|(i, result)| SearchResult {
+                rank: i + 1,
+                ..result
+            })
+            .collect();
+        Ok(results)
+    }
+
+    fn parse_related_queries(&self, content: &str) -> Vec<String> {
+        let Ok(json) = helpers::parse_json_tolerant(content) else {
+            return Vec::new();
+        };
+        let queries = ["corrections", "suggestions"]
+            .into_iter()
+            .flat_map(|field| helpers::extract_json_array(&json, field).unwrap_or_default())
+            .filter_map(|query| query.as_str().map(str::to_string));
+        helpers::unique_queries(queries)
+    }
+}
+
+impl Default for SearxngParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_searxng_parser() {
+        let parser = SearxngParser::new();
+        let json = r#"{
+            "query": "rust",
+            "number_of_results": 0,
+            "results": [
+                {
+                    "url": "https://www.rust-lang.org/",
+                    "title": "Rust Programming Language",
+                    "content": "A language empowering everyone.",
+                    "engine": "duckduckgo",
+                    "parsed_url": ["https", "www.rust-lang.org", "/", "", "", ""],
+                    "template": "default.html",
+                    "engines": ["duckduckgo", "wikipedia"],
+                    "positions": [1, 2],
+                    "publishedDate": "2024-07-25T00:00:00",
+                    "score": 4.0,
+                    "category": "general"
+                },
+                {"url": "https://example.com/untitled", "title": "", "content": "No title"},
+                {
+                    "url": "https://doc.rust-lang.org/book/",
+                    "title": "The Rust Programming Language",
+                    "engine": "wikipedia",
+                    "publishedDate": null
+                },
+                {"url": "https://crates.io/", "title": "crates.io", "content": "Registry"}
+            ],
+            "answers": [],
+            "corrections": [],
+            "infoboxes": [],
+            "suggestions": ["rust book", "rust async", "rust book"],
+            "unresponsive_engines": [["google", "timeout"]]
+        }"#;
+
+        let results = parser.parse(json, 2).unwrap();
+        assert_eq!(parser.name(), "SearxngParser");
+        assert!(parser.supports(&SearchEngineType::SearxNG));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "Rust Programming Language");
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert_eq!(results[0].snippet, "A language empowering everyone.");
+        assert_eq!(results[0].rank, 1);
+        assert_eq!(results[0].date.as_deref(), Some("2024-07-25T00:00:00"));
+        assert_eq!(results[1].title, "The Rust Programming Language");
+        assert_eq!(results[1].snippet, "");
+        assert_eq!(results[1].rank, 2);
+        assert_eq!(results[1].date, None);
+
+        assert_eq!(
+            parser.parse_related_queries(json),
+            vec!["rust book".to_string(), "rust async".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_searxng_parser_errors() {
+        let parser = SearxngParser::new();
+        // Instances with the JSON format disabled answer with an HTML page
+        assert!(parser.parse("<html>Forbidden</html>", 10).is_err());
+        assert!(parser.parse(r#"{"query": "rust"}"#, 10).unwrap().is_empty());
+        assert!(parser.parse_related_queries("not json").is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+use super::base::{BaseParser, BaseParserImpl, helpers};
+use crate::Result;
+use crate::error::TarziError;
+use crate::search::types::{SearchEngineType, SearchResult};
+
+/// SerpApi parser (JSON-based)
+pub struct SerpApiParser {
+    base: BaseParserImpl,
+}
+
+impl SerpApiParser {
+    pub fn new() -> Self {
+        Self {
+            base: BaseParserImpl::new("SerpApiParser".to_string(), SearchEngineType::SerpApi),
+        }
+    }
+}
+
+impl BaseParser for SerpApiParser {
+    fn name(&self) -> &str {
+        self.base.name()
+    }
+
+    fn engine_type(&self) -> SearchEngineType {
+        self.base.engine_type()
+    }
+
+    fn parse(&self, content: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let json = helpers::parse_json_tolerant(content)?;
+        if let Some(error) = json["error"].as_str() {
+            return Err(TarziError::Search(format!("SerpApi error: {error}")));
+        }
+
+        let Some(items) = helpers::extract_json_array(&json, "organic_results") else {
+            tracing::warn!("{}: response has no organic_results", self.name());
+            return Ok(Vec::new());
+        };
+        let results = items
+            .iter()
+            .filter_map(|item| {
+                let result = helpers::create_search_result_tolerant(
+                    item,
+                    self.name(),
+                    &["title"],
+                    &["link", "url"],
+                    &["snippet", "description"],
+                    0,
+                )?;
+                Some(SearchResult {
+                    date: helpers::json_result_date(item, &["date"]),
+                    ..result
+                })
+            })
+            .take(limit)
+            .enumerate()
+            .map(|(i, result)| SearchResult {
+                rank: i + 1,
+                ..result
+            })
+            .collect();
+        Ok(results)
+    }
+
+    fn parse_related_queries(&self, content: &str) -> Vec<String> {
+        let Ok(json) = helpers::parse_json_tolerant(content) else {
+            return Vec::new();
+        };
+        let questions = helpers::extract_json_array(&json, "related_questions")
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| helpers::extract_json_text(&item, "question"));
+        let related = helpers::extract_json_array(&json, "related_searches")
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| helpers::extract_json_text(&item, "query"));
+        helpers::unique_queries(questions.chain(related))
+    }
+}
+
+impl Default for SerpApiParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serpapi_parser() {
+        let parser = SerpApiParser::new();
+        let json = r#"{
+            "search_metadata": {"status": "Success"},
+            "organic_results": [
+                {"position": 1, "title": "Rust", "link": "https://www.rust-lang.org/", "snippet": "A language", "date": "Jul 25, 2024"},
+                {"position": 2, "title": "", "link": "https://example.com/untitled"},
+                {"position": 3, "title": "The Book", "link": "https://doc.rust-lang.org/book/"},
+                {"position": 4, "title": "Crates", "link": "https://crates.io/", "snippet": "Registry"}
+            ],
+            "related_questions": [{"question": "Is Rust fast?"}],
+            "related_searches": [{"query": "rust book"}, {"query": "rust book"}]
+        }"#;
+
+        let results = parser.parse(json, 2).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "Rust");
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert_eq!(results[0].snippet, "A language");
+        assert_eq!(results[0].rank, 1);
+        assert_eq!(results[0].date.as_deref(), Some("2024-07-25"));
+        assert_eq!(results[1].title, "The Book");
+        assert_eq!(results[1].snippet, "");
+        assert_eq!(results[1].rank, 2);
+        assert_eq!(results[1].date, None);
+
+        assert_eq!(
+            parser.parse_related_queries(json),
+            vec!["Is Rust fast?".to_string(), "rust book".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_serpapi_parser_errors() {
+        let parser = SerpApiParser::new();
+
+        let err = parser
+            .parse(r#"{"error": "Invalid API key."}"#, 10)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid API key."));
+
+        assert!(parser.parse("<html>not json</html>", 10).is_err());
+        assert!(
+            parser
+                .parse(r#"{"organic_results": []}"#, 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(parser.parse_related_queries("not json").is_empty());
+    }
+
+    #[test]
+    fn test_serpapi_parser_schema_tolerance() {
+        let parser = SerpApiParser::new();
+        let json = r#")]}'
+        {
+            "organic_results": [
+                {"title": "Renamed", "url": "https://example.com/a", "description": "Moved"},
+                {"title": "No link"},
+                {"title": 42, "link": "https://example.com/b"}
+            ]
+        }"#;
+
+        let results = parser.parse(json, 10).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].url, "https://example.com/a");
+        assert_eq!(results[0].snippet, "Moved");
+        assert_eq!(results[1].title, "42");
+        assert_eq!(results[1].rank, 2);
+
+        assert!(parser.parse(r#"{"ads": []}"#, 10).unwrap().is_empty());
+    }
+}
@@ -40,7 +40,7 @@ impl BaseParser for SogouWeixinParser {
             || html.contains("验证码：")
             || html.contains("VerifyCode")
         {
-            return Err(crate::error::TarziError::Search(
+            return Err(crate::error::TarziError::Blocked(
                 "Sogou WeChat search detected automated request and is showing CAPTCHA page. \
                 This is a common anti-bot measure. Try accessing the site manually first or use a different search engine."
                     .to_string(),
@@ -102,6 +102,7 @@ impl BaseParser for SogouWeixinParser {
                     url: resolved_url,
                     snippet,
                     rank: results.len() + 1,
+                    date: None,
                 });
                 continue;
             };
@@ -140,6 +141,7 @@ impl BaseParser for SogouWeixinParser {
                 url: resolved_url,
                 snippet,
                 rank: results.len() + 1,
+                date: None,
             });
         }
 
@@ -413,7 +415,9 @@ mod tests {
 
         let result = parser.parse(captcha_html, 10);
         assert!(result.is_err());
-        let error_msg = result.unwrap_err().to_string();
+        let error = result.unwrap_err();
+        assert_eq!(error.exit_code(), crate::constants::EXIT_CODE_BLOCKED);
+        let error_msg = error.to_string();
         assert!(error_msg.contains("CAPTCHA page"));
         assert!(error_msg.contains("anti-bot measure"));
 
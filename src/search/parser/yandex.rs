@@ -0,0 +1,200 @@
+use super::base::{BaseParser, BaseParserImpl};
+use crate::Result;
+use crate::search::types::{SearchEngineType, SearchResult};
+use select::document::Document;
+use select::node::Node;
+use select::predicate::{And, Class, Descendant, Name};
+
+/// `data-fast-name` values of sponsored (Yandex Direct) result blocks
+const AD_BLOCK_NAMES: [&str; 2] = ["direct", "adv"];
+
+/// Host of the Yandex ad click counter that sponsored links point to
+const AD_REDIRECT_HOST: &str = "yabs.yandex.";
+
+pub struct YandexParser {
+    base: BaseParserImpl,
+}
+
+impl YandexParser {
+    pub fn new() -> Self {
+        Self {
+            base: BaseParserImpl::new("YandexParser".to_string(), SearchEngineType::Yandex),
+        }
+    }
+
+    fn is_ad(node: &Node) -> bool {
+        node.attr("data-fast-name")
+            .is_some_and(|name| AD_BLOCK_NAMES.contains(&name))
+    }
+
+    /// The result's title link: the `h2` link, or the `OrganicTitle-Link` class
+    /// used when the title is not wrapped in a heading
+    fn title_link<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+        node.find(Descendant(Name("h2"), Name("a")))
+            .next()
+            .or_else(|| node.find(Class("OrganicTitle-Link")).next())
+    }
+
+    fn snippet(node: &Node) -> String {
+        ["OrganicTextContentSpan", "TextContainer", "organic__text"]
+            .iter()
+            .find_map(|class| node.find(Class(*class)).next())
+            .map(|n| n.text().split_whitespace().collect::<Vec<_>>().join(" "))
+            .unwrap_or_default()
+    }
+}
+
+impl BaseParser for YandexParser {
+    fn name(&self) -> &str {
+        self.base.name()
+    }
+    fn engine_type(&self) -> SearchEngineType {
+        self.base.engine_type()
+    }
+
+    fn parse(&self, html: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let document = Document::from(html);
+        let mut results = Vec::new();
+
+        if limit == 0 {
+            return Ok(results);
+        }
+
+        for node in document.find(And(Name("li"), Class("serp-item"))) {
+            if results.len() >= limit {
+                break;
+            }
+            if Self::is_ad(&node) {
+                continue;
+            }
+
+            let Some(link) = Self::title_link(&node) else {
+                continue;
+            };
+            let title = link.text().trim().to_string();
+            let url = link.attr("href").unwrap_or_default().to_string();
+            if title.is_empty() || url.is_empty() || url.contains(AD_REDIRECT_HOST) {
+                continue;
+            }
+
+            results.push(SearchResult {
+                title,
+                url,
+                snippet: Self::snippet(&node),
+                rank: results.len() + 1,
+                date: None,
+            });
+        }
+        Ok(results)
+    }
+}
+
+impl Default for YandexParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trimmed copy of a saved yandex.com results page for "rust programming language"
+    const RESULTS_PAGE: &str = r#"
+    <html>
+      <body>
+        <ul id="search-result" class="serp-list serp-list_left_yes">
+          <li class="serp-item serp-item_card" data-cid="0" data-fast-name="direct">
+            <div class="Organic">
+              <h2><a class="OrganicTitle-Link" href="https://yabs.yandex.ru/count/WdqejI_zO">
+                <span class="OrganicTitle-LinkText">Learn Rust online — courses</span>
+              </a></h2>
+              <div class="TextContainer">Sponsored course listing</div>
+            </div>
+          </li>
+          <li class="serp-item serp-item_card" data-cid="1">
+            <div class="Organic organic">
+              <h2 class="OrganicTitle">
+                <a class="OrganicTitle-Link" href="https://www.rust-lang.org/">
+                  <span class="OrganicTitle-LinkText">Rust Programming Language</span>
+                </a>
+              </h2>
+              <div class="Organic-ContentWrapper">
+                <div class="TextContainer OrganicText">
+                  <span class="OrganicTextContentSpan">A language empowering everyone
+                    to build reliable and efficient software.</span>
+                </div>
+              </div>
+            </div>
+          </li>
+          <li class="serp-item serp-item_card" data-cid="2" data-fast-name="images">
+            <div class="Images">Images for rust programming language</div>
+          </li>
+          <li class="serp-item serp-item_card" data-cid="3">
+            <div class="Organic organic">
+              <a class="OrganicTitle-Link" href="https://ru.wikipedia.org/wiki/Rust_(язык_программирования)">
+                <span class="OrganicTitle-LinkText">Rust (язык программирования) — Википедия</span>
+              </a>
+              <div class="TextContainer OrganicText">
+                <span class="OrganicTextContentSpan">Rust — мультипарадигменный компилируемый
+                  язык программирования общего назначения.</span>
+              </div>
+            </div>
+          </li>
+          <li class="serp-item serp-item_card" data-cid="4">
+            <div class="Organic organic">
+              <h2><a class="OrganicTitle-Link" href="https://doc.rust-lang.org/book/">
+                <span class="OrganicTitle-LinkText">The Rust Programming Language - The Rust Book</span>
+              </a></h2>
+              <div class="organic__text">by Steve Klabnik and Carol Nichols</div>
+            </div>
+          </li>
+        </ul>
+      </body>
+    </html>
+    "#;
+
+    #[test]
+    fn test_yandex_parser() {
+        let parser = YandexParser::new();
+        let results = parser.parse(RESULTS_PAGE, 10).unwrap();
+
+        assert_eq!(parser.name(), "YandexParser");
+        assert!(parser.supports(&SearchEngineType::Yandex));
+        assert!(!parser.supports(&SearchEngineType::Google));
+
+        // The ad and the image block are skipped
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].title, "Rust Programming Language");
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+        assert_eq!(
+            results[0].snippet,
+            "A language empowering everyone to build reliable and efficient software."
+        );
+        assert_eq!(results[0].rank, 1);
+
+        assert_eq!(results[1].title, "Rust (язык программирования) — Википедия");
+        assert_eq!(
+            results[1].url,
+            "https://ru.wikipedia.org/wiki/Rust_(язык_программирования)"
+        );
+        assert!(results[1].snippet.starts_with("Rust — мультипарадигменный"));
+        assert_eq!(results[1].rank, 2);
+
+        assert_eq!(results[2].url, "https://doc.rust-lang.org/book/");
+        assert_eq!(results[2].snippet, "by Steve Klabnik and Carol Nichols");
+        assert_eq!(results[2].rank, 3);
+    }
+
+    #[test]
+    fn test_yandex_parser_limit_and_empty_input() {
+        let parser = YandexParser::new();
+
+        let results = parser.parse(RESULTS_PAGE, 2).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].rank, 2);
+
+        assert!(parser.parse(RESULTS_PAGE, 0).unwrap().is_empty());
+        assert!(parser.parse("", 5).unwrap().is_empty());
+    }
+}
@@ -1,13 +1,32 @@
-use super::types::{SearchEngineType, SearchResult};
+use super::types::{SafeSearch, SearchEngineType, SearchResult};
 use crate::Result;
-use crate::fetcher::WebFetcher;
+use crate::constants::{
+    API_KEY_COOLDOWN, BING_API_KEY_HEADER, COUNTRY_SETTING, DEFAULT_SERPAPI_ENGINE,
+    FETCH_MODE_SETTING, GOOGLE_CSE_CX_SETTING, LANGUAGE_SETTING, SAFE_SEARCH_SETTING,
+    SEARXNG_ENGINES_SETTING, SERPAPI_ENGINE_SETTING,
+};
+use crate::error::TarziError;
+use crate::fetcher::{FetchMode, WebFetcher};
 use crate::search::parser::ParserFactory;
 use async_trait::async_trait;
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Instant;
 
-/// Provider configuration for web search only
+/// Provider configuration
 #[derive(Debug)]
 pub struct ProviderConfig {
     pub fetcher: Box<WebFetcher>,
+    /// API keys for API-backed providers, used in turn; ignored by web providers
+    pub api_keys: Vec<String>,
+    /// Base URL replacing a web provider's default domain (`search.provider_urls`),
+    /// or the address of a SearxNG instance
+    pub base_url: Option<String>,
+    /// Further provider-specific settings, e.g. `cx` (the Programmable Search
+    /// Engine ID) for Google CSE or `engines` for SearxNG
+    pub extra: HashMap<String, String>,
 }
 
 /// Unified interface for all search providers
@@ -37,11 +56,68 @@ macro_rules! impl_search_provider {
         #[derive(Debug)]
         pub struct $provider_name {
             fetcher: WebFetcher,
+            fetch_mode: FetchMode,
+            base_url: Option<String>,
+            country: Option<String>,
+            language: Option<String>,
+            safe_search: SafeSearch,
         }
 
         impl $provider_name {
             pub fn new_web(fetcher: WebFetcher) -> Self {
-                Self { fetcher }
+                Self {
+                    fetcher,
+                    fetch_mode: FetchMode::BrowserHeadless,
+                    base_url: None,
+                    country: None,
+                    language: None,
+                    safe_search: SafeSearch::default(),
+                }
+            }
+
+            /// Fetch result pages in `mode` instead of a headless browser
+            pub fn with_fetch_mode(mut self, mode: FetchMode) -> Self {
+                self.fetch_mode = mode;
+                self
+            }
+
+            /// Search a mirror or regional domain instead of the default one
+            pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+                self.base_url = Some(base_url.into());
+                self
+            }
+
+            /// Localize results for a country and language, if the engine supports it
+            pub fn with_region(
+                mut self,
+                country: Option<String>,
+                language: Option<String>,
+            ) -> Self {
+                self.country = country;
+                self.language = language;
+                self
+            }
+
+            /// Filter explicit results at `level`, if the engine supports it
+            pub fn with_safe_search(mut self, level: SafeSearch) -> Self {
+                self.safe_search = level;
+                self
+            }
+
+            /// Search URL for `query`, with the region and safe search parameters applied
+            pub fn search_url(&self, query: &str) -> String {
+                let query_pattern = match &self.base_url {
+                    Some(base_url) => $engine_type.query_pattern_with_base(base_url),
+                    None => $engine_type.get_query_pattern(),
+                };
+                let mut search_url = query_pattern.replace("{query}", &urlencoding::encode(query));
+                for (key, value) in
+                    $engine_type.region_params(self.country.as_deref(), self.language.as_deref())
+                {
+                    search_url.push_str(&format!("&{key}={}", urlencoding::encode(&value)));
+                }
+                append_safe_search(&mut search_url, $engine_type, self.safe_search);
+                search_url
             }
         }
 
@@ -50,18 +126,15 @@ macro_rules! impl_search_provider {
             type Config = crate::fetcher::WebFetcher;
 
             fn new(config: Self::Config) -> Self {
-                Self { fetcher: config }
+                Self::new_web(config)
             }
 
             async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
-                let query_pattern = $engine_type.get_query_pattern();
-                let search_url = query_pattern.replace("{query}", &urlencoding::encode(query));
+                let search_url = self.search_url(query);
                 tracing::info!("{} web search: {}", stringify!($provider_name), search_url);
 
-                let search_page_content = self
-                    .fetcher
-                    .fetch_raw(&search_url, crate::fetcher::FetchMode::BrowserHeadless)
-                    .await?;
+                let search_page_content =
+                    self.fetcher.fetch_raw(&search_url, self.fetch_mode).await?;
 
                 // Use the parser to extract results
                 let parser = ParserFactory::new().get_parser(&$engine_type);
@@ -86,6 +159,531 @@ impl_search_provider!(DuckDuckGoProvider, SearchEngineType::DuckDuckGo);
 impl_search_provider!(BraveSearchProvider, SearchEngineType::BraveSearch);
 impl_search_provider!(BaiduSearchProvider, SearchEngineType::Baidu);
 impl_search_provider!(SougouWeixinProvider, SearchEngineType::SougouWeixin);
+impl_search_provider!(YandexSearchProvider, SearchEngineType::Yandex);
+
+/// SerpApi-backed provider, returning results of the engine SerpApi queries
+#[derive(Debug)]
+pub struct SerpApiProvider {
+    fetcher: WebFetcher,
+    api_keys: ApiKeyPool,
+    engine: String,
+    safe_search: SafeSearch,
+}
+
+impl SerpApiProvider {
+    pub fn new_api(fetcher: WebFetcher, api_key: Option<String>) -> Self {
+        Self {
+            fetcher,
+            api_keys: ApiKeyPool::new(api_key),
+            engine: DEFAULT_SERPAPI_ENGINE.to_string(),
+            safe_search: SafeSearch::default(),
+        }
+    }
+
+    /// Rotate through `keys` instead of the single configured key
+    pub fn with_api_keys(mut self, keys: Vec<String>) -> Self {
+        self.api_keys = ApiKeyPool::new(keys);
+        self
+    }
+
+    /// Set the engine SerpApi should query, e.g. "google" or "bing"
+    pub fn with_engine(mut self, engine: impl Into<String>) -> Self {
+        self.engine = engine.into();
+        self
+    }
+
+    /// Filter explicit results at `level`
+    pub fn with_safe_search(mut self, level: SafeSearch) -> Self {
+        self.safe_search = level;
+        self
+    }
+}
+
+#[async_trait]
+impl SearchProvider for SerpApiProvider {
+    type Config = ProviderConfig;
+
+    fn new(config: Self::Config) -> Self {
+        let safe_search = safe_search_setting(&config.extra);
+        let provider = Self::new_api(*config.fetcher, None)
+            .with_api_keys(config.api_keys)
+            .with_safe_search(safe_search);
+        match config.extra.get(SERPAPI_ENGINE_SETTING) {
+            Some(engine) if !engine.is_empty() => provider.with_engine(engine),
+            _ => provider,
+        }
+    }
+
+    async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let mut search_url = SearchEngineType::SerpApi
+            .get_query_pattern()
+            .replace("{engine}", &urlencoding::encode(&self.engine))
+            .replace("{query}", &urlencoding::encode(query));
+        append_safe_search(&mut search_url, SearchEngineType::SerpApi, self.safe_search);
+        tracing::info!("SerpApiProvider api search: {}", search_url);
+
+        let content = fetch_api(
+            self.fetcher.http_client(),
+            &search_url,
+            SearchEngineType::SerpApi,
+            &self.api_keys,
+        )
+        .await?;
+        ParserFactory::new()
+            .get_parser(&SearchEngineType::SerpApi)
+            .parse(&content, limit)
+    }
+
+    fn is_healthy(&self) -> bool {
+        !self.api_keys.is_empty()
+    }
+
+    fn get_engine_type(&self) -> SearchEngineType {
+        SearchEngineType::SerpApi
+    }
+}
+
+/// Bing Web Search API provider, authenticated with an Azure subscription key
+#[derive(Debug)]
+pub struct BingApiProvider {
+    fetcher: WebFetcher,
+    api_keys: ApiKeyPool,
+    safe_search: SafeSearch,
+}
+
+impl BingApiProvider {
+    pub fn new_api(fetcher: WebFetcher, api_key: Option<String>) -> Self {
+        Self {
+            fetcher,
+            api_keys: ApiKeyPool::new(api_key),
+            safe_search: SafeSearch::default(),
+        }
+    }
+
+    /// Rotate through `keys` instead of the single configured key
+    pub fn with_api_keys(mut self, keys: Vec<String>) -> Self {
+        self.api_keys = ApiKeyPool::new(keys);
+        self
+    }
+
+    /// Filter explicit results at `level`
+    pub fn with_safe_search(mut self, level: SafeSearch) -> Self {
+        self.safe_search = level;
+        self
+    }
+}
+
+#[async_trait]
+impl SearchProvider for BingApiProvider {
+    type Config = ProviderConfig;
+
+    fn new(config: Self::Config) -> Self {
+        let safe_search = safe_search_setting(&config.extra);
+        Self::new_api(*config.fetcher, None)
+            .with_api_keys(config.api_keys)
+            .with_safe_search(safe_search)
+    }
+
+    async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let mut search_url = SearchEngineType::BingApi
+            .get_query_pattern()
+            .replace("{query}", &urlencoding::encode(query));
+        append_safe_search(&mut search_url, SearchEngineType::BingApi, self.safe_search);
+        tracing::info!("BingApiProvider api search: {}", search_url);
+
+        let content = fetch_api(
+            self.fetcher.http_client(),
+            &search_url,
+            SearchEngineType::BingApi,
+            &self.api_keys,
+        )
+        .await?;
+        ParserFactory::new()
+            .get_parser(&SearchEngineType::BingApi)
+            .parse(&content, limit)
+    }
+
+    fn is_healthy(&self) -> bool {
+        !self.api_keys.is_empty()
+    }
+
+    fn get_engine_type(&self) -> SearchEngineType {
+        SearchEngineType::BingApi
+    }
+}
+
+/// Google Custom Search JSON API provider, querying one Programmable Search Engine
+#[derive(Debug)]
+pub struct GoogleCseProvider {
+    fetcher: WebFetcher,
+    api_keys: ApiKeyPool,
+    cx: Option<String>,
+    safe_search: SafeSearch,
+}
+
+impl GoogleCseProvider {
+    pub fn new_api(fetcher: WebFetcher, api_key: Option<String>, cx: Option<String>) -> Self {
+        Self {
+            fetcher,
+            api_keys: ApiKeyPool::new(api_key),
+            cx: cx.filter(|cx| !cx.is_empty()),
+            safe_search: SafeSearch::default(),
+        }
+    }
+
+    /// Rotate through `keys` instead of the single configured key
+    pub fn with_api_keys(mut self, keys: Vec<String>) -> Self {
+        self.api_keys = ApiKeyPool::new(keys);
+        self
+    }
+
+    /// Filter explicit results at `level`
+    pub fn with_safe_search(mut self, level: SafeSearch) -> Self {
+        self.safe_search = level;
+        self
+    }
+}
+
+#[async_trait]
+impl SearchProvider for GoogleCseProvider {
+    type Config = ProviderConfig;
+
+    fn new(config: Self::Config) -> Self {
+        let cx = config.extra.get(GOOGLE_CSE_CX_SETTING).cloned();
+        let safe_search = safe_search_setting(&config.extra);
+        Self::new_api(*config.fetcher, None, cx)
+            .with_api_keys(config.api_keys)
+            .with_safe_search(safe_search)
+    }
+
+    async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let cx = self.cx.as_deref().ok_or_else(|| {
+            TarziError::Config(
+                "search.google_cse_cx is required for the google_cse engine".to_string(),
+            )
+        })?;
+        let mut search_url = SearchEngineType::GoogleCse
+            .get_query_pattern()
+            .replace("{cx}", &urlencoding::encode(cx))
+            .replace("{query}", &urlencoding::encode(query));
+        append_safe_search(
+            &mut search_url,
+            SearchEngineType::GoogleCse,
+            self.safe_search,
+        );
+        tracing::info!("GoogleCseProvider api search: {}", search_url);
+
+        let content = fetch_api(
+            self.fetcher.http_client(),
+            &search_url,
+            SearchEngineType::GoogleCse,
+            &self.api_keys,
+        )
+        .await?;
+        ParserFactory::new()
+            .get_parser(&SearchEngineType::GoogleCse)
+            .parse(&content, limit)
+    }
+
+    fn is_healthy(&self) -> bool {
+        !self.api_keys.is_empty() && self.cx.is_some()
+    }
+
+    fn get_engine_type(&self) -> SearchEngineType {
+        SearchEngineType::GoogleCse
+    }
+}
+
+/// Provider querying the JSON API of a self-hosted SearxNG instance
+#[derive(Debug)]
+pub struct SearxngProvider {
+    fetcher: WebFetcher,
+    base_url: Option<String>,
+    engines: Vec<String>,
+    safe_search: SafeSearch,
+}
+
+impl SearxngProvider {
+    pub fn new_api(fetcher: WebFetcher, base_url: Option<String>) -> Self {
+        Self {
+            fetcher,
+            base_url: base_url.filter(|base_url| !base_url.is_empty()),
+            engines: Vec::new(),
+            safe_search: SafeSearch::default(),
+        }
+    }
+
+    /// Only query these SearxNG engines instead of the instance's defaults
+    pub fn with_engines(mut self, engines: Vec<String>) -> Self {
+        self.engines = engines;
+        self
+    }
+
+    /// Filter explicit results at `level`
+    pub fn with_safe_search(mut self, level: SafeSearch) -> Self {
+        self.safe_search = level;
+        self
+    }
+}
+
+#[async_trait]
+impl SearchProvider for SearxngProvider {
+    type Config = ProviderConfig;
+
+    fn new(config: Self::Config) -> Self {
+        let engines = config
+            .extra
+            .get(SEARXNG_ENGINES_SETTING)
+            .map(|engines| {
+                engines
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|engine| !engine.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let safe_search = safe_search_setting(&config.extra);
+        Self::new_api(*config.fetcher, config.base_url)
+            .with_engines(engines)
+            .with_safe_search(safe_search)
+    }
+
+    async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let base_url = self.base_url.as_deref().ok_or_else(|| {
+            TarziError::Config(
+                "search.searxng_base_url is required for the searxng engine".to_string(),
+            )
+        })?;
+        let mut search_url = SearchEngineType::SearxNG
+            .query_pattern_with_base(base_url)
+            .replace("{query}", &urlencoding::encode(query));
+        if !self.engines.is_empty() {
+            search_url.push_str("&engines=");
+            search_url.push_str(&urlencoding::encode(&self.engines.join(",")));
+        }
+        append_safe_search(&mut search_url, SearchEngineType::SearxNG, self.safe_search);
+        tracing::info!("SearxngProvider api search: {}", search_url);
+
+        let content = fetch_open_api(
+            self.fetcher.http_client(),
+            &search_url,
+            SearchEngineType::SearxNG,
+        )
+        .await?;
+        ParserFactory::new()
+            .get_parser(&SearchEngineType::SearxNG)
+            .parse(&content, limit)
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.base_url.is_some()
+    }
+
+    fn get_engine_type(&self) -> SearchEngineType {
+        SearchEngineType::SearxNG
+    }
+}
+
+/// Append `engine_type`'s parameter filtering explicit results at `level`, if any
+fn append_safe_search(search_url: &mut String, engine_type: SearchEngineType, level: SafeSearch) {
+    if let Some((key, value)) = engine_type.safe_search_param(level) {
+        search_url.push_str(&format!("&{key}={value}"));
+    }
+}
+
+/// Fetch mode in a provider's `extra` settings, a headless browser if unset or invalid
+fn fetch_mode_setting(extra: &HashMap<String, String>) -> FetchMode {
+    extra
+        .get(FETCH_MODE_SETTING)
+        .and_then(|mode| FetchMode::from_str(mode).ok())
+        .unwrap_or(FetchMode::BrowserHeadless)
+}
+
+/// Safe search level in a provider's `extra` settings, moderate if unset or invalid
+fn safe_search_setting(extra: &HashMap<String, String>) -> SafeSearch {
+    extra
+        .get(SAFE_SEARCH_SETTING)
+        .and_then(|level| SafeSearch::from_str(level).ok())
+        .unwrap_or_default()
+}
+
+/// API keys for one provider, used round-robin. A key answered with 401 or 429 cools
+/// down for a while and is skipped until then, unless every key is cooling down.
+#[derive(Debug, Default)]
+pub struct ApiKeyPool {
+    keys: Vec<String>,
+    state: Mutex<KeyPoolState>,
+}
+
+#[derive(Debug, Default)]
+struct KeyPoolState {
+    next: usize,
+    cooldown_until: Vec<Option<Instant>>,
+}
+
+impl ApiKeyPool {
+    /// Create a pool from `keys`, dropping empty and duplicate keys
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut unique: Vec<String> = Vec::new();
+        for key in keys.into_iter().map(Into::into) {
+            if !key.is_empty() && !unique.contains(&key) {
+                unique.push(key);
+            }
+        }
+        let state = KeyPoolState {
+            next: 0,
+            cooldown_until: vec![None; unique.len()],
+        };
+        Self {
+            keys: unique,
+            state: Mutex::new(state),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// All keys in the pool, in rotation order
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Pick the next key in turn that is not cooling down. When all are, the one
+    /// whose cooldown ends first is used, so a single key is always tried.
+    pub fn next_key(&self) -> Option<(usize, &str)> {
+        if self.keys.is_empty() {
+            return None;
+        }
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let count = self.keys.len();
+        let index = (0..count)
+            .map(|offset| (state.next + offset) % count)
+            .find(|&i| state.cooldown_until[i].is_none_or(|until| until <= now))
+            .unwrap_or_else(|| {
+                (0..count)
+                    .min_by_key(|&i| state.cooldown_until[i])
+                    .unwrap_or(0)
+            });
+        state.cooldown_until[index] = None;
+        state.next = (index + 1) % count;
+        Some((index, &self.keys[index]))
+    }
+
+    /// Skip the key at `index` until `API_KEY_COOLDOWN` has passed
+    pub fn mark_cooling_down(&self, index: usize) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(until) = state.cooldown_until.get_mut(index) {
+            *until = Some(Instant::now() + API_KEY_COOLDOWN);
+        }
+    }
+
+    /// Number of keys that are currently not cooling down
+    pub fn available(&self) -> usize {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        state
+            .cooldown_until
+            .iter()
+            .filter(|until| until.is_none_or(|until| until <= now))
+            .count()
+    }
+}
+
+/// Call the JSON API of `engine` at `url`, authenticating with a key from `keys`:
+/// SerpApi takes it as the `api_key` parameter, Google CSE as `key`, the Bing API
+/// in a header. A key
+/// rejected with 401 or 429 is put on cooldown and the next one is tried; a 429 for
+/// the last key fails with [`TarziError::RateLimited`]. Other error responses carry
+/// a JSON error message, so the body is returned for the parser to report.
+pub(crate) async fn fetch_api(
+    client: &reqwest::Client,
+    url: &str,
+    engine: SearchEngineType,
+    keys: &ApiKeyPool,
+) -> Result<String> {
+    let key_setting = match engine {
+        SearchEngineType::BingApi => "bing_api_key",
+        SearchEngineType::GoogleCse => "google_cse_key",
+        _ => "serpapi_key",
+    };
+    let mut attempts = keys.available().max(1);
+    loop {
+        let (index, api_key) = keys.next_key().ok_or_else(|| {
+            TarziError::Config(format!(
+                "search.{key_setting} is required for the {} engine",
+                engine.name()
+            ))
+        })?;
+        let request = match engine {
+            SearchEngineType::BingApi => client.get(url).header(BING_API_KEY_HEADER, api_key),
+            SearchEngineType::GoogleCse => client.get(url).query(&[("key", api_key)]),
+            _ => client.get(url).query(&[("api_key", api_key)]),
+        };
+        let response = request.send().await?;
+        let status = response.status();
+        attempts -= 1;
+        if status == StatusCode::UNAUTHORIZED || status == StatusCode::TOO_MANY_REQUESTS {
+            keys.mark_cooling_down(index);
+            if attempts > 0 {
+                tracing::warn!(
+                    "{} key #{} rejected ({}), trying the next key",
+                    engine.name(),
+                    index + 1,
+                    status
+                );
+                continue;
+            }
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                return Err(TarziError::rate_limited(response.headers()));
+            }
+        }
+        return Ok(response.text().await?);
+    }
+}
+
+/// Call the JSON API of `engine`, which needs no key, at `url`. Error responses
+/// carry no JSON message, so they are reported by status (429 as
+/// [`TarziError::RateLimited`]).
+pub(crate) async fn fetch_open_api(
+    client: &reqwest::Client,
+    url: &str,
+    engine: SearchEngineType,
+) -> Result<String> {
+    let response = client.get(url).send().await?;
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(TarziError::rate_limited(response.headers()));
+    }
+    if !status.is_success() {
+        let hint = if engine == SearchEngineType::SearxNG && status == StatusCode::FORBIDDEN {
+            " (is the json format enabled in the instance's search.formats setting?)"
+        } else {
+            ""
+        };
+        return Err(TarziError::Search(format!(
+            "{} returned {status}{hint}",
+            engine.name()
+        )));
+    }
+    Ok(response.text().await?)
+}
+
+/// Whether `provider` can serve searches: its own configuration check and, for
+/// web providers, whether the WebDriver their browser fetches need is reachable
+pub fn provider_health<P: SearchProvider>(provider: &P, webdriver_available: bool) -> bool {
+    provider.is_healthy() && (provider.get_engine_type().is_api() || webdriver_available)
+}
 
 /// Provider variant enum for different search engines
 #[derive(Debug)]
@@ -96,30 +694,58 @@ pub enum ProviderVariant {
     BraveSearch(BraveSearchProvider),
     Baidu(BaiduSearchProvider),
     SougouWeixin(SougouWeixinProvider),
+    Yandex(YandexSearchProvider),
+    SerpApi(SerpApiProvider),
+    BingApi(BingApiProvider),
+    GoogleCse(GoogleCseProvider),
+    SearxNG(SearxngProvider),
 }
 
 impl ProviderVariant {
     /// Create a provider variant from engine type and configuration
     pub fn from_engine_type(engine_type: SearchEngineType, config: ProviderConfig) -> Result<Self> {
+        macro_rules! web_provider {
+            ($provider:ident) => {{
+                let provider = $provider::new_web(*config.fetcher)
+                    .with_fetch_mode(fetch_mode_setting(&config.extra))
+                    .with_region(
+                        config.extra.get(COUNTRY_SETTING).cloned(),
+                        config.extra.get(LANGUAGE_SETTING).cloned(),
+                    )
+                    .with_safe_search(safe_search_setting(&config.extra));
+                match config.base_url {
+                    Some(base_url) => provider.with_base_url(base_url),
+                    None => provider,
+                }
+            }};
+        }
+
         match engine_type {
-            SearchEngineType::Google => Ok(ProviderVariant::Google(GoogleSearchProvider::new_web(
-                *config.fetcher,
+            SearchEngineType::Google => {
+                Ok(ProviderVariant::Google(web_provider!(GoogleSearchProvider)))
+            }
+            SearchEngineType::Bing => Ok(ProviderVariant::Bing(web_provider!(BingSearchProvider))),
+            SearchEngineType::DuckDuckGo => Ok(ProviderVariant::DuckDuckGo(web_provider!(
+                DuckDuckGoProvider
             ))),
-            SearchEngineType::Bing => Ok(ProviderVariant::Bing(BingSearchProvider::new_web(
-                *config.fetcher,
+            SearchEngineType::BraveSearch => Ok(ProviderVariant::BraveSearch(web_provider!(
+                BraveSearchProvider
             ))),
-            SearchEngineType::DuckDuckGo => Ok(ProviderVariant::DuckDuckGo(
-                DuckDuckGoProvider::new_web(*config.fetcher),
-            )),
-            SearchEngineType::BraveSearch => Ok(ProviderVariant::BraveSearch(
-                BraveSearchProvider::new_web(*config.fetcher),
-            )),
-            SearchEngineType::Baidu => Ok(ProviderVariant::Baidu(BaiduSearchProvider::new_web(
-                *config.fetcher,
+            SearchEngineType::Baidu => {
+                Ok(ProviderVariant::Baidu(web_provider!(BaiduSearchProvider)))
+            }
+            SearchEngineType::SougouWeixin => Ok(ProviderVariant::SougouWeixin(web_provider!(
+                SougouWeixinProvider
             ))),
-            SearchEngineType::SougouWeixin => Ok(ProviderVariant::SougouWeixin(
-                SougouWeixinProvider::new_web(*config.fetcher),
-            )),
+            SearchEngineType::Yandex => {
+                Ok(ProviderVariant::Yandex(web_provider!(YandexSearchProvider)))
+            }
+            SearchEngineType::SerpApi => Ok(ProviderVariant::SerpApi(SerpApiProvider::new(config))),
+            SearchEngineType::BingApi => Ok(ProviderVariant::BingApi(BingApiProvider::new(config))),
+            SearchEngineType::GoogleCse => {
+                Ok(ProviderVariant::GoogleCse(GoogleCseProvider::new(config)))
+            }
+            SearchEngineType::SearxNG => Ok(ProviderVariant::SearxNG(SearxngProvider::new(config))),
         }
     }
 
@@ -132,6 +758,62 @@ impl ProviderVariant {
             ProviderVariant::BraveSearch(_) => SearchEngineType::BraveSearch,
             ProviderVariant::Baidu(_) => SearchEngineType::Baidu,
             ProviderVariant::SougouWeixin(_) => SearchEngineType::SougouWeixin,
+            ProviderVariant::Yandex(_) => SearchEngineType::Yandex,
+            ProviderVariant::SerpApi(_) => SearchEngineType::SerpApi,
+            ProviderVariant::BingApi(_) => SearchEngineType::BingApi,
+            ProviderVariant::GoogleCse(_) => SearchEngineType::GoogleCse,
+            ProviderVariant::SearxNG(_) => SearchEngineType::SearxNG,
+        }
+    }
+
+    /// Search with the wrapped provider
+    pub async fn search(&mut self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        match self {
+            ProviderVariant::Google(p) => p.search(query, limit).await,
+            ProviderVariant::Bing(p) => p.search(query, limit).await,
+            ProviderVariant::DuckDuckGo(p) => p.search(query, limit).await,
+            ProviderVariant::BraveSearch(p) => p.search(query, limit).await,
+            ProviderVariant::Baidu(p) => p.search(query, limit).await,
+            ProviderVariant::SougouWeixin(p) => p.search(query, limit).await,
+            ProviderVariant::Yandex(p) => p.search(query, limit).await,
+            ProviderVariant::SerpApi(p) => p.search(query, limit).await,
+            ProviderVariant::BingApi(p) => p.search(query, limit).await,
+            ProviderVariant::GoogleCse(p) => p.search(query, limit).await,
+            ProviderVariant::SearxNG(p) => p.search(query, limit).await,
+        }
+    }
+
+    /// Shut down the browser and driver the provider's fetcher may have started
+    pub async fn shutdown(&mut self) {
+        match self {
+            ProviderVariant::Google(p) => p.fetcher.shutdown().await,
+            ProviderVariant::Bing(p) => p.fetcher.shutdown().await,
+            ProviderVariant::DuckDuckGo(p) => p.fetcher.shutdown().await,
+            ProviderVariant::BraveSearch(p) => p.fetcher.shutdown().await,
+            ProviderVariant::Baidu(p) => p.fetcher.shutdown().await,
+            ProviderVariant::SougouWeixin(p) => p.fetcher.shutdown().await,
+            ProviderVariant::Yandex(p) => p.fetcher.shutdown().await,
+            ProviderVariant::SerpApi(p) => p.fetcher.shutdown().await,
+            ProviderVariant::BingApi(p) => p.fetcher.shutdown().await,
+            ProviderVariant::GoogleCse(p) => p.fetcher.shutdown().await,
+            ProviderVariant::SearxNG(p) => p.fetcher.shutdown().await,
+        }
+    }
+
+    /// See [`provider_health`]
+    pub fn is_healthy(&self, webdriver_available: bool) -> bool {
+        match self {
+            ProviderVariant::Google(p) => provider_health(p, webdriver_available),
+            ProviderVariant::Bing(p) => provider_health(p, webdriver_available),
+            ProviderVariant::DuckDuckGo(p) => provider_health(p, webdriver_available),
+            ProviderVariant::BraveSearch(p) => provider_health(p, webdriver_available),
+            ProviderVariant::Baidu(p) => provider_health(p, webdriver_available),
+            ProviderVariant::SougouWeixin(p) => provider_health(p, webdriver_available),
+            ProviderVariant::Yandex(p) => provider_health(p, webdriver_available),
+            ProviderVariant::SerpApi(p) => provider_health(p, webdriver_available),
+            ProviderVariant::BingApi(p) => provider_health(p, webdriver_available),
+            ProviderVariant::GoogleCse(p) => provider_health(p, webdriver_available),
+            ProviderVariant::SearxNG(p) => provider_health(p, webdriver_available),
         }
     }
 }
@@ -140,6 +822,7 @@ impl ProviderVariant {
 mod tests {
     use super::*;
     use crate::fetcher::WebFetcher;
+    use crate::search::test_server::{Request, Response, serve};
 
     #[test]
     fn test_google_search_provider() {
@@ -186,11 +869,137 @@ mod tests {
         assert!(provider.is_healthy());
     }
 
+    #[test]
+    fn test_serpapi_provider() {
+        let provider = SerpApiProvider::new_api(WebFetcher::new(), None);
+        assert_eq!(provider.get_engine_type(), SearchEngineType::SerpApi);
+        assert!(!provider.is_healthy());
+
+        let provider = SerpApiProvider::new(ProviderConfig {
+            fetcher: Box::new(WebFetcher::new()),
+            api_keys: vec!["key".to_string()],
+            base_url: None,
+            extra: HashMap::new(),
+        })
+        .with_engine("bing");
+        assert!(provider.is_healthy());
+        assert_eq!(provider.engine, "bing");
+    }
+
+    #[tokio::test]
+    async fn test_google_cse_provider_requires_key_and_cx() {
+        let provider = GoogleCseProvider::new(ProviderConfig {
+            fetcher: Box::new(WebFetcher::new()),
+            api_keys: vec!["key".to_string()],
+            base_url: None,
+            extra: HashMap::from([(GOOGLE_CSE_CX_SETTING.to_string(), "cse-id".to_string())]),
+        });
+        assert_eq!(provider.get_engine_type(), SearchEngineType::GoogleCse);
+        assert!(provider.is_healthy());
+
+        let mut provider = GoogleCseProvider::new_api(WebFetcher::new(), Some("key".into()), None);
+        assert!(!provider.is_healthy());
+        let err = provider.search("rust", 5).await.unwrap_err();
+        assert!(err.to_string().contains("search.google_cse_cx"));
+
+        let mut provider = GoogleCseProvider::new_api(WebFetcher::new(), None, Some("id".into()));
+        assert!(!provider.is_healthy());
+        let err = provider.search("rust", 5).await.unwrap_err();
+        assert!(err.to_string().contains("search.google_cse_key"));
+    }
+
+    #[tokio::test]
+    async fn test_searxng_provider() {
+        let mut provider = SearxngProvider::new_api(WebFetcher::new(), None);
+        assert_eq!(provider.get_engine_type(), SearchEngineType::SearxNG);
+        assert!(!provider.is_healthy());
+        let err = provider.search("rust", 5).await.unwrap_err();
+        assert!(err.to_string().contains("search.searxng_base_url"));
+
+        let base_url = serve(|request: &Request| {
+            if request.path
+                == "/search?q=rust%20lang&format=json&engines=google%2Cwikipedia&safesearch=2"
+            {
+                r#"{"results": [{"url": "https://www.rust-lang.org/", "title": "Rust", "content": "A language"}]}"#
+            } else {
+                "{}"
+            }
+        })
+        .await;
+
+        let mut provider = SearxngProvider::new(ProviderConfig {
+            fetcher: Box::new(WebFetcher::new()),
+            api_keys: Vec::new(),
+            base_url: Some(base_url),
+            extra: HashMap::from([
+                (
+                    SEARXNG_ENGINES_SETTING.to_string(),
+                    "google, wikipedia".to_string(),
+                ),
+                (SAFE_SEARCH_SETTING.to_string(), "strict".to_string()),
+            ]),
+        });
+        assert!(provider.is_healthy());
+        let results = provider.search("rust lang", 5).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].url, "https://www.rust-lang.org/");
+    }
+
+    #[test]
+    fn test_api_providers_safe_search() {
+        let config = || ProviderConfig {
+            fetcher: Box::new(WebFetcher::new()),
+            api_keys: vec!["key".to_string()],
+            base_url: None,
+            extra: HashMap::from([(SAFE_SEARCH_SETTING.to_string(), "strict".to_string())]),
+        };
+        assert_eq!(
+            SerpApiProvider::new(config()).safe_search,
+            SafeSearch::Strict
+        );
+        assert_eq!(
+            BingApiProvider::new(config()).safe_search,
+            SafeSearch::Strict
+        );
+        assert_eq!(
+            GoogleCseProvider::new(config()).safe_search,
+            SafeSearch::Strict
+        );
+        assert_eq!(
+            SearchEngineType::BingApi.safe_search_param(SafeSearch::Strict),
+            Some(("safeSearch", "Strict"))
+        );
+        assert_eq!(
+            SearchEngineType::GoogleCse.safe_search_param(SafeSearch::Off),
+            Some(("safe", "off"))
+        );
+
+        // Unset or invalid levels fall back to moderate
+        let mut config = config();
+        config
+            .extra
+            .insert(SAFE_SEARCH_SETTING.to_string(), "extreme".to_string());
+        assert_eq!(
+            SerpApiProvider::new(config).safe_search,
+            SafeSearch::Moderate
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serpapi_provider_requires_key() {
+        let mut provider = SerpApiProvider::new_api(WebFetcher::new(), None);
+        let err = provider.search("rust", 5).await.unwrap_err();
+        assert!(matches!(err, TarziError::Config(_)));
+    }
+
     #[test]
     fn test_provider_variant_from_engine_type() {
         let fetcher = WebFetcher::new();
         let config = ProviderConfig {
             fetcher: Box::new(fetcher),
+            api_keys: Vec::new(),
+            base_url: None,
+            extra: HashMap::new(),
         };
 
         // Test Google provider creation
@@ -202,6 +1011,9 @@ mod tests {
         let fetcher = WebFetcher::new();
         let config = ProviderConfig {
             fetcher: Box::new(fetcher),
+            api_keys: Vec::new(),
+            base_url: None,
+            extra: HashMap::new(),
         };
         let bing_variant =
             ProviderVariant::from_engine_type(SearchEngineType::Bing, config).unwrap();
@@ -210,6 +1022,9 @@ mod tests {
         let fetcher = WebFetcher::new();
         let config = ProviderConfig {
             fetcher: Box::new(fetcher),
+            api_keys: Vec::new(),
+            base_url: None,
+            extra: HashMap::new(),
         };
         let duckduckgo_variant =
             ProviderVariant::from_engine_type(SearchEngineType::DuckDuckGo, config).unwrap();
@@ -221,6 +1036,9 @@ mod tests {
         let fetcher = WebFetcher::new();
         let config = ProviderConfig {
             fetcher: Box::new(fetcher),
+            api_keys: Vec::new(),
+            base_url: None,
+            extra: HashMap::new(),
         };
         let brave_variant =
             ProviderVariant::from_engine_type(SearchEngineType::BraveSearch, config).unwrap();
@@ -229,6 +1047,9 @@ mod tests {
         let fetcher = WebFetcher::new();
         let config = ProviderConfig {
             fetcher: Box::new(fetcher),
+            api_keys: Vec::new(),
+            base_url: None,
+            extra: HashMap::new(),
         };
         let baidu_variant =
             ProviderVariant::from_engine_type(SearchEngineType::Baidu, config).unwrap();
@@ -266,6 +1087,9 @@ mod tests {
         let fetcher = WebFetcher::new();
         let config = ProviderConfig {
             fetcher: Box::new(fetcher),
+            api_keys: Vec::new(),
+            base_url: None,
+            extra: HashMap::new(),
         };
 
         // Test that config can be created and used
@@ -281,11 +1105,15 @@ mod tests {
             SearchEngineType::DuckDuckGo,
             SearchEngineType::BraveSearch,
             SearchEngineType::Baidu,
+            SearchEngineType::Yandex,
         ];
 
         for engine_type in engine_types {
             let config = ProviderConfig {
                 fetcher: Box::new(WebFetcher::new()),
+                api_keys: Vec::new(),
+                base_url: None,
+                extra: HashMap::new(),
             };
             let variant = ProviderVariant::from_engine_type(engine_type, config);
             assert!(
@@ -314,4 +1142,167 @@ mod tests {
         assert_eq!(bing_provider.get_engine_type(), SearchEngineType::Bing);
         assert!(bing_provider.is_healthy());
     }
+
+    /// Provider with a fixed engine type and configuration health
+    struct MockProvider {
+        engine_type: SearchEngineType,
+        healthy: bool,
+    }
+
+    #[async_trait]
+    impl SearchProvider for MockProvider {
+        type Config = (SearchEngineType, bool);
+
+        fn new((engine_type, healthy): Self::Config) -> Self {
+            Self {
+                engine_type,
+                healthy,
+            }
+        }
+
+        async fn search(&mut self, _query: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+            Ok(Vec::new())
+        }
+
+        fn is_healthy(&self) -> bool {
+            self.healthy
+        }
+
+        fn get_engine_type(&self) -> SearchEngineType {
+            self.engine_type
+        }
+    }
+
+    #[test]
+    fn test_provider_health() {
+        // Web providers need a reachable WebDriver, API providers don't
+        let web = MockProvider::new((SearchEngineType::Google, true));
+        assert!(provider_health(&web, true));
+        assert!(!provider_health(&web, false));
+        let api = MockProvider::new((SearchEngineType::BingApi, true));
+        assert!(provider_health(&api, false));
+
+        // An unhealthy provider stays unhealthy whatever the driver state
+        let unconfigured = MockProvider::new((SearchEngineType::SerpApi, false));
+        assert!(!provider_health(&unconfigured, true));
+        let unconfigured = MockProvider::new((SearchEngineType::Bing, false));
+        assert!(!provider_health(&unconfigured, true));
+    }
+
+    #[test]
+    fn test_api_key_pool_rotation() {
+        let pool = ApiKeyPool::new(["a", "", "b", "a", "c"]);
+        assert_eq!(pool.len(), 3);
+        let key = |pool: &ApiKeyPool| pool.next_key().unwrap().1.to_string();
+        assert_eq!(key(&pool), "a");
+        assert_eq!(key(&pool), "b");
+        assert_eq!(key(&pool), "c");
+        assert_eq!(key(&pool), "a");
+
+        // A cooling-down key is skipped while others are available
+        pool.mark_cooling_down(1);
+        assert_eq!(pool.available(), 2);
+        assert_eq!(key(&pool), "c");
+        assert_eq!(key(&pool), "a");
+        assert_eq!(key(&pool), "c");
+
+        // A single key keeps being used even while cooling down
+        let single = ApiKeyPool::new(["only"]);
+        single.mark_cooling_down(0);
+        assert_eq!(key(&single), "only");
+        assert!(ApiKeyPool::new(Vec::<String>::new()).next_key().is_none());
+    }
+
+    /// Test that a key rejected with 429 is skipped in favour of the next one
+    #[tokio::test]
+    async fn test_fetch_serpapi_rotates_rejected_keys() {
+        let base_url = serve(|request: &Request| {
+            if request.path.contains("api_key=spent") {
+                Response::ok("{}").status("429 Too Many Requests")
+            } else {
+                Response::ok("{\"ok\":true}")
+            }
+        })
+        .await;
+
+        let keys = ApiKeyPool::new(["spent", "fresh"]);
+        let client = reqwest::Client::new();
+        let url = format!("{base_url}/search.json");
+        let body = fetch_api(&client, &url, SearchEngineType::SerpApi, &keys)
+            .await
+            .unwrap();
+        assert_eq!(body, "{\"ok\":true}");
+        assert_eq!(keys.available(), 1);
+
+        // The rejected key stays on cooldown for the next request
+        assert_eq!(keys.next_key().unwrap().1, "fresh");
+    }
+
+    /// Test that a 429 for the last key fails with RateLimited carrying Retry-After
+    #[tokio::test]
+    async fn test_fetch_api_rate_limited() {
+        let base_url = serve(|_: &Request| {
+            Response::ok("{}")
+                .status("429 Too Many Requests")
+                .header("Retry-After", "5")
+        })
+        .await;
+
+        let client = reqwest::Client::new();
+        let expected = Some(std::time::Duration::from_secs(5));
+        let keys = ApiKeyPool::new(["spent"]);
+        let url = format!("{base_url}/search.json");
+        let error = fetch_api(&client, &url, SearchEngineType::SerpApi, &keys)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, TarziError::RateLimited { retry_after } if retry_after == expected),
+            "{error:?}"
+        );
+
+        let url = format!("{base_url}/search?format=json");
+        let error = fetch_open_api(&client, &url, SearchEngineType::SearxNG)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, TarziError::RateLimited { retry_after } if retry_after == expected),
+            "{error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_bing_api_sends_key_header() {
+        let base_url = serve(|request: &Request| {
+            if request.header("ocp-apim-subscription-key") == Some("azure-key")
+                && !request.path.contains("api_key=")
+            {
+                Response::ok("{\"ok\":true}")
+            } else {
+                Response::ok("{}").status("401 Unauthorized")
+            }
+        })
+        .await;
+
+        let keys = ApiKeyPool::new(["azure-key"]);
+        let url = format!("{base_url}/v7.0/search?q=rust");
+        let body = fetch_api(
+            &reqwest::Client::new(),
+            &url,
+            SearchEngineType::BingApi,
+            &keys,
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, "{\"ok\":true}");
+
+        let err = fetch_api(
+            &reqwest::Client::new(),
+            &url,
+            SearchEngineType::BingApi,
+            &ApiKeyPool::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("search.bing_api_key"));
+    }
 }
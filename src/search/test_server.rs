@@ -0,0 +1,122 @@
+//! Canned-response HTTP server for the search engine and provider tests
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// A request received by [`serve`]
+pub(crate) struct Request {
+    /// Path and query string, e.g. `/search?q=rust`
+    pub path: String,
+    head: String,
+}
+
+impl Request {
+    /// Value of header `name`, matched case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().skip(1).find_map(|line| {
+            let (header, value) = line.split_once(':')?;
+            header.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+}
+
+/// A response sent by [`serve`]: `200 OK` unless another status is set, with a
+/// `Content-Type` guessed from the body
+pub(crate) struct Response {
+    status: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+    delay: Duration,
+}
+
+impl Response {
+    pub fn ok(body: impl Into<String>) -> Self {
+        let body = body.into();
+        let content_type = match body.trim_start().chars().next() {
+            Some('{' | '[') => "application/json",
+            Some('<') => "text/html",
+            _ => "text/plain",
+        };
+        Self {
+            status: "200 OK",
+            headers: vec![("Content-Type", content_type.to_string())],
+            body,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Answer with `status`, e.g. `429 Too Many Requests`
+    pub fn status(mut self, status: &'static str) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    /// Wait this long before answering
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+impl From<String> for Response {
+    fn from(body: String) -> Self {
+        Self::ok(body)
+    }
+}
+
+impl From<&str> for Response {
+    fn from(body: &str) -> Self {
+        Self::ok(body)
+    }
+}
+
+/// Serve `respond`'s response to every request, each connection on its own task,
+/// and return the server's base URL, e.g. `http://127.0.0.1:1234`
+pub(crate) async fn serve<F, R>(respond: F) -> String
+where
+    F: Fn(&Request) -> R + Send + Sync + 'static,
+    R: Into<Response>,
+{
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let respond = Arc::new(respond);
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let respond = Arc::clone(&respond);
+            tokio::spawn(async move {
+                let mut head = Vec::new();
+                let mut buf = [0u8; 4096];
+                while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(read) => head.extend_from_slice(&buf[..read]),
+                    }
+                }
+                let head = String::from_utf8_lossy(&head).into_owned();
+                let path = head.split_whitespace().nth(1).unwrap_or("/").to_string();
+                let response: Response = respond(&Request { path, head }).into();
+
+                tokio::time::sleep(response.delay).await;
+                let headers: String = response
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .collect();
+                let raw = format!(
+                    "HTTP/1.1 {}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.status,
+                    response.body.len(),
+                    response.body
+                );
+                let _ = socket.write_all(raw.as_bytes()).await;
+            });
+        }
+    });
+    format!("http://{addr}")
+}
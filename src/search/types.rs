@@ -1,8 +1,13 @@
 use crate::constants::{
-    BAIDU_QUERY_PATTERN, BING_QUERY_PATTERN, BRAVE_QUERY_PATTERN, DUCKDUCKGO_QUERY_PATTERN,
-    GOOGLE_QUERY_PATTERN, SEARCH_ENGINE_BAIDU, SEARCH_ENGINE_BING, SEARCH_ENGINE_BRAVE,
-    SEARCH_ENGINE_DUCKDUCKGO, SEARCH_ENGINE_GOOGLE, SEARCH_ENGINE_SOUGOU_WEIXIN,
-    SOUGOU_WEIXIN_QUERY_PATTERN,
+    AUTOSWITCH_NONE, AUTOSWITCH_ON_EMPTY, AUTOSWITCH_ON_ERROR, AUTOSWITCH_SMART,
+    BAIDU_QUERY_PATTERN, BING_API_QUERY_PATTERN, BING_QUERY_PATTERN, BRAVE_QUERY_PATTERN,
+    DEFAULT_SEARCH_LIMIT, DUCKDUCKGO_QUERY_PATTERN, GOOGLE_CSE_QUERY_PATTERN, GOOGLE_QUERY_PATTERN,
+    SAFE_SEARCH_MODERATE, SAFE_SEARCH_OFF, SAFE_SEARCH_STRICT, SEARCH_ENGINE_BAIDU,
+    SEARCH_ENGINE_BING, SEARCH_ENGINE_BING_API, SEARCH_ENGINE_BRAVE, SEARCH_ENGINE_DUCKDUCKGO,
+    SEARCH_ENGINE_GOOGLE, SEARCH_ENGINE_GOOGLE_CSE, SEARCH_ENGINE_SEARXNG, SEARCH_ENGINE_SERPAPI,
+    SEARCH_ENGINE_SOUGOU_WEIXIN, SEARCH_ENGINE_YANDEX, SEARCH_RESULTS_SCHEMA_VERSION,
+    SEARXNG_QUERY_PATTERN, SERPAPI_QUERY_PATTERN, SOUGOU_WEIXIN_QUERY_PATTERN,
+    YANDEX_QUERY_PATTERN,
 };
 use crate::error::TarziError;
 use serde::{Deserialize, Serialize};
@@ -16,6 +21,17 @@ pub enum SearchEngineType {
     BraveSearch,
     Baidu,
     SougouWeixin,
+    Yandex,
+    /// SerpApi JSON API, querying the engine set by `search.serpapi_engine`
+    SerpApi,
+    /// Bing Web Search JSON API, authenticated with `search.bing_api_key`
+    BingApi,
+    /// Google Custom Search JSON API, querying the Programmable Search Engine
+    /// `search.google_cse_cx` with `search.google_cse_key`
+    GoogleCse,
+    /// JSON API of a self-hosted SearxNG meta-search instance at
+    /// `search.searxng_base_url`; no key needed
+    SearxNG,
 }
 
 impl FromStr for SearchEngineType {
@@ -24,17 +40,42 @@ impl FromStr for SearchEngineType {
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             SEARCH_ENGINE_BING => Ok(SearchEngineType::Bing),
-            SEARCH_ENGINE_DUCKDUCKGO => Ok(SearchEngineType::DuckDuckGo),
+            SEARCH_ENGINE_DUCKDUCKGO | "ddg" => Ok(SearchEngineType::DuckDuckGo),
             SEARCH_ENGINE_GOOGLE => Ok(SearchEngineType::Google),
             SEARCH_ENGINE_BRAVE => Ok(SearchEngineType::BraveSearch),
             SEARCH_ENGINE_BAIDU => Ok(SearchEngineType::Baidu),
             SEARCH_ENGINE_SOUGOU_WEIXIN => Ok(SearchEngineType::SougouWeixin),
-            _ => Err(TarziError::InvalidEngine(s.to_string())),
+            SEARCH_ENGINE_YANDEX => Ok(SearchEngineType::Yandex),
+            SEARCH_ENGINE_SERPAPI => Ok(SearchEngineType::SerpApi),
+            SEARCH_ENGINE_BING_API => Ok(SearchEngineType::BingApi),
+            SEARCH_ENGINE_GOOGLE_CSE => Ok(SearchEngineType::GoogleCse),
+            SEARCH_ENGINE_SEARXNG => Ok(SearchEngineType::SearxNG),
+            _ => Err(TarziError::InvalidEngine(format!(
+                "'{s}' (expected one of: {SEARCH_ENGINE_BING}, {SEARCH_ENGINE_DUCKDUCKGO}, \
+                 {SEARCH_ENGINE_GOOGLE}, {SEARCH_ENGINE_BRAVE}, {SEARCH_ENGINE_BAIDU}, \
+                 {SEARCH_ENGINE_SOUGOU_WEIXIN}, {SEARCH_ENGINE_YANDEX}, {SEARCH_ENGINE_SERPAPI}, \
+                 {SEARCH_ENGINE_BING_API}, {SEARCH_ENGINE_GOOGLE_CSE}, {SEARCH_ENGINE_SEARXNG})"
+            ))),
         }
     }
 }
 
 impl SearchEngineType {
+    /// Every supported engine, in the order they are listed to users
+    pub const ALL: [SearchEngineType; 11] = [
+        SearchEngineType::Bing,
+        SearchEngineType::DuckDuckGo,
+        SearchEngineType::Google,
+        SearchEngineType::BraveSearch,
+        SearchEngineType::Baidu,
+        SearchEngineType::SougouWeixin,
+        SearchEngineType::Yandex,
+        SearchEngineType::SerpApi,
+        SearchEngineType::BingApi,
+        SearchEngineType::GoogleCse,
+        SearchEngineType::SearxNG,
+    ];
+
     pub fn get_query_pattern(&self) -> String {
         match self {
             SearchEngineType::Bing => BING_QUERY_PATTERN.to_string(),
@@ -43,7 +84,520 @@ impl SearchEngineType {
             SearchEngineType::BraveSearch => BRAVE_QUERY_PATTERN.to_string(),
             SearchEngineType::Baidu => BAIDU_QUERY_PATTERN.to_string(),
             SearchEngineType::SougouWeixin => SOUGOU_WEIXIN_QUERY_PATTERN.to_string(),
+            SearchEngineType::Yandex => YANDEX_QUERY_PATTERN.to_string(),
+            SearchEngineType::SerpApi => SERPAPI_QUERY_PATTERN.to_string(),
+            SearchEngineType::BingApi => BING_API_QUERY_PATTERN.to_string(),
+            SearchEngineType::GoogleCse => GOOGLE_CSE_QUERY_PATTERN.to_string(),
+            SearchEngineType::SearxNG => SEARXNG_QUERY_PATTERN.to_string(),
+        }
+    }
+
+    /// Name of the engine as used in config files
+    pub fn name(&self) -> &'static str {
+        match self {
+            SearchEngineType::Bing => SEARCH_ENGINE_BING,
+            SearchEngineType::DuckDuckGo => SEARCH_ENGINE_DUCKDUCKGO,
+            SearchEngineType::Google => SEARCH_ENGINE_GOOGLE,
+            SearchEngineType::BraveSearch => SEARCH_ENGINE_BRAVE,
+            SearchEngineType::Baidu => SEARCH_ENGINE_BAIDU,
+            SearchEngineType::SougouWeixin => SEARCH_ENGINE_SOUGOU_WEIXIN,
+            SearchEngineType::Yandex => SEARCH_ENGINE_YANDEX,
+            SearchEngineType::SerpApi => SEARCH_ENGINE_SERPAPI,
+            SearchEngineType::BingApi => SEARCH_ENGINE_BING_API,
+            SearchEngineType::GoogleCse => SEARCH_ENGINE_GOOGLE_CSE,
+            SearchEngineType::SearxNG => SEARCH_ENGINE_SEARXNG,
+        }
+    }
+
+    /// Query pattern with the default scheme and domain replaced by `base_url`,
+    /// e.g. `https://www.google.de` or a self-hosted mirror. A base URL that already
+    /// contains `{query}` is used as the full pattern.
+    pub fn query_pattern_with_base(&self, base_url: &str) -> String {
+        if base_url.contains("{query}") {
+            return base_url.to_string();
         }
+        let pattern = self.get_query_pattern();
+        let path_start = pattern
+            .find("://")
+            .and_then(|scheme_end| {
+                pattern[scheme_end + 3..]
+                    .find('/')
+                    .map(|i| scheme_end + 3 + i)
+            })
+            .unwrap_or(pattern.len());
+        format!(
+            "{}{}",
+            base_url.trim_end_matches('/'),
+            &pattern[path_start..]
+        )
+    }
+
+    /// Maximum number of results the engine returns on one results page
+    pub fn max_results_per_page(&self) -> usize {
+        match self {
+            SearchEngineType::BraveSearch => 20,
+            SearchEngineType::Bing
+            | SearchEngineType::DuckDuckGo
+            | SearchEngineType::Google
+            | SearchEngineType::Baidu
+            | SearchEngineType::SougouWeixin
+            | SearchEngineType::Yandex
+            | SearchEngineType::SerpApi
+            | SearchEngineType::BingApi
+            | SearchEngineType::GoogleCse
+            | SearchEngineType::SearxNG => 10,
+        }
+    }
+
+    /// URL parameter (name, value) selecting the results page that starts at the
+    /// zero-based result index `start`, or `None` if the engine cannot paginate by URL
+    pub fn page_param(&self, start: usize) -> Option<(&'static str, String)> {
+        let page_size = self.max_results_per_page();
+        match self {
+            SearchEngineType::Google | SearchEngineType::SerpApi => {
+                Some(("start", start.to_string()))
+            }
+            SearchEngineType::Bing => Some(("first", (start + 1).to_string())),
+            SearchEngineType::BingApi => Some(("offset", start.to_string())),
+            SearchEngineType::GoogleCse => Some(("start", (start + 1).to_string())),
+            SearchEngineType::SearxNG => Some(("pageno", (start / page_size + 1).to_string())),
+            SearchEngineType::Baidu => Some(("pn", start.to_string())),
+            SearchEngineType::BraveSearch => Some(("offset", (start / page_size).to_string())),
+            SearchEngineType::SougouWeixin => Some(("page", (start / page_size + 1).to_string())),
+            SearchEngineType::Yandex => Some(("p", (start / page_size).to_string())),
+            SearchEngineType::DuckDuckGo => None,
+        }
+    }
+
+    /// Whether results come from a JSON API rather than a scraped results page
+    pub fn is_api(&self) -> bool {
+        matches!(
+            self,
+            SearchEngineType::SerpApi
+                | SearchEngineType::BingApi
+                | SearchEngineType::GoogleCse
+                | SearchEngineType::SearxNG
+        )
+    }
+
+    /// Whether searching needs an API key
+    pub fn requires_api_key(&self) -> bool {
+        self.is_api() && *self != SearchEngineType::SearxNG
+    }
+}
+
+/// Capabilities of a search engine and whether it is ready to use, as reported
+/// by [`crate::search::SearchEngine::available_engines`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineInfo {
+    pub engine_type: SearchEngineType,
+    /// Results are scraped from the engine's web results page
+    pub supports_web: bool,
+    /// Results come from a JSON API
+    pub supports_api: bool,
+    pub requires_api_key: bool,
+    /// An API key is configured; always true for engines without one
+    pub has_key_configured: bool,
+}
+
+impl SearchEngineType {
+    /// URL parameter used by the engine to select the interface language, if any
+    pub fn language_param(&self) -> Option<&'static str> {
+        match self {
+            SearchEngineType::Bing => Some("setlang"),
+            SearchEngineType::Google => Some("hl"),
+            SearchEngineType::DuckDuckGo => Some("kl"),
+            SearchEngineType::SerpApi => Some("hl"),
+            SearchEngineType::Yandex => Some("lang"),
+            SearchEngineType::BingApi => Some("setLang"),
+            SearchEngineType::GoogleCse => Some("hl"),
+            SearchEngineType::SearxNG => Some("language"),
+            SearchEngineType::BraveSearch
+            | SearchEngineType::Baidu
+            | SearchEngineType::SougouWeixin => None,
+        }
+    }
+
+    /// URL parameters localizing results for a country (ISO 3166-1, e.g. "de") and
+    /// language (ISO 639-1, e.g. "de"). Engines without a region parameter only get
+    /// their [`Self::language_param`]; either value may be missing.
+    pub fn region_params(
+        &self,
+        country: Option<&str>,
+        language: Option<&str>,
+    ) -> Vec<(&'static str, String)> {
+        let country = country.map(str::trim).filter(|c| !c.is_empty());
+        let language = language.map(str::trim).filter(|l| !l.is_empty());
+        let mut params = Vec::new();
+        match (self, country, language) {
+            (
+                SearchEngineType::Google | SearchEngineType::SerpApi | SearchEngineType::GoogleCse,
+                ..,
+            ) => {
+                if let Some(country) = country {
+                    params.push(("gl", country.to_lowercase()));
+                }
+                if let Some(language) = language {
+                    params.push(("hl", language.to_lowercase()));
+                }
+            }
+            // Bing markets combine both, e.g. "de-DE"
+            (SearchEngineType::Bing | SearchEngineType::BingApi, Some(country), Some(language)) => {
+                params.push((
+                    "mkt",
+                    format!("{}-{}", language.to_lowercase(), country.to_uppercase()),
+                ));
+            }
+            (SearchEngineType::Bing | SearchEngineType::BingApi, Some(country), None) => {
+                params.push(("cc", country.to_uppercase()));
+            }
+            // DuckDuckGo regions are "<country>-<language>", e.g. "de-de"
+            (SearchEngineType::DuckDuckGo, Some(country), Some(language)) => {
+                params.push((
+                    "kl",
+                    format!("{}-{}", country.to_lowercase(), language.to_lowercase()),
+                ));
+            }
+            (SearchEngineType::SearxNG, Some(country), Some(language)) => {
+                params.push((
+                    "language",
+                    format!("{}-{}", language.to_lowercase(), country.to_uppercase()),
+                ));
+            }
+            (_, _, Some(language)) => {
+                if let Some(param) = self.language_param() {
+                    params.push((param, language.to_string()));
+                }
+            }
+            _ => {}
+        }
+        params
+    }
+
+    /// URL parameter (name, value) used by the engine to restrict results to a time range
+    pub fn time_range_param(&self, range: TimeRange) -> Option<(&'static str, &'static str)> {
+        match (self, range) {
+            (SearchEngineType::Google, TimeRange::Day) => Some(("tbs", "qdr:d")),
+            (SearchEngineType::Google, TimeRange::Week) => Some(("tbs", "qdr:w")),
+            (SearchEngineType::Google, TimeRange::Month) => Some(("tbs", "qdr:m")),
+            (SearchEngineType::Google, TimeRange::Year) => Some(("tbs", "qdr:y")),
+            (SearchEngineType::DuckDuckGo, TimeRange::Day) => Some(("df", "d")),
+            (SearchEngineType::DuckDuckGo, TimeRange::Week) => Some(("df", "w")),
+            (SearchEngineType::DuckDuckGo, TimeRange::Month) => Some(("df", "m")),
+            (SearchEngineType::DuckDuckGo, TimeRange::Year) => Some(("df", "y")),
+            (SearchEngineType::BraveSearch, TimeRange::Day) => Some(("tf", "pd")),
+            (SearchEngineType::BraveSearch, TimeRange::Week) => Some(("tf", "pw")),
+            (SearchEngineType::BraveSearch, TimeRange::Month) => Some(("tf", "pm")),
+            (SearchEngineType::BraveSearch, TimeRange::Year) => Some(("tf", "py")),
+            (SearchEngineType::BingApi, TimeRange::Day) => Some(("freshness", "Day")),
+            (SearchEngineType::BingApi, TimeRange::Week) => Some(("freshness", "Week")),
+            (SearchEngineType::BingApi, TimeRange::Month) => Some(("freshness", "Month")),
+            (SearchEngineType::GoogleCse, TimeRange::Day) => Some(("dateRestrict", "d1")),
+            (SearchEngineType::GoogleCse, TimeRange::Week) => Some(("dateRestrict", "w1")),
+            (SearchEngineType::GoogleCse, TimeRange::Month) => Some(("dateRestrict", "m1")),
+            (SearchEngineType::GoogleCse, TimeRange::Year) => Some(("dateRestrict", "y1")),
+            (SearchEngineType::SearxNG, TimeRange::Day) => Some(("time_range", "day")),
+            (SearchEngineType::SearxNG, TimeRange::Week) => Some(("time_range", "week")),
+            (SearchEngineType::SearxNG, TimeRange::Month) => Some(("time_range", "month")),
+            (SearchEngineType::SearxNG, TimeRange::Year) => Some(("time_range", "year")),
+            _ => None,
+        }
+    }
+
+    /// URL parameter (name, value) used by the engine to filter explicit results at
+    /// the given level, or `None` if the engine has no such parameter. Moderate
+    /// filtering is the engines' default, so no parameter is needed for it.
+    pub fn safe_search_param(&self, level: SafeSearch) -> Option<(&'static str, &'static str)> {
+        match (self, level) {
+            (_, SafeSearch::Moderate) => None,
+            (SearchEngineType::Google, SafeSearch::Off) => Some(("safe", "off")),
+            (SearchEngineType::Google, SafeSearch::Strict) => Some(("safe", "active")),
+            (SearchEngineType::Bing, SafeSearch::Off) => Some(("adlt", "off")),
+            (SearchEngineType::Bing, SafeSearch::Strict) => Some(("adlt", "strict")),
+            (SearchEngineType::DuckDuckGo, SafeSearch::Off) => Some(("kp", "-2")),
+            (SearchEngineType::DuckDuckGo, SafeSearch::Strict) => Some(("kp", "1")),
+            (SearchEngineType::BraveSearch, SafeSearch::Off) => Some(("safesearch", "off")),
+            (SearchEngineType::BraveSearch, SafeSearch::Strict) => Some(("safesearch", "strict")),
+            (SearchEngineType::SerpApi | SearchEngineType::GoogleCse, SafeSearch::Off) => {
+                Some(("safe", "off"))
+            }
+            (SearchEngineType::SerpApi | SearchEngineType::GoogleCse, SafeSearch::Strict) => {
+                Some(("safe", "active"))
+            }
+            (SearchEngineType::BingApi, SafeSearch::Off) => Some(("safeSearch", "Off")),
+            (SearchEngineType::BingApi, SafeSearch::Strict) => Some(("safeSearch", "Strict")),
+            (SearchEngineType::SearxNG, SafeSearch::Off) => Some(("safesearch", "0")),
+            (SearchEngineType::SearxNG, SafeSearch::Strict) => Some(("safesearch", "2")),
+            (
+                SearchEngineType::Baidu | SearchEngineType::SougouWeixin | SearchEngineType::Yandex,
+                _,
+            ) => None,
+        }
+    }
+
+    /// Query text restricted to results from `site` (a domain, optionally with a
+    /// path), or `None` if the engine has no site operator. Every supported engine
+    /// (and the `q` parameter of SerpApi, the Bing API, Google CSE and SearxNG)
+    /// understands `site:`.
+    pub fn site_query(&self, query: &str, site: &str) -> Option<String> {
+        let site = site
+            .trim()
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/');
+        match self {
+            SearchEngineType::Bing
+            | SearchEngineType::Google
+            | SearchEngineType::DuckDuckGo
+            | SearchEngineType::BraveSearch
+            | SearchEngineType::Baidu
+            | SearchEngineType::Yandex
+            | SearchEngineType::SerpApi
+            | SearchEngineType::BingApi
+            | SearchEngineType::GoogleCse
+            | SearchEngineType::SearxNG => Some(format!("{query} site:{site}")),
+            SearchEngineType::SougouWeixin => None,
+        }
+    }
+}
+
+/// Domain allow/deny lists applied to search results, matching the result URL's
+/// host and its subdomains (`example.com` also matches `docs.example.com`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DomainFilter {
+    /// When non-empty, only results from these domains are kept
+    pub include: Vec<String>,
+    /// Results from these domains are dropped
+    pub exclude: Vec<String>,
+}
+
+impl DomainFilter {
+    pub fn new<I, E>(include: I, exclude: E) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        E: IntoIterator,
+        E::Item: AsRef<str>,
+    {
+        Self {
+            include: normalize_domains(include),
+            exclude: normalize_domains(exclude),
+        }
+    }
+
+    /// Whether the filter lets every result through
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether a result with this URL passes the filter. URLs without a host only
+    /// pass when there is no allow list.
+    pub fn allows(&self, url: &str) -> bool {
+        let host = url::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_lowercase));
+        let Some(host) = host else {
+            return self.include.is_empty();
+        };
+        let matches = |domain: &String| {
+            host == *domain
+                || host
+                    .strip_suffix(domain.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        };
+        if self.exclude.iter().any(matches) {
+            return false;
+        }
+        self.include.is_empty() || self.include.iter().any(matches)
+    }
+}
+
+/// Lowercase configured domains, dropping wildcard prefixes, dots and empty entries
+fn normalize_domains<I>(domains: I) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    domains
+        .into_iter()
+        .map(|domain| {
+            domain
+                .as_ref()
+                .trim()
+                .trim_start_matches("*.")
+                .trim_matches('.')
+                .to_lowercase()
+        })
+        .filter(|domain| !domain.is_empty())
+        .collect()
+}
+
+/// Time range used to restrict search results to recent pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeRange {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl FromStr for TimeRange {
+    type Err = TarziError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "day" | "d" => Ok(TimeRange::Day),
+            "week" | "w" => Ok(TimeRange::Week),
+            "month" | "m" => Ok(TimeRange::Month),
+            "year" | "y" => Ok(TimeRange::Year),
+            _ => Err(TarziError::Config(format!("Invalid time range: {s}"))),
+        }
+    }
+}
+
+/// When a search falls back to the next of `search.fallback_engines`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoSwitchStrategy {
+    /// Only use the configured engine
+    #[default]
+    None,
+    /// Fall back when an engine fails or returns no results
+    Smart,
+    /// Fall back only when an engine fails
+    OnError,
+    /// Fall back only when an engine returns no results
+    OnEmpty,
+}
+
+impl AutoSwitchStrategy {
+    pub fn switches_on_error(&self) -> bool {
+        matches!(
+            self,
+            AutoSwitchStrategy::Smart | AutoSwitchStrategy::OnError
+        )
+    }
+
+    pub fn switches_on_empty(&self) -> bool {
+        matches!(
+            self,
+            AutoSwitchStrategy::Smart | AutoSwitchStrategy::OnEmpty
+        )
+    }
+}
+
+impl FromStr for AutoSwitchStrategy {
+    type Err = TarziError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            AUTOSWITCH_NONE => Ok(AutoSwitchStrategy::None),
+            AUTOSWITCH_SMART => Ok(AutoSwitchStrategy::Smart),
+            AUTOSWITCH_ON_ERROR => Ok(AutoSwitchStrategy::OnError),
+            AUTOSWITCH_ON_EMPTY => Ok(AutoSwitchStrategy::OnEmpty),
+            _ => Err(TarziError::Config(format!(
+                "Invalid autoswitch strategy '{s}' (expected one of: {AUTOSWITCH_NONE}, \
+                 {AUTOSWITCH_SMART}, {AUTOSWITCH_ON_ERROR}, {AUTOSWITCH_ON_EMPTY})"
+            ))),
+        }
+    }
+}
+
+/// How strictly explicit results are filtered (`search.safe_search`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SafeSearch {
+    Off,
+    /// The engines' own default filtering
+    #[default]
+    Moderate,
+    Strict,
+}
+
+impl SafeSearch {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SafeSearch::Off => SAFE_SEARCH_OFF,
+            SafeSearch::Moderate => SAFE_SEARCH_MODERATE,
+            SafeSearch::Strict => SAFE_SEARCH_STRICT,
+        }
+    }
+}
+
+impl FromStr for SafeSearch {
+    type Err = TarziError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            SAFE_SEARCH_OFF => Ok(SafeSearch::Off),
+            SAFE_SEARCH_MODERATE => Ok(SafeSearch::Moderate),
+            SAFE_SEARCH_STRICT => Ok(SafeSearch::Strict),
+            _ => Err(TarziError::Config(format!(
+                "Invalid safe search level '{s}' (expected one of: {SAFE_SEARCH_OFF}, \
+                 {SAFE_SEARCH_MODERATE}, {SAFE_SEARCH_STRICT})"
+            ))),
+        }
+    }
+}
+
+/// A search request with optional parameters
+///
+/// Built with [`SearchQuery::new`] and the chained setters, then passed to
+/// [`crate::search::SearchEngine::search_query`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    pub query: String,
+    pub limit: usize,
+    /// Number of leading results to skip
+    pub offset: usize,
+    /// Interface language, passed through the engine's language parameter
+    pub language: Option<String>,
+    pub time_range: Option<TimeRange>,
+    /// Restrict results to this site, using the engine's site operator
+    pub site: Option<String>,
+    /// Additional raw URL parameters appended to the search URL
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl SearchQuery {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            limit: DEFAULT_SEARCH_LIMIT,
+            offset: 0,
+            language: None,
+            time_range: None,
+            site: None,
+            extra_params: Vec::new(),
+        }
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn time_range(mut self, time_range: TimeRange) -> Self {
+        self.time_range = Some(time_range);
+        self
+    }
+
+    pub fn site(mut self, site: impl Into<String>) -> Self {
+        self.site = Some(site.into());
+        self
+    }
+
+    pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_params.push((key.into(), value.into()));
+        self
     }
 }
 
@@ -53,6 +607,110 @@ pub struct SearchResult {
     pub url: String,
     pub snippet: String,
     pub rank: usize,
+    /// Publication date shown by the engine, as an ISO-8601 date when it could
+    /// be parsed and as displayed (e.g. "3 days ago") otherwise
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
+impl SearchResult {
+    /// Registrable domain of the result, e.g. `bbc.co.uk` for
+    /// `https://www.bbc.co.uk/news`. Without a public suffix list this keeps the
+    /// last two labels, or three under common second-level suffixes such as
+    /// `co.uk` or `com.au`; IP addresses are returned as-is.
+    pub fn domain(&self) -> Option<String> {
+        let url = url::Url::parse(&self.url).ok()?;
+        let host = match url.host()? {
+            url::Host::Domain(host) => host.trim_end_matches('.').to_lowercase(),
+            ip => return Some(ip.to_string()),
+        };
+        let labels: Vec<&str> = host.split('.').collect();
+        let keep = match labels.as_slice() {
+            [.., second, tld] if tld.len() == 2 && SECOND_LEVEL_SUFFIXES.contains(second) => 3,
+            _ => 2,
+        };
+        Some(labels[labels.len().saturating_sub(keep)..].join("."))
+    }
+
+    /// Cut the snippet after `max_len` characters and append "…"; a snippet
+    /// that fits, or a `max_len` of 0, is left as is
+    pub fn truncate_snippet(&mut self, max_len: usize) {
+        if max_len == 0 {
+            return;
+        }
+        // Byte index of the first character past the limit, always a char boundary
+        if let Some((end, _)) = self.snippet.char_indices().nth(max_len) {
+            self.snippet.truncate(end);
+            self.snippet.push('…');
+        }
+    }
+
+    /// Conventional favicon location of the result's site, `/favicon.ico` on
+    /// the same scheme and host
+    pub fn favicon_url(&self) -> Option<String> {
+        let url = url::Url::parse(&self.url).ok()?;
+        url.host_str()?;
+        Some(format!(
+            "{}/favicon.ico",
+            url.origin().ascii_serialization()
+        ))
+    }
+
+    /// The result URL reduced to what identifies the page, for recognizing the
+    /// same page returned by different engines: the scheme, a leading `www.`,
+    /// the fragment and a trailing slash are dropped and the host is lowercased.
+    /// URLs that don't parse are returned as-is.
+    pub fn normalized_url(&self) -> String {
+        let Ok(url) = url::Url::parse(&self.url) else {
+            return self.url.clone();
+        };
+        let Some(host) = url.host_str() else {
+            return self.url.clone();
+        };
+        let host = host.trim_start_matches("www.");
+        let port = url
+            .port()
+            .map(|port| format!(":{port}"))
+            .unwrap_or_default();
+        let path = url.path().trim_end_matches('/');
+        let query = url
+            .query()
+            .map(|query| format!("?{query}"))
+            .unwrap_or_default();
+        format!("{host}{port}{path}{query}")
+    }
+}
+
+/// Second-level labels that, under a two-letter country code, form a public suffix
+const SECOND_LEVEL_SUFFIXES: &[&str] = &["ac", "co", "com", "edu", "gov", "net", "org"];
+
+/// Versioned envelope for serialized search results
+///
+/// `schema_version` is bumped whenever a change to [`SearchResult`] would break
+/// existing consumers (renamed or removed fields, changed types). Adding new
+/// optional fields does not bump the version.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SearchResults {
+    pub schema_version: u32,
+    pub results: Vec<SearchResult>,
+}
+
+impl SearchResults {
+    pub fn new(results: Vec<SearchResult>) -> Self {
+        Self {
+            schema_version: SEARCH_RESULTS_SCHEMA_VERSION,
+            results,
+        }
+    }
+}
+
+/// Search results together with related queries suggested by the engine
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SearchResponse {
+    pub results: Vec<SearchResult>,
+    /// "Related searches" and "people also ask" queries, empty when the
+    /// engine or page does not provide them
+    pub related_queries: Vec<String>,
 }
 
 #[cfg(test)]
@@ -61,7 +719,8 @@ mod tests {
     use crate::constants::{
         BAIDU_QUERY_PATTERN, BING_QUERY_PATTERN, BRAVE_QUERY_PATTERN, DUCKDUCKGO_QUERY_PATTERN,
         GOOGLE_QUERY_PATTERN, SEARCH_ENGINE_BAIDU, SEARCH_ENGINE_BING, SEARCH_ENGINE_BRAVE,
-        SEARCH_ENGINE_DUCKDUCKGO, SEARCH_ENGINE_GOOGLE, SEARCH_ENGINE_SOUGOU_WEIXIN,
+        SEARCH_ENGINE_DUCKDUCKGO, SEARCH_ENGINE_GOOGLE, SEARCH_ENGINE_SERPAPI,
+        SEARCH_ENGINE_SOUGOU_WEIXIN, SERPAPI_QUERY_PATTERN,
     };
 
     #[test]
@@ -91,9 +750,30 @@ mod tests {
             SearchEngineType::from_str(SEARCH_ENGINE_SOUGOU_WEIXIN).unwrap(),
             SearchEngineType::SougouWeixin
         );
+        assert_eq!(
+            SearchEngineType::from_str(SEARCH_ENGINE_YANDEX).unwrap(),
+            SearchEngineType::Yandex
+        );
+        assert_eq!(
+            SearchEngineType::from_str(SEARCH_ENGINE_BING_API).unwrap(),
+            SearchEngineType::BingApi
+        );
+        assert!(SearchEngineType::BingApi.is_api());
+        assert_eq!(
+            SearchEngineType::from_str(SEARCH_ENGINE_SERPAPI).unwrap(),
+            SearchEngineType::SerpApi
+        );
 
         // Test invalid engine types
-        assert!(SearchEngineType::from_str("invalid").is_err());
+        assert_eq!(
+            SearchEngineType::from_str("DDG").unwrap(),
+            SearchEngineType::DuckDuckGo
+        );
+        let err = SearchEngineType::from_str("invalid").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected one of: bing, duckduckgo")
+        );
         assert!(SearchEngineType::from_str("").is_err());
         assert!(SearchEngineType::from_str("web").is_err());
         assert!(SearchEngineType::from_str("api").is_err());
@@ -158,6 +838,232 @@ mod tests {
             SearchEngineType::SougouWeixin.get_query_pattern(),
             SOUGOU_WEIXIN_QUERY_PATTERN
         );
+        assert_eq!(
+            SearchEngineType::Yandex.get_query_pattern(),
+            YANDEX_QUERY_PATTERN
+        );
+        assert_eq!(
+            SearchEngineType::SerpApi.get_query_pattern(),
+            SERPAPI_QUERY_PATTERN
+        );
+    }
+
+    #[test]
+    fn test_search_query_builder() {
+        let query = SearchQuery::new("rust async")
+            .limit(10)
+            .offset(5)
+            .language("en")
+            .time_range(TimeRange::Week)
+            .param("safe", "off");
+
+        assert_eq!(query.query, "rust async");
+        assert_eq!(query.limit, 10);
+        assert_eq!(query.offset, 5);
+        assert_eq!(query.language, Some("en".to_string()));
+        assert_eq!(query.time_range, Some(TimeRange::Week));
+        assert_eq!(
+            query.extra_params,
+            vec![("safe".to_string(), "off".to_string())]
+        );
+
+        let query = SearchQuery::new("defaults");
+        assert_eq!(query.limit, crate::constants::DEFAULT_SEARCH_LIMIT);
+        assert_eq!(query.offset, 0);
+        assert!(query.language.is_none());
+        assert!(query.time_range.is_none());
+    }
+
+    #[test]
+    fn test_query_pattern_with_base() {
+        assert_eq!(
+            SearchEngineType::Google.query_pattern_with_base("https://www.google.de/"),
+            "https://www.google.de/search?q={query}"
+        );
+        assert_eq!(
+            SearchEngineType::DuckDuckGo.query_pattern_with_base("http://ddg.internal:8080"),
+            "http://ddg.internal:8080/?q={query}"
+        );
+        assert_eq!(
+            SearchEngineType::Bing.query_pattern_with_base("https://mirror.example/find?q={query}"),
+            "https://mirror.example/find?q={query}"
+        );
+        assert_eq!(SearchEngineType::BraveSearch.name(), SEARCH_ENGINE_BRAVE);
+    }
+
+    #[test]
+    fn test_domain_filter() {
+        let filter = DomainFilter::new(Vec::<String>::new(), ["Pinterest.com", "*.quora.com"]);
+        assert!(filter.allows("https://example.com/a"));
+        assert!(!filter.allows("https://www.pinterest.com/pin/1"));
+        assert!(!filter.allows("https://quora.com/q"));
+        assert!(filter.allows("https://notpinterest.com/"));
+
+        let filter = DomainFilter::new(["rust-lang.org"], ["blog.rust-lang.org"]);
+        assert!(filter.allows("https://doc.rust-lang.org/std/"));
+        assert!(filter.allows("https://rust-lang.org/"));
+        assert!(!filter.allows("https://blog.rust-lang.org/2024/"));
+        assert!(!filter.allows("https://example.com/"));
+        assert!(!filter.allows("not a url"));
+
+        assert!(DomainFilter::default().is_empty());
+        assert!(DomainFilter::default().allows("not a url"));
+    }
+
+    #[test]
+    fn test_search_result_domain_and_favicon() {
+        let result = |url: &str| SearchResult {
+            title: String::new(),
+            url: url.to_string(),
+            snippet: String::new(),
+            rank: 1,
+            date: None,
+        };
+
+        let news = result("https://www.bbc.co.uk/news?id=1");
+        assert_eq!(news.domain().as_deref(), Some("bbc.co.uk"));
+        assert_eq!(
+            news.favicon_url().as_deref(),
+            Some("https://www.bbc.co.uk/favicon.ico")
+        );
+        assert_eq!(
+            result("https://docs.rust-lang.org/book/")
+                .domain()
+                .as_deref(),
+            Some("rust-lang.org")
+        );
+        assert_eq!(
+            result("http://localhost:8080/").favicon_url().as_deref(),
+            Some("http://localhost:8080/favicon.ico")
+        );
+        assert_eq!(
+            result("http://127.0.0.1/").domain().as_deref(),
+            Some("127.0.0.1")
+        );
+        assert_eq!(result("not a url").domain(), None);
+        assert_eq!(result("not a url").favicon_url(), None);
+    }
+
+    #[test]
+    fn test_search_result_normalized_url() {
+        let normalized = |url: &str| {
+            SearchResult {
+                title: String::new(),
+                url: url.to_string(),
+                snippet: String::new(),
+                rank: 1,
+                date: None,
+            }
+            .normalized_url()
+        };
+
+        assert_eq!(
+            normalized("https://www.Rust-Lang.org/learn/"),
+            "rust-lang.org/learn"
+        );
+        assert_eq!(
+            normalized("http://rust-lang.org/learn#book"),
+            normalized("https://www.rust-lang.org/learn/")
+        );
+        assert_eq!(normalized("https://example.com/?q=1"), "example.com?q=1");
+        assert_ne!(
+            normalized("https://example.com:8080/"),
+            normalized("https://example.com/")
+        );
+        assert_eq!(normalized("not a url"), "not a url");
+    }
+
+    #[test]
+    fn test_truncate_snippet() {
+        let mut result = SearchResult {
+            title: String::new(),
+            url: String::new(),
+            snippet: "Größenänderung über Straßen 日本語のテキスト".to_string(),
+            rank: 1,
+            date: None,
+        };
+
+        let mut whole = result.clone();
+        whole.truncate_snippet(0);
+        assert_eq!(whole.snippet, result.snippet);
+        whole.truncate_snippet(100);
+        assert_eq!(whole.snippet, result.snippet);
+
+        // Cuts land inside runs of multibyte characters without splitting them
+        result.truncate_snippet(33);
+        assert_eq!(result.snippet, "Größenänderung über Straßen 日本語のテ…");
+        assert_eq!(result.snippet.chars().count(), 34);
+
+        result.truncate_snippet(2);
+        assert_eq!(result.snippet, "Gr…");
+    }
+
+    #[test]
+    fn test_page_params() {
+        assert_eq!(SearchEngineType::Google.max_results_per_page(), 10);
+        assert_eq!(SearchEngineType::BraveSearch.max_results_per_page(), 20);
+
+        assert_eq!(
+            SearchEngineType::Google.page_param(10),
+            Some(("start", "10".to_string()))
+        );
+        assert_eq!(
+            SearchEngineType::Bing.page_param(10),
+            Some(("first", "11".to_string()))
+        );
+        assert_eq!(
+            SearchEngineType::BraveSearch.page_param(40),
+            Some(("offset", "2".to_string()))
+        );
+        assert_eq!(
+            SearchEngineType::SougouWeixin.page_param(0),
+            Some(("page", "1".to_string()))
+        );
+        assert_eq!(
+            SearchEngineType::Yandex.page_param(20),
+            Some(("p", "2".to_string()))
+        );
+        assert_eq!(SearchEngineType::DuckDuckGo.page_param(10), None);
+    }
+
+    #[test]
+    fn test_time_range_params() {
+        assert_eq!(TimeRange::from_str("week").unwrap(), TimeRange::Week);
+        assert_eq!(TimeRange::from_str("D").unwrap(), TimeRange::Day);
+        assert!(TimeRange::from_str("decade").is_err());
+
+        assert_eq!(
+            SearchEngineType::Google.time_range_param(TimeRange::Month),
+            Some(("tbs", "qdr:m"))
+        );
+        assert_eq!(
+            SearchEngineType::Baidu.time_range_param(TimeRange::Day),
+            None
+        );
+        assert_eq!(SearchEngineType::Google.language_param(), Some("hl"));
+        assert_eq!(SearchEngineType::Baidu.language_param(), None);
+    }
+
+    #[test]
+    fn test_search_results_envelope_roundtrip() {
+        let results = SearchResults::new(vec![SearchResult {
+            title: "Test Title".to_string(),
+            url: "https://example.com".to_string(),
+            snippet: "Test snippet".to_string(),
+            rank: 1,
+            date: None,
+        }]);
+        assert_eq!(
+            results.schema_version,
+            crate::constants::SEARCH_RESULTS_SCHEMA_VERSION
+        );
+
+        let json = serde_json::to_string(&results).unwrap();
+        assert!(json.contains(r#""date":null"#));
+        let parsed: SearchResults = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.schema_version, results.schema_version);
+        assert_eq!(parsed.results.len(), 1);
+        assert_eq!(parsed.results[0].url, "https://example.com");
     }
 
     #[test]
@@ -167,6 +1073,7 @@ mod tests {
             url: "https://example.com".to_string(),
             snippet: "Test snippet".to_string(),
             rank: 1,
+            date: None,
         };
 
         assert_eq!(result.title, "Test Title");
@@ -1,5 +1,6 @@
-use crate::constants::{CHROMEDRIVER_DEFAULT_URL, WEBDRIVER_CHECK_TIMEOUT};
+use crate::constants::{CHROMEDRIVER_DEFAULT_URL, USER_DIR_NAME, WEBDRIVER_CHECK_TIMEOUT};
 use reqwest;
+use std::path::{Path, PathBuf};
 use tokio::time::timeout;
 
 /// Check if WebDriver server is available at the default endpoint
@@ -17,3 +18,141 @@ pub async fn is_webdriver_available() -> bool {
         _ => false,
     }
 }
+
+/// The user's cache directory for tarzi: `$XDG_CACHE_HOME/tarzi`, else
+/// `~/.cache/tarzi`. `None` when neither variable is set; never a shared
+/// directory such as the system temp directory, where other users could plant files.
+pub fn user_cache_dir() -> Option<PathBuf> {
+    env_dir("XDG_CACHE_HOME")
+        .or_else(|| env_dir("HOME").map(|home| home.join(".cache")))
+        .map(|dir| dir.join(USER_DIR_NAME))
+}
+
+/// The user's directory for tarzi's runtime state: `$XDG_RUNTIME_DIR/tarzi`,
+/// else [`user_cache_dir`]
+pub fn user_state_dir() -> Option<PathBuf> {
+    env_dir("XDG_RUNTIME_DIR")
+        .map(|dir| dir.join(USER_DIR_NAME))
+        .or_else(user_cache_dir)
+}
+
+fn env_dir(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Create `dir` and its parents, accessible to its owner only on Unix
+pub fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
+}
+
+/// Writes a sequence of values as JSON while they are produced, instead of
+/// serializing a complete collection at once. Each value is flushed as soon as
+/// it is written, so output never has to be held in memory in full.
+///
+/// The array layout matches `serde_json::to_string_pretty` of a `Vec`; the
+/// lines layout writes one compact value per line (JSON Lines).
+pub struct JsonStreamWriter<W: std::io::Write> {
+    writer: W,
+    lines: bool,
+    count: usize,
+}
+
+impl<W: std::io::Write> JsonStreamWriter<W> {
+    /// Write values as elements of a pretty-printed JSON array
+    pub fn array(writer: W) -> Self {
+        Self {
+            writer,
+            lines: false,
+            count: 0,
+        }
+    }
+
+    /// Write values as JSON Lines
+    pub fn lines(writer: W) -> Self {
+        Self {
+            writer,
+            lines: true,
+            count: 0,
+        }
+    }
+
+    /// Number of values written so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn write<T: serde::Serialize>(&mut self, value: &T) -> crate::Result<()> {
+        if self.lines {
+            serde_json::to_writer(&mut self.writer, value)?;
+            self.writer.write_all(b"\n")?;
+        } else {
+            let separator = if self.count == 0 { "[\n" } else { ",\n" };
+            self.writer.write_all(separator.as_bytes())?;
+            // Strings escape their newlines, so every line break is structural
+            let json = serde_json::to_string_pretty(value)?;
+            for (i, line) in json.lines().enumerate() {
+                if i > 0 {
+                    self.writer.write_all(b"\n")?;
+                }
+                write!(self.writer, "  {line}")?;
+            }
+        }
+        self.count += 1;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Close the array, if any, and return the writer
+    pub fn finish(mut self) -> crate::Result<W> {
+        if !self.lines {
+            let end = if self.count == 0 { "[]" } else { "\n]" };
+            self.writer.write_all(end.as_bytes())?;
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_stream_writer() {
+        let values = vec![
+            json!({"url": "https://example.com", "content": "line one\nline two"}),
+            json!(["nested", {"a": 1}]),
+        ];
+
+        let mut writer = JsonStreamWriter::array(Vec::new());
+        for value in &values {
+            writer.write(value).unwrap();
+        }
+        assert_eq!(writer.count(), 2);
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(output, serde_json::to_string_pretty(&values).unwrap());
+
+        let empty = JsonStreamWriter::array(Vec::new()).finish().unwrap();
+        assert_eq!(empty, b"[]");
+
+        let mut writer = JsonStreamWriter::lines(Vec::new());
+        for value in &values {
+            writer.write(value).unwrap();
+        }
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+        let parsed: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, values);
+    }
+}
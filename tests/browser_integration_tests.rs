@@ -370,3 +370,35 @@ async fn test_config_merge_preserves_driver_type() {
     .await
     .expect("Test timed out after 60 seconds");
 }
+
+/// Attach to a running Chrome started with `--remote-debugging-port`, given by the
+/// `TARZI_CDP_ENDPOINT` env var (e.g. `127.0.0.1:9222`); skipped when unset.
+/// Requires chromedriver to be installed.
+#[tokio::test]
+async fn test_attach_to_external_browser() {
+    let Ok(endpoint) = std::env::var("TARZI_CDP_ENDPOINT") else {
+        println!("TARZI_CDP_ENDPOINT not set, skipping external browser test");
+        return;
+    };
+
+    tokio::time::timeout(TEST_TIMEOUT, async {
+        let mut config = create_config_for_self_managed("chromedriver");
+        config.fetcher.browser_debugger_address = Some(endpoint.clone());
+        let mut browser_manager = BrowserManager::from_config(&config);
+
+        let browser = browser_manager
+            .get_or_create_browser(true)
+            .await
+            .unwrap_or_else(|e| panic!("Failed to attach to {endpoint}: {e}"));
+        browser
+            .get("data:text/html,<title>attached</title>")
+            .await
+            .unwrap();
+        assert_eq!(browser.title().await.unwrap(), "attached");
+        assert_eq!(browser_manager.get_browser_ids(), vec!["external"]);
+
+        browser_manager.shutdown().await;
+    })
+    .await
+    .expect("Test timed out after 60 seconds");
+}
@@ -0,0 +1,310 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// Integration tests for the tarzi command line
+
+/// Run `tarzi` with `args`, piping `stdin` to it, and return its stdout
+fn run_with_stdin(args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start tarzi");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "tarzi failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_convert_reads_stdin() {
+    let html = "<h1>Piped</h1><p>Some <strong>bold</strong> text</p>";
+
+    // `--input -` reads stdin, as does omitting `--input` when stdin is piped
+    for args in [&["convert", "--input", "-"][..], &["convert"][..]] {
+        let markdown = run_with_stdin(args, html);
+        assert!(markdown.contains("Piped\n"), "{markdown}");
+        assert!(markdown.contains("Some **bold** text"), "{markdown}");
+    }
+
+    let text = run_with_stdin(&["convert", "-i", "-", "--format", "text"], html);
+    assert!(text.contains("Some bold text"), "{text}");
+}
+
+#[test]
+fn test_convert_uses_converter_config() {
+    let html = "<nav>Home | World</nav><article><h1>Storm</h1>\
+                <p>Heavy rain pushed rivers over their banks overnight.</p></article>\
+                <footer>Copyright Daily News</footer>";
+    let mut config = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+    writeln!(config, "[converter]\nreadability = true").unwrap();
+    let config_path = config.path().to_str().unwrap();
+
+    let markdown = run_with_stdin(&["--config", config_path, "convert"], html);
+    assert!(markdown.contains("Heavy rain"), "{markdown}");
+    assert!(!markdown.contains("Copyright"), "{markdown}");
+}
+
+#[test]
+fn test_convert_url_uses_plain_request_by_default() {
+    use std::io::Read;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let (mut socket, _) = listener.accept().unwrap();
+        let mut buf = [0u8; 4096];
+        let _ = socket.read(&mut buf);
+        let body = "<h1>Served</h1>";
+        let _ = write!(
+            socket,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{body}",
+            body.len()
+        );
+    });
+    // Like `fetch`, `convert` ignores the configured browser mode without a browser flag
+    let mut config = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+    writeln!(config, "[fetcher]\nmode = \"browser_headless\"").unwrap();
+    let config_path = config.path().to_str().unwrap();
+
+    let markdown = run_with_stdin(&["--config", config_path, "convert", "-i", &url], "");
+    assert!(markdown.contains("Served"), "{markdown}");
+}
+
+#[test]
+fn test_fetch_rejects_invalid_mode() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args([
+            "fetch",
+            "--url",
+            "https://example.com",
+            "--mode",
+            "teleport",
+        ])
+        .output()
+        .expect("failed to start tarzi");
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("teleport"), "{stderr}");
+    for mode in ["plain_request", "browser_head", "browser_headless"] {
+        assert!(stderr.contains(mode), "{stderr}");
+    }
+}
+
+#[test]
+fn test_fetch_unreachable_url_exits_with_network_code() {
+    // A port nothing listens on once the listener is dropped
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let url = format!("http://127.0.0.1:{port}/");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args(["fetch", "--url", &url, "--mode", "plain_request"])
+        .output()
+        .expect("failed to start tarzi");
+    assert_eq!(output.status.code(), Some(2));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error = stderr
+        .lines()
+        .find(|line| line.starts_with("Error: "))
+        .unwrap_or_else(|| panic!("no error line in {stderr}"));
+    assert!(error.contains(&url), "{error}");
+}
+
+#[test]
+fn test_search_unreachable_engine_exits_with_network_code() {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let mut config = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+    writeln!(
+        config,
+        "[search]\nquery_pattern = \"http://127.0.0.1:{port}/search?q={{query}}\""
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args(["--no-browser", "--config"])
+        .arg(config.path())
+        .args(["search", "--query", "rust"])
+        .output()
+        .expect("failed to start tarzi");
+    assert_eq!(
+        output.status.code(),
+        Some(2),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_log_format_json() {
+    use std::io::Read;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut socket, _) = listener.accept().unwrap();
+        let mut buf = [0u8; 1024];
+        let _ = socket.read(&mut buf);
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\
+                  Content-Length: 13\r\nConnection: close\r\n\r\n<h1>Logs</h1>",
+            )
+            .unwrap();
+    });
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args(["--log-format", "json", "fetch", "--url", &url])
+        .output()
+        .expect("failed to start tarzi");
+    server.join().unwrap();
+    assert!(output.status.success());
+
+    // Every log line is a JSON object with the level and fields
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let lines: Vec<&str> = stderr.lines().filter(|line| !line.is_empty()).collect();
+    assert!(!lines.is_empty(), "no log lines");
+    for line in lines {
+        let entry: serde_json::Value =
+            serde_json::from_str(line).unwrap_or_else(|e| panic!("{e}: {line}"));
+        assert!(entry["level"].is_string(), "{line}");
+        assert!(entry["fields"].is_object(), "{line}");
+    }
+}
+
+#[test]
+fn test_doctor_lists_engine_health() {
+    let mut config = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+    writeln!(config, "[search]\nbing_api_key = \"key\"").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args(["--no-browser", "--config"])
+        .arg(config.path())
+        .arg("doctor")
+        .output()
+        .expect("failed to start tarzi");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let status = |engine: &str| {
+        stdout
+            .lines()
+            .find(|line| line.split_whitespace().next() == Some(engine))
+            .and_then(|line| line.split_whitespace().last())
+            .map(str::to_string)
+    };
+    assert_eq!(status("bing_api").as_deref(), Some("ok"), "{stdout}");
+    assert_eq!(status("duckduckgo").as_deref(), Some("ok"), "{stdout}");
+    assert_eq!(
+        status("searxng").as_deref(),
+        Some("unavailable"),
+        "{stdout}"
+    );
+}
+
+/// Ctrl-C during a browser fetch shuts down the driver tarzi started instead of
+/// orphaning it. Skipped unless chromedriver or geckodriver (and its browser) is
+/// installed.
+#[test]
+#[cfg(unix)]
+fn test_sigint_shuts_down_driver() {
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    let Some(driver) = ["chromedriver", "geckodriver"]
+        .into_iter()
+        .find(|driver| which::which(driver).is_ok())
+    else {
+        println!("No driver installed - skipping test");
+        return;
+    };
+
+    // A page that never answers, so the fetch is still running when interrupted
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let (connected, on_connect) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut streams = Vec::new();
+        for stream in listener.incoming().flatten() {
+            streams.push(stream);
+            let _ = connected.send(());
+        }
+    });
+
+    let mut config = tempfile::NamedTempFile::with_suffix(".toml").unwrap();
+    writeln!(config, "[fetcher]\nweb_driver = \"{driver}\"").unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tarzi"))
+        .args(["--headless", "--config"])
+        .arg(config.path())
+        .args(["fetch", "--url", &url])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to start tarzi");
+
+    if on_connect.recv_timeout(Duration::from_secs(60)).is_err() {
+        let _ = child.kill();
+        let _ = child.wait();
+        println!("The browser never loaded the page - skipping test");
+        return;
+    }
+    let pgrep = Command::new("pgrep")
+        .args(["-P", &child.id().to_string(), "-x", driver])
+        .output()
+        .unwrap();
+    let driver_pids: Vec<String> = String::from_utf8_lossy(&pgrep.stdout)
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+
+    Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if started.elapsed() > Duration::from_secs(30) {
+            let _ = child.kill();
+            let _ = child.wait();
+            panic!("tarzi did not exit after SIGINT");
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+    assert_eq!(status.code(), Some(130));
+
+    // `kill -0` fails once the driver process is gone
+    for pid in driver_pids {
+        let alive = Command::new("kill")
+            .args(["-0", &pid])
+            .stderr(Stdio::null())
+            .status()
+            .unwrap()
+            .success();
+        assert!(!alive, "{driver} (pid {pid}) is still running");
+    }
+}
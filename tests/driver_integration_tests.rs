@@ -67,7 +67,7 @@ fn test_driver_lifecycle(
 
     let config = DriverConfig {
         driver_type: driver_type.clone(),
-        port,
+        port: Some(port),
         args,
         timeout,
         verbose: false,
@@ -82,8 +82,8 @@ fn test_driver_lifecycle(
                     driver_type, info.config.driver_type
                 ));
             }
-            if info.config.port != port {
-                return Err(format!("Expected port {}, got {}", port, info.config.port));
+            if info.port != port {
+                return Err(format!("Expected port {}, got {}", port, info.port));
             }
             if info.status != DriverStatus::Running {
                 return Err(format!("Expected status Running, got {:?}", info.status));
@@ -103,10 +103,10 @@ fn test_driver_lifecycle(
             if drivers.len() != 1 {
                 return Err(format!("Expected 1 driver in list, got {}", drivers.len()));
             }
-            if drivers[0].config.port != port {
+            if drivers[0].port != port {
                 return Err(format!(
                     "Expected listed driver port {}, got {}",
-                    port, drivers[0].config.port
+                    port, drivers[0].port
                 ));
             }
 
@@ -160,25 +160,25 @@ fn test_driver_binary_detection() {
     // Test Chrome driver detection
     let chrome_result = manager.check_driver_binary(&DriverType::Chrome);
     match &chrome_result {
-        Ok(()) => println!("ChromeDriver is available"),
+        Ok(path) => println!("ChromeDriver is available at {}", path.display()),
         Err(e) => println!("ChromeDriver not available: {e}"),
     }
 
     // Test Firefox driver detection
     let firefox_result = manager.check_driver_binary(&DriverType::Firefox);
     match &firefox_result {
-        Ok(()) => println!("GeckoDriver is available"),
+        Ok(path) => println!("GeckoDriver is available at {}", path.display()),
         Err(e) => println!("GeckoDriver not available: {e}"),
     }
 
     // At least one of the results should provide useful error messages
-    if chrome_result.is_err() {
-        let error_msg = format!("{}", chrome_result.unwrap_err());
+    if let Err(e) = chrome_result {
+        let error_msg = format!("{e}");
         assert!(error_msg.contains("chromedriver") || error_msg.contains("ChromeDriver"));
     }
 
-    if firefox_result.is_err() {
-        let error_msg = format!("{}", firefox_result.unwrap_err());
+    if let Err(e) = firefox_result {
+        let error_msg = format!("{e}");
         assert!(error_msg.contains("geckodriver") || error_msg.contains("GeckoDriver"));
     }
 }
@@ -228,7 +228,7 @@ fn test_nonexistent_driver() {
 
     let config = DriverConfig {
         driver_type: DriverType::Generic("nonexistent-driver".to_string()),
-        port,
+        port: Some(port),
         args: vec![],
         timeout: Duration::from_secs(5),
         verbose: false,
@@ -254,3 +254,68 @@ fn test_nonexistent_driver() {
     let drivers = manager.list_drivers();
     assert_eq!(drivers.len(), 0, "Expected no drivers after failed start");
 }
+
+/// Test that drivers started without a port get distinct free ports. A stand-in
+/// driver script listens on the port it is given, so no real driver is needed.
+#[test]
+#[cfg(unix)]
+fn test_concurrent_starts_get_different_ports() {
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::Arc;
+    use tarzi::fetcher::driver::DriverConfig;
+
+    if std::process::Command::new("python3")
+        .arg("--version")
+        .output()
+        .is_err()
+    {
+        println!("✓ Concurrent driver start test skipped: python3 not available");
+        return;
+    }
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("fake-driver");
+    std::fs::write(
+        &script,
+        "#!/bin/sh\n\
+         port=\"${1#--port=}\"\n\
+         exec python3 -c 'import socket, sys, time\n\
+s = socket.socket()\n\
+s.bind((\"127.0.0.1\", int(sys.argv[1])))\n\
+s.listen()\n\
+time.sleep(60)' \"$port\"\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let manager = Arc::new(DriverManager::new());
+    let config = DriverConfig {
+        driver_type: DriverType::Generic(script.to_string_lossy().into_owned()),
+        port: None,
+        args: vec![],
+        timeout: std::time::Duration::from_secs(10),
+        verbose: false,
+    };
+    let starts: Vec<_> = (0..2)
+        .map(|_| {
+            let manager = Arc::clone(&manager);
+            let config = config.clone();
+            std::thread::spawn(move || manager.start_driver_with_config(config))
+        })
+        .collect();
+    let infos: Vec<_> = starts
+        .into_iter()
+        .map(|start| start.join().unwrap().unwrap())
+        .collect();
+
+    assert_ne!(infos[0].port, infos[1].port);
+    for info in &infos {
+        assert_eq!(info.endpoint, format!("http://127.0.0.1:{}", info.port));
+    }
+    assert_eq!(manager.list_drivers().len(), 2);
+
+    for info in &infos {
+        manager.stop_driver(info.port).unwrap();
+    }
+    assert!(manager.list_drivers().is_empty());
+}
@@ -71,6 +71,21 @@ class TestWebFetcher:
         with pytest.raises(ValueError, match="Invalid format"):
             fetcher.fetch(test_url, "plain_request", "invalid_format")
 
+    @pytest.mark.network
+    def test_fetch_many(self, fetcher, test_url):
+        """Test fetching several URLs returns one entry per URL, in order."""
+        results = fetcher.fetch_many([test_url, "http://127.0.0.1:9/missing"], "plain_request", "markdown")
+        assert len(results) == 2
+        if isinstance(results[0], Exception):
+            pytest.skip(f"Network request failed: {results[0]}")
+        assert isinstance(results[0], str)
+        assert isinstance(results[1], RuntimeError)
+
+    def test_fetch_many_invalid_mode(self, fetcher, test_url):
+        """Test fetch_many with an invalid fetch mode raises ValueError."""
+        with pytest.raises(ValueError, match="Invalid fetch mode"):
+            fetcher.fetch_many([test_url], "invalid_mode", "html")
+
     def test_from_config(self):
         """Test creating WebFetcher from config."""
         config = tarzi.Config()
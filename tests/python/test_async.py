@@ -0,0 +1,105 @@
+#!/usr/bin/env python3
+"""
+Unit tests for the async methods of tarzi, against a local HTTP server.
+"""
+
+import asyncio
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+import pytest
+
+import tarzi
+
+
+SEARCH_RESULTS = json.dumps(
+    {
+        "results": [
+            {"url": f"https://example.com/{index}", "title": f"Result {index}", "content": "Snippet"}
+            for index in range(3)
+        ]
+    }
+)
+
+
+class PageHandler(BaseHTTPRequestHandler):
+    """Serves SearxNG results under /search and a small HTML page for every other path."""
+
+    def do_GET(self):
+        if self.path.startswith("/search?"):
+            body, content_type = SEARCH_RESULTS.encode(), "application/json"
+        else:
+            body, content_type = f"<h1>Async</h1><p>Path {self.path}</p>".encode(), "text/html"
+        self.send_response(200)
+        self.send_header("Content-Type", content_type)
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def log_message(self, format, *args):
+        pass
+
+
+@pytest.fixture
+def server_url():
+    """Fixture for a local HTTP server, stopped after the test."""
+    server = HTTPServer(("127.0.0.1", 0), PageHandler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}"
+    server.shutdown()
+    server.server_close()
+
+
+@pytest.mark.unit
+class TestAsync:
+    """Test cases for fetch_async and search_async."""
+
+    def test_fetch_async(self, server_url):
+        """Test awaiting fetch_async returns the converted page."""
+        fetcher = tarzi.WebFetcher()
+        content = asyncio.run(fetcher.fetch_async(f"{server_url}/page", "plain_request", "markdown"))
+        assert "# Async" in content
+        assert "Path /page" in content
+
+    def test_fetch_async_concurrent(self, server_url):
+        """Test several fetch_async calls can be gathered on one event loop."""
+
+        async def fetch_all():
+            fetchers = [tarzi.WebFetcher() for _ in range(3)]
+            return await asyncio.gather(
+                *(
+                    fetcher.fetch_async(f"{server_url}/{index}", "plain_request", "markdown")
+                    for index, fetcher in enumerate(fetchers)
+                )
+            )
+
+        results = asyncio.run(fetch_all())
+        for index, content in enumerate(results):
+            assert f"Path /{index}" in content
+
+    def test_fetch_async_invalid_mode(self, server_url):
+        """Test fetch_async with an invalid fetch mode raises ValueError at call time."""
+        fetcher = tarzi.WebFetcher()
+        with pytest.raises(ValueError, match="Invalid fetch mode"):
+            fetcher.fetch_async(server_url, "invalid_mode", "html")
+
+    def test_fetch_async_failure(self):
+        """Test a failing fetch_async raises RuntimeError when awaited."""
+        fetcher = tarzi.WebFetcher()
+        with pytest.raises(RuntimeError, match="Failed to fetch"):
+            asyncio.run(fetcher.fetch_async("http://127.0.0.1:9/", "plain_request", "html"))
+
+    def test_search_async(self, server_url):
+        """Test awaiting search_async, with the configured limit when none is given."""
+        config = tarzi.Config.from_str(
+            f'[search]\nengine = "searxng"\nsearxng_base_url = "{server_url}"\nlimit = 2\n'
+        )
+        engine = tarzi.SearchEngine.from_config(config)
+
+        results = asyncio.run(engine.search_async("rust"))
+        assert [result.title for result in results] == ["Result 0", "Result 1"]
+
+        results = asyncio.run(engine.search_async("rust", 1))
+        assert [result.url for result in results] == ["https://example.com/0"]
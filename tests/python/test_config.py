@@ -54,6 +54,61 @@ class TestConfig:
         with pytest.raises(RuntimeError, match="Failed to read config file"):
             tarzi.Config.from_file("nonexistent_file.toml")
 
+    def test_config_from_file_formats(self, tmp_path):
+        """Test loading YAML and JSON files as well as TOML."""
+        (tmp_path / "tarzi.yaml").write_text("search:\n  engine: duckduckgo\n")
+        (tmp_path / "tarzi.json").write_text('{"search": {"engine": "searxng"}}')
+        (tmp_path / "tarzi.ini").write_text("[search]\n")
+        assert tarzi.Config.from_file(str(tmp_path / "tarzi.yaml")).search_engine == "duckduckgo"
+        assert tarzi.Config.from_file(str(tmp_path / "tarzi.json")).search_engine == "searxng"
+        with pytest.raises(RuntimeError, match="Unsupported config file"):
+            tarzi.Config.from_file(str(tmp_path / "tarzi.ini"))
+
+    def test_config_from_file_env_api_keys(self, tmp_path, monkeypatch):
+        """Test API keys from the environment override the file's."""
+        path = tmp_path / "tarzi.toml"
+        path.write_text('[search]\nserpapi_key = "file-key"\n')
+        monkeypatch.setenv("TARZI_SERPAPI_KEY", "env-key")
+        assert tarzi.Config.from_file(str(path)).serpapi_key == "env-key"
+        monkeypatch.setenv("TARZI_SERPAPI_KEY", "")
+        assert tarzi.Config.from_file(str(path)).serpapi_key == "file-key"
+
+    def test_config_getters(self, sample_config):
+        """Test fields loaded from a string are readable."""
+        config = tarzi.Config.from_str(sample_config)
+        assert config.search_engine == "brave"
+        assert config.fetcher_format == "html"
+        assert config.user_agent == "Test Agent"
+        assert config.proxy == "http://proxy.example.com:8080"
+        assert config.serpapi_key is None
+
+    def test_config_setters(self, config):
+        """Test setters change the configuration and its TOML."""
+        config.search_engine = "duckduckgo"
+        config.fetcher_mode = "plain_request"
+        config.fetcher_format = "json"
+        config.user_agent = "Custom Agent"
+        config.proxy = None
+        config.bing_api_key = "bing-key"
+
+        toml = config.to_toml_string()
+        assert 'engine = "duckduckgo"' in toml
+        assert 'mode = "plain_request"' in toml
+        assert 'format = "json"' in toml
+        assert 'user_agent = "Custom Agent"' in toml
+        assert 'bing_api_key = "bing-key"' in toml
+
+        assert tarzi.Config.from_str(toml).search_engine == "duckduckgo"
+
+    def test_config_setters_invalid(self, config):
+        """Test setters reject unknown engines, modes and formats."""
+        with pytest.raises(ValueError, match="Invalid search engine"):
+            config.search_engine = "altavista"
+        with pytest.raises(ValueError, match="Invalid fetch mode"):
+            config.fetcher_mode = "telnet"
+        with pytest.raises(ValueError, match="Invalid format"):
+            config.fetcher_format = "pdf"
+
 
 @pytest.mark.unit
 class TestConfigIntegration:
@@ -74,3 +129,11 @@ class TestConfigIntegration:
         # Test with search engine
         engine = tarzi.SearchEngine.from_config(config)
         assert isinstance(engine, tarzi.SearchEngine)
+
+    def test_search_limit_reaches_search_engine(self, config):
+        """Test a search_limit set in Python is the search engine's default limit."""
+        config.search_limit = 3
+        assert config.search_limit == 3
+
+        engine = tarzi.SearchEngine.from_config(config)
+        assert engine.default_limit == 3
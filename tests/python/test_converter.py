@@ -76,6 +76,42 @@ class TestConverter:
         converter = tarzi.Converter.from_config(config)
         assert isinstance(converter, tarzi.Converter)
 
+    def test_from_config_readability(self):
+        """Test the [converter] readability setting applies to converters from config."""
+        html = (
+            "<nav><a href='/'>Home</a></nav>"
+            "<article><p>The story itself, with enough words to count as content.</p></article>"
+            "<footer>Copyright 2024</footer>"
+        )
+        assert "Copyright" in tarzi.Converter.from_config(tarzi.Config()).convert(html, "markdown")
+
+        config = tarzi.Config.from_str("[converter]\nreadability = true\n")
+        result = tarzi.Converter.from_config(config).convert(html, "markdown")
+        assert "The story itself" in result
+        assert "Copyright" not in result
+
+    def test_repeated_conversions(self, converter, sample_html):
+        """Test many conversions in a row all succeed on the shared runtime."""
+        results = [converter.convert(sample_html, "markdown") for _ in range(100)]
+        assert all("Test Title" in result for result in results)
+
+    def test_extract_metadata(self, converter):
+        """Test extracting OpenGraph and JSON-LD metadata."""
+        html = (
+            "<head><title>Fallback</title>"
+            '<meta property="og:title" content="OG title">'
+            '<meta property="og:image" content="https://example.com/card.png">'
+            '<script type="application/ld+json">'
+            '{"@type": "Article", "datePublished": "2024-07-25"}</script></head>'
+        )
+        metadata = converter.extract_metadata(html)
+        assert metadata["title"] == "OG title"
+        assert metadata["image"] == "https://example.com/card.png"
+        assert metadata["description"] is None
+        assert metadata["site_name"] is None
+        assert metadata["published_time"] == "2024-07-25"
+        assert metadata["json_ld"] == [{"@type": "Article", "datePublished": "2024-07-25"}]
+
 
 @pytest.mark.unit
 def test_convert_html_function(sample_html):